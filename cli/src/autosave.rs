@@ -0,0 +1,25 @@
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fs, io, path::PathBuf};
+
+fn autosave_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("matrix_toolkit").join("autosave.json"))
+}
+
+// A missing or corrupted autosave is treated as having nothing to restore
+pub fn load<T: DeserializeOwned>() -> Option<T> {
+    autosave_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+}
+
+pub fn save<T: Serialize>(workspace: &T) -> io::Result<()> {
+    let path = autosave_path().ok_or(io::ErrorKind::NotFound)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    // Written to a temporary file first, so a crash halfway through never leaves a truncated autosave
+    let temporary = path.with_extension("json.tmp");
+    fs::write(&temporary, serde_json::to_string(workspace)?)?;
+    fs::rename(temporary, path)
+}
@@ -0,0 +1,35 @@
+use crate::{csv, transcript};
+use arboard::Clipboard;
+use matrix_core::{Border, FormatOptions, Matrix};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Format {
+    Plain,
+    Csv,
+    Latex,
+    Matlab,
+}
+
+pub fn render(matrix: &Matrix, format: Format, options: &FormatOptions) -> String {
+    match format {
+        Format::Plain => matrix
+            .formatted(FormatOptions {
+                border: Border::None,
+                ..*options
+            })
+            .to_string(),
+        // Full precision, so the values can be read back without losing digits
+        Format::Csv => csv::to_csv(matrix),
+        Format::Latex => transcript::latex_matrix(matrix, options),
+        Format::Matlab => matrix.to_matlab_string(),
+    }
+}
+
+// On Linux the copied text is served by the process that owns the clipboard, so the caller keeps
+// the same Clipboard alive for the rest of the session instead of dropping it after copying
+pub fn copy(clipboard: &mut Option<Clipboard>, text: String) -> Result<(), arboard::Error> {
+    if clipboard.is_none() {
+        *clipboard = Some(Clipboard::new()?);
+    }
+    clipboard.as_mut().unwrap().set_text(text)
+}
@@ -0,0 +1,225 @@
+use crate::csv;
+use clap::{Parser, Subcommand};
+use matrix_core::{Matrix, MatrixRecord};
+use std::{
+    error::Error,
+    fs,
+    io::{self, IsTerminal, Read},
+    path::PathBuf,
+};
+
+#[derive(Parser)]
+#[command(about = "Perform matrix operations interactively or from the command line")]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    #[command(flatten)]
+    pub piped: PipedOperation,
+}
+
+// An operation on a matrix piped into stdin as CSV or JSON, such as `cat a.csv | cli --transpose`
+#[derive(clap::Args)]
+#[group(multiple = false)]
+pub struct PipedOperation {
+    /// Transpose the piped matrix
+    #[arg(long)]
+    transpose: bool,
+    /// Adjoint of the piped matrix
+    #[arg(long)]
+    adjoint: bool,
+    /// Inverse of the piped matrix
+    #[arg(long)]
+    inverse: bool,
+    /// Trace of the piped matrix
+    #[arg(long)]
+    trace: bool,
+    /// Determinant of the piped matrix
+    #[arg(long, visible_alias = "det")]
+    determinant: bool,
+    /// Multiply the piped matrix by a scalar
+    #[arg(long, value_name = "SCALAR", allow_negative_numbers = true)]
+    scale: Option<f64>,
+}
+
+impl PipedOperation {
+    pub fn is_requested(&self) -> bool {
+        self.transpose
+            || self.adjoint
+            || self.inverse
+            || self.trace
+            || self.determinant
+            || self.scale.is_some()
+    }
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Add two matrices
+    Add {
+        first: PathBuf,
+        second: PathBuf,
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Subtract the second matrix from the first
+    Subtract {
+        first: PathBuf,
+        second: PathBuf,
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Multiply two matrices
+    Multiply {
+        first: PathBuf,
+        second: PathBuf,
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Multiply a matrix by a scalar
+    Scale {
+        #[arg(allow_negative_numbers = true)]
+        scalar: f64,
+        matrix: PathBuf,
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Transpose of a matrix
+    Transpose {
+        matrix: PathBuf,
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Adjoint of a matrix
+    Adjoint {
+        matrix: PathBuf,
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Inverse of a matrix
+    Inverse {
+        matrix: PathBuf,
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Trace of a matrix
+    Trace { matrix: PathBuf },
+    /// Determinant of a matrix
+    #[command(alias = "det")]
+    Determinant { matrix: PathBuf },
+}
+
+fn write_matrix(result: &Matrix, output: Option<PathBuf>) -> Result<(), Box<dyn Error>> {
+    let csv = csv::to_csv(result);
+    match output {
+        Some(path) => fs::write(&path, csv).map_err(|err| format!("{}: {err}", path.display()))?,
+        None => print!("{csv}"),
+    }
+
+    Ok(())
+}
+
+pub fn run(command: Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Add {
+            first,
+            second,
+            output,
+        } => {
+            let sum = (&csv::read_matrix(first)? + &csv::read_matrix(second)?)?;
+            write_matrix(&sum, output)
+        }
+        Command::Subtract {
+            first,
+            second,
+            output,
+        } => {
+            let difference = (&csv::read_matrix(first)? - &csv::read_matrix(second)?)?;
+            write_matrix(&difference, output)
+        }
+        Command::Multiply {
+            first,
+            second,
+            output,
+        } => {
+            let product = (&csv::read_matrix(first)? * &csv::read_matrix(second)?)?;
+            write_matrix(&product, output)
+        }
+        Command::Scale {
+            scalar,
+            matrix,
+            output,
+        } => write_matrix(&(scalar * &csv::read_matrix(matrix)?), output),
+        Command::Transpose { matrix, output } => {
+            write_matrix(&csv::read_matrix(matrix)?.transpose(), output)
+        }
+        Command::Adjoint { matrix, output } => {
+            write_matrix(&csv::read_matrix(matrix)?.adjoint()?, output)
+        }
+        Command::Inverse { matrix, output } => {
+            write_matrix(&csv::read_matrix(matrix)?.inverse()?, output)
+        }
+        Command::Trace { matrix } => {
+            println!("{}", csv::read_matrix(matrix)?.trace()?);
+            Ok(())
+        }
+        Command::Determinant { matrix } => {
+            println!("{}", csv::read_matrix(matrix)?.determinant()?);
+            Ok(())
+        }
+    }
+}
+
+// The result is written in the format the matrix was piped in
+pub fn run_piped(operation: PipedOperation) -> Result<(), Box<dyn Error>> {
+    let mut stdin = io::stdin();
+    if stdin.is_terminal() {
+        return Err(
+            "pipe a matrix into stdin to use an operation flag, e.g. `cat a.csv | cli --transpose`"
+                .into(),
+        );
+    }
+    if !operation.is_requested() {
+        return Err(
+            "choose an operation for the piped matrix, e.g. --transpose, or see --help".into(),
+        );
+    }
+
+    let mut input = String::new();
+    stdin.read_to_string(&mut input)?;
+    let is_json = input.trim_start().starts_with('{');
+    let record = if is_json {
+        serde_json::from_str::<MatrixRecord>(&input)?
+    } else {
+        MatrixRecord::from(csv::parse_matrix(&input, csv::Delimiter::Auto, false)?)
+    };
+    let matrix = &record.matrix;
+
+    let result = if operation.trace {
+        println!("{}", matrix.trace()?);
+        return Ok(());
+    } else if operation.determinant {
+        println!("{}", matrix.determinant()?);
+        return Ok(());
+    } else if let Some(scalar) = operation.scale {
+        scalar * matrix
+    } else if operation.adjoint {
+        matrix.adjoint()?
+    } else if operation.inverse {
+        matrix.inverse()?
+    } else {
+        matrix.transpose()
+    };
+
+    // The name, tags and description carry over, so they follow the matrix along a pipeline
+    if is_json {
+        let result = MatrixRecord {
+            matrix: result,
+            ..record
+        };
+        println!("{}", serde_json::to_string(&result)?);
+    } else {
+        write_matrix(&result, None)?;
+    }
+    Ok(())
+}
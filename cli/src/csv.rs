@@ -0,0 +1,44 @@
+use matrix_core::{CsvOptions, Matrix};
+use std::{error::Error, path::Path};
+
+pub use matrix_core::Delimiter;
+
+pub fn parse_matrix(
+    text: &str,
+    delimiter: Delimiter,
+    has_header: bool,
+) -> Result<Matrix, Box<dyn Error>> {
+    Ok(Matrix::from_csv_str(
+        text,
+        CsvOptions {
+            delimiter,
+            has_header,
+            ..Default::default()
+        },
+    )?)
+}
+
+pub fn read_matrix(path: impl AsRef<Path>) -> Result<Matrix, Box<dyn Error>> {
+    import_matrix(path, Delimiter::Auto, false)
+}
+
+pub fn import_matrix(
+    path: impl AsRef<Path>,
+    delimiter: Delimiter,
+    has_header: bool,
+) -> Result<Matrix, Box<dyn Error>> {
+    let path = path.as_ref();
+    Matrix::from_csv_path(
+        path,
+        CsvOptions {
+            delimiter,
+            has_header,
+            ..Default::default()
+        },
+    )
+    .map_err(|err| format!("{}: {err}", path.display()).into())
+}
+
+pub fn to_csv(matrix: &Matrix) -> String {
+    matrix.to_csv(CsvOptions::default())
+}
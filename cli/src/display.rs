@@ -0,0 +1,148 @@
+use console::Term;
+use matrix_core::{FormatOptions, Matrix};
+use std::io;
+
+// Number of leading and trailing rows/columns kept in the truncated view, as NumPy does
+const EDGE_ITEMS: usize = 3;
+
+// Lines taken up by the prompts and borders around a note
+const RESERVED_LINES: usize = 12;
+const RESERVED_COLUMNS: usize = 8;
+
+fn cells(
+    matrix: &Matrix,
+    rows: &[usize],
+    columns: &[usize],
+    options: &FormatOptions,
+) -> Vec<Vec<String>> {
+    rows.iter()
+        .map(|row| {
+            columns
+                .iter()
+                .map(|column| options.format_number(matrix.get_element(*row, *column).unwrap()))
+                .collect()
+        })
+        .collect()
+}
+
+fn edges(len: usize) -> Option<Vec<usize>> {
+    if len <= 2 * EDGE_ITEMS {
+        None
+    } else {
+        Some((0..EDGE_ITEMS).chain((len - EDGE_ITEMS)..len).collect())
+    }
+}
+
+fn truncated(matrix: &Matrix, options: &FormatOptions) -> String {
+    let rows = edges(matrix.rows()).unwrap_or_else(|| (0..matrix.rows()).collect());
+    let columns = edges(matrix.columns()).unwrap_or_else(|| (0..matrix.columns()).collect());
+    let mut cells = cells(matrix, &rows, &columns, options);
+
+    if matrix.columns() > 2 * EDGE_ITEMS {
+        cells
+            .iter_mut()
+            .for_each(|row| row.insert(EDGE_ITEMS, "…".to_string()));
+    }
+
+    if matrix.rows() > 2 * EDGE_ITEMS {
+        let width = cells[0].len();
+        cells.insert(EDGE_ITEMS, vec!["⋮".to_string(); width]);
+    }
+
+    options.render_cells(&cells)
+}
+
+fn terminal_size() -> (usize, usize) {
+    let (rows, columns) = Term::stdout().size();
+    (
+        (rows as usize)
+            .saturating_sub(RESERVED_LINES)
+            .max(EDGE_ITEMS),
+        (columns as usize)
+            .saturating_sub(RESERVED_COLUMNS)
+            .max(EDGE_ITEMS),
+    )
+}
+
+pub fn show_matrix(title: &str, matrix: &Matrix, options: &FormatOptions) -> io::Result<()> {
+    let full = matrix.formatted(*options).to_string();
+    let (max_lines, max_width) = terminal_size();
+
+    if full.lines().count() <= max_lines && full.lines().all(|line| line.len() <= max_width) {
+        return cliclack::note(title, full);
+    }
+
+    cliclack::note(
+        format!("{title} ({}×{})", matrix.rows(), matrix.columns()),
+        truncated(matrix, options),
+    )?;
+
+    if cliclack::confirm(
+        "The matrix is too large to show in full. Do you want to scroll through it?",
+    )
+    .initial_value(false)
+    .interact()?
+    {
+        scroll(title, matrix, options, max_lines, max_width)?;
+    }
+
+    Ok(())
+}
+
+fn scroll(
+    title: &str,
+    matrix: &Matrix,
+    options: &FormatOptions,
+    page_rows: usize,
+    max_width: usize,
+) -> io::Result<()> {
+    let widest = (0..matrix.rows())
+        .flat_map(|row| matrix.get_row(row).unwrap())
+        .map(|num| options.format_number(num).chars().count())
+        .max()
+        .unwrap();
+    let page_columns = (max_width / (widest + 1)).max(1);
+
+    let (mut first_row, mut first_column) = (0, 0);
+    loop {
+        let last_row = (first_row + page_rows).min(matrix.rows());
+        let last_column = (first_column + page_columns).min(matrix.columns());
+        let rows: Vec<usize> = (first_row..last_row).collect();
+        let columns: Vec<usize> = (first_column..last_column).collect();
+
+        cliclack::note(
+            format!(
+                "{title} — rows {}-{} of {}, columns {}-{} of {}",
+                first_row + 1,
+                last_row,
+                matrix.rows(),
+                first_column + 1,
+                last_column,
+                matrix.columns()
+            ),
+            options.render_cells(&cells(matrix, &rows, &columns, options)),
+        )?;
+
+        let mut navigation = cliclack::select("Scroll");
+        if last_row < matrix.rows() {
+            navigation = navigation.item("down", "Next rows", "");
+        }
+        if first_row > 0 {
+            navigation = navigation.item("up", "Previous rows", "");
+        }
+        if last_column < matrix.columns() {
+            navigation = navigation.item("right", "Next columns", "");
+        }
+        if first_column > 0 {
+            navigation = navigation.item("left", "Previous columns", "");
+        }
+
+        match navigation.item("done", "Done", "").interact()? {
+            "down" => first_row += page_rows,
+            "up" => first_row -= page_rows,
+            "right" => first_column += page_columns,
+            "left" => first_column -= page_columns,
+            _ => return Ok(()),
+        }
+    }
+}
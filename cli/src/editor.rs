@@ -0,0 +1,225 @@
+use crate::expression;
+use console::{style, Key, Term};
+use matrix_core::Matrix;
+use std::{collections::BTreeMap, io};
+
+// Lines taken up by the title, help and status lines around the grid
+const RESERVED_LINES: usize = 6;
+const RESERVED_COLUMNS: usize = 4;
+
+struct Grid<'a> {
+    values: Vec<Vec<f64>>,
+    row: usize,
+    column: usize,
+    first_row: usize,
+    first_column: usize,
+    // Text typed into the current cell, which overwrites it once committed
+    input: Option<String>,
+    error: Option<String>,
+    variables: &'a BTreeMap<String, f64>,
+}
+
+impl Grid<'_> {
+    fn rows(&self) -> usize {
+        self.values.len()
+    }
+
+    fn columns(&self) -> usize {
+        self.values[0].len()
+    }
+
+    fn cell(&self, row: usize, column: usize) -> String {
+        match &self.input {
+            Some(input) if (row, column) == (self.row, self.column) => format!("{input}_"),
+            _ => self.values[row][column].to_string(),
+        }
+    }
+
+    // Returns false and keeps the input when it is not a valid number
+    fn commit(&mut self) -> bool {
+        let Some(input) = &self.input else {
+            return true;
+        };
+
+        match expression::evaluate(input, self.variables) {
+            Err(err) => {
+                self.error = Some(format!("Elements should be real numbers ({err})"));
+                false
+            }
+            Ok(num) => {
+                self.values[self.row][self.column] = num;
+                self.input = None;
+                true
+            }
+        }
+    }
+
+    fn move_to(&mut self, row: usize, column: usize) {
+        if self.commit() {
+            self.row = row.min(self.rows() - 1);
+            self.column = column.min(self.columns() - 1);
+        }
+    }
+
+    fn render(&mut self, title: &str, (max_lines, max_width): (usize, usize)) -> Vec<String> {
+        let widths: Vec<usize> = (0..self.columns())
+            .map(|column| {
+                (0..self.rows())
+                    .map(|row| self.cell(row, column).chars().count())
+                    .max()
+                    .unwrap()
+            })
+            .collect();
+
+        // Scroll just far enough to keep the cursor on screen
+        let page_rows = max_lines.max(1);
+        if self.row < self.first_row {
+            self.first_row = self.row;
+        } else if self.row >= self.first_row + page_rows {
+            self.first_row = self.row + 1 - page_rows;
+        }
+        if self.column < self.first_column {
+            self.first_column = self.column;
+        }
+        while self.first_column < self.column
+            && widths[self.first_column..=self.column]
+                .iter()
+                .map(|width| width + 2)
+                .sum::<usize>()
+                > max_width
+        {
+            self.first_column += 1;
+        }
+
+        let mut last_column = self.first_column;
+        let mut used = 0;
+        while last_column < self.columns() && used + widths[last_column] + 2 <= max_width {
+            used += widths[last_column] + 2;
+            last_column += 1;
+        }
+        let last_column = last_column.max(self.column + 1);
+        let last_row = (self.first_row + page_rows).min(self.rows());
+
+        let mut lines = vec![format!(
+            "{} {}",
+            style(title).bold(),
+            style(format!(
+                "row {} of {}, column {} of {}",
+                self.row + 1,
+                self.rows(),
+                self.column + 1,
+                self.columns()
+            ))
+            .dim()
+        )];
+
+        for row in self.first_row..last_row {
+            let line: String = (self.first_column..last_column)
+                .map(|column| {
+                    let cell = format!(
+                        " {:>width$} ",
+                        self.cell(row, column),
+                        width = widths[column]
+                    );
+                    if (row, column) == (self.row, self.column) {
+                        style(cell).reverse().to_string()
+                    } else {
+                        cell
+                    }
+                })
+                .collect();
+            lines.push(line);
+        }
+
+        lines.push(match &self.error {
+            Some(error) => style(error).red().to_string(),
+            None => String::new(),
+        });
+        lines.push(
+            style(
+                "Arrows/Tab: move  Type: overwrite  Enter: confirm, again to finish  Esc: cancel",
+            )
+            .dim()
+            .to_string(),
+        );
+        lines
+    }
+}
+
+// Returns None when the user cancels with Esc or Ctrl-C
+pub fn edit_matrix(
+    title: &str,
+    matrix: &Matrix,
+    variables: &BTreeMap<String, f64>,
+) -> io::Result<Option<Matrix>> {
+    let term = Term::stdout();
+    let mut grid = Grid {
+        values: (0..matrix.rows())
+            .map(|row| matrix.get_row(row).unwrap())
+            .collect(),
+        row: 0,
+        column: 0,
+        first_row: 0,
+        first_column: 0,
+        input: None,
+        error: None,
+        variables,
+    };
+
+    let (rows, columns) = term.size();
+    let size = (
+        (rows as usize).saturating_sub(RESERVED_LINES),
+        (columns as usize).saturating_sub(RESERVED_COLUMNS),
+    );
+
+    term.hide_cursor()?;
+    let mut drawn = 0;
+    let result = loop {
+        term.clear_last_lines(drawn)?;
+        let lines = grid.render(title, size);
+        drawn = lines.len();
+        term.write_line(&lines.join("\n"))?;
+
+        let key = term.read_key()?;
+        grid.error = None;
+        match key {
+            Key::ArrowUp => grid.move_to(grid.row.saturating_sub(1), grid.column),
+            Key::ArrowDown => grid.move_to(grid.row + 1, grid.column),
+            Key::ArrowLeft => grid.move_to(grid.row, grid.column.saturating_sub(1)),
+            Key::ArrowRight => grid.move_to(grid.row, grid.column + 1),
+            Key::Tab => {
+                if grid.column + 1 < grid.columns() {
+                    grid.move_to(grid.row, grid.column + 1)
+                } else {
+                    grid.move_to(grid.row + 1, 0)
+                }
+            }
+            Key::BackTab => {
+                if grid.column > 0 {
+                    grid.move_to(grid.row, grid.column - 1)
+                } else {
+                    grid.move_to(grid.row.saturating_sub(1), grid.columns() - 1)
+                }
+            }
+            Key::Char(character) if !character.is_control() => {
+                grid.input.get_or_insert_with(String::new).push(character)
+            }
+            Key::Backspace => {
+                grid.input.get_or_insert_with(String::new).pop();
+            }
+            Key::Enter => {
+                let editing = grid.input.is_some();
+                if grid.commit() && !editing {
+                    break Some(Matrix::new_with_data(grid.values).unwrap());
+                }
+            }
+            Key::Escape if grid.input.is_some() => grid.input = None,
+            Key::Escape | Key::CtrlC => break None,
+            _ => {}
+        }
+    };
+
+    term.clear_last_lines(drawn)?;
+    term.show_cursor()?;
+    Ok(result)
+}
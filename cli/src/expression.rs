@@ -0,0 +1,299 @@
+use std::{collections::BTreeMap, f64::consts};
+
+// Evaluates inputs like `1/3`, `-2.5e-3`, `sqrt(2)` or `pi/4`, with `variables` supplying named scalars
+pub fn evaluate(input: &str, variables: &BTreeMap<String, f64>) -> Result<f64, String> {
+    let mut parser = Parser {
+        chars: input.chars().collect(),
+        position: 0,
+        variables,
+    };
+
+    let value = parser.sum()?;
+    parser.skip_whitespace();
+    if parser.peek().is_some() {
+        return Err(parser.unexpected());
+    }
+
+    if value.is_finite() {
+        Ok(value)
+    } else {
+        Err("the result is not a finite number".to_string())
+    }
+}
+
+struct Parser<'a> {
+    chars: Vec<char>,
+    position: usize,
+    variables: &'a BTreeMap<String, f64>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    // Whitespace separates tokens, so `1 2` is two numbers rather than 12
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn eat(&mut self, character: char) -> bool {
+        self.skip_whitespace();
+        let matches = self.peek() == Some(character);
+        if matches {
+            self.position += 1;
+        }
+        matches
+    }
+
+    fn sum(&mut self) -> Result<f64, String> {
+        let mut value = self.product()?;
+        loop {
+            if self.eat('+') {
+                value += self.product()?;
+            } else if self.eat('-') {
+                value -= self.product()?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn product(&mut self) -> Result<f64, String> {
+        let mut value = self.unary()?;
+        loop {
+            if self.eat('*') {
+                value *= self.unary()?;
+            } else if self.eat('/') {
+                value /= self.unary()?;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+
+    fn unary(&mut self) -> Result<f64, String> {
+        if self.eat('-') {
+            Ok(-self.unary()?)
+        } else if self.eat('+') {
+            self.unary()
+        } else {
+            self.power()
+        }
+    }
+
+    // Exponentiation is right associative and binds tighter than a leading minus, so -2^2 is -4
+    fn power(&mut self) -> Result<f64, String> {
+        let base = self.primary()?;
+        if self.eat('^') {
+            Ok(base.powf(self.unary()?))
+        } else {
+            Ok(base)
+        }
+    }
+
+    // Two operands in a row, as in `2 x`, are rejected rather than read as a product
+    fn unexpected(&self) -> String {
+        match self.peek() {
+            None => "the expression ends too early".to_string(),
+            Some(character)
+                if character.is_alphanumeric()
+                    || character == '.'
+                    || character == '_'
+                    || character == '(' =>
+            {
+                format!("missing an operator before `{character}`")
+            }
+            Some(character) => format!("unexpected `{character}`"),
+        }
+    }
+
+    fn close_parenthesis(&mut self) -> Result<(), String> {
+        if self.eat(')') {
+            Ok(())
+        } else if self.peek().is_none() {
+            Err("missing `)`".to_string())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    fn primary(&mut self) -> Result<f64, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            None => Err("the expression ends too early".to_string()),
+            Some('(') => {
+                self.position += 1;
+                let value = self.sum()?;
+                self.close_parenthesis()?;
+                Ok(value)
+            }
+            Some(character) if character.is_ascii_digit() || character == '.' => self.number(),
+            Some(character) if character.is_alphabetic() || character == '_' => self.name(),
+            Some(character) => Err(format!("unexpected `{character}`")),
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, String> {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|character| character.is_ascii_digit() || character == '.')
+        {
+            self.position += 1;
+        }
+
+        // An exponent only counts when digits follow, so that `2e` is not silently read as 2
+        if let Some('e' | 'E') = self.peek() {
+            let mut end = self.position + 1;
+            if let Some('+' | '-') = self.chars.get(end) {
+                end += 1;
+            }
+            if self.chars.get(end).is_some_and(char::is_ascii_digit) {
+                self.position = end;
+                while self
+                    .peek()
+                    .is_some_and(|character| character.is_ascii_digit())
+                {
+                    self.position += 1;
+                }
+            }
+        }
+
+        let text: String = self.chars[start..self.position].iter().collect();
+        text.parse()
+            .map_err(|_| format!("`{text}` is not a number"))
+    }
+
+    fn name(&mut self) -> Result<f64, String> {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|character| character.is_alphanumeric() || character == '_')
+        {
+            self.position += 1;
+        }
+        let name: String = self.chars[start..self.position].iter().collect();
+
+        if self.eat('(') {
+            let argument = self.sum()?;
+            self.close_parenthesis()?;
+
+            return match name.as_str() {
+                "sqrt" => Ok(argument.sqrt()),
+                "abs" => Ok(argument.abs()),
+                "exp" => Ok(argument.exp()),
+                "ln" => Ok(argument.ln()),
+                "log" => Ok(argument.log10()),
+                "sin" => Ok(argument.sin()),
+                "cos" => Ok(argument.cos()),
+                "tan" => Ok(argument.tan()),
+                _ => Err(format!("unknown function `{name}`")),
+            };
+        }
+
+        match name.as_str() {
+            "pi" => Ok(consts::PI),
+            "e" => Ok(consts::E),
+            _ => self
+                .variables
+                .get(&name)
+                .copied()
+                .ok_or_else(|| format!("unknown name `{name}`")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(input: &str) -> Result<f64, String> {
+        evaluate(input, &BTreeMap::new())
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(Ok(7.0), value("1 + 2 * 3"));
+        assert_eq!(Ok(2.0), value("8 / 2 / 2"));
+        assert_eq!(Ok(1.0), value("5 - 3 - 1"));
+        // Exponentiation is right associative
+        assert_eq!(Ok(512.0), value("2^3^2"));
+        assert_eq!(Ok(0.0025), value("2.5e-3"));
+        assert_eq!(Ok(1.0 / 3.0), value("1/3"));
+    }
+
+    #[test]
+    fn unary_minus() {
+        assert_eq!(Ok(-2.0 / 7.0), value("-2/7"));
+        assert_eq!(Ok(-4.0), value("-2^2"));
+        assert_eq!(Ok(2.0), value("--2"));
+        assert_eq!(Ok(-1.0), value("3 * -1 + 2"));
+    }
+
+    #[test]
+    fn parentheses() {
+        assert_eq!(Ok(9.0), value("(1 + 2) * 3"));
+        assert_eq!(Ok(4.0), value("(-2)^2"));
+        assert_eq!(Ok(2.0_f64.sqrt()), value("sqrt( 2 )"));
+        assert_eq!(Ok(consts::FRAC_PI_4), value("pi/4"));
+    }
+
+    #[test]
+    fn variables() {
+        let variables = BTreeMap::from([("x".to_string(), 2.0), ("rate_2".to_string(), 0.5)]);
+        assert_eq!(Ok(5.0), evaluate("x^2 + 1", &variables));
+        assert_eq!(Ok(1.0), evaluate("rate_2 * x", &variables));
+        assert_eq!(
+            Err("unknown name `y`".to_string()),
+            evaluate("y", &variables)
+        );
+    }
+
+    #[test]
+    fn division_by_zero() {
+        assert_eq!(
+            Err("the result is not a finite number".to_string()),
+            value("1/0")
+        );
+        assert_eq!(
+            Err("the result is not a finite number".to_string()),
+            value("0/0")
+        );
+    }
+
+    #[test]
+    fn malformed_input() {
+        // Whitespace separates operands instead of joining them
+        assert_eq!(
+            Err("missing an operator before `2`".to_string()),
+            value("1 2")
+        );
+        let variables = BTreeMap::from([("x".to_string(), 2.0), ("x1".to_string(), 5.0)]);
+        assert_eq!(
+            Err("missing an operator before `1`".to_string()),
+            evaluate("x 1", &variables)
+        );
+        assert_eq!(
+            Err("missing an operator before `3`".to_string()),
+            value("(2 3)")
+        );
+        assert_eq!(
+            Err("missing an operator before `e`".to_string()),
+            value("2e")
+        );
+
+        assert_eq!(
+            Err("the expression ends too early".to_string()),
+            value("2 +")
+        );
+        assert_eq!(Err("the expression ends too early".to_string()), value(""));
+        assert_eq!(Err("missing `)`".to_string()), value("(1 + 2"));
+        assert_eq!(Err("unexpected `)`".to_string()), value("1)"));
+        assert_eq!(Err("unexpected `#`".to_string()), value("#"));
+        assert_eq!(Err("`1.2.3` is not a number".to_string()), value("1.2.3"));
+        assert_eq!(Err("unknown function `foo`".to_string()), value("foo(1)"));
+    }
+}
@@ -0,0 +1,294 @@
+use matrix_core::{LinearSystemSolution, Matrix, MatrixError, Norm};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+    ops::Deref,
+    sync::Arc,
+};
+
+// Results are rounded to five decimal places, so beyond this the inverse and solutions lose most of their accuracy
+pub const ILL_CONDITIONED: f64 = 1e6;
+
+// Operands are stored by name so that running an entry again uses the current matrices
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Add(String, String),
+    Subtract(String, String),
+    Multiply(String, String),
+    Scale(f64, String),
+    Trace(String),
+    Transpose(String),
+    Determinant(String),
+    Adjoint(String),
+    Inverse(String),
+    Power(String, i32),
+    Exponential(String),
+    SquareRoot(String),
+    Solve(String, String),
+    Augment(String, String),
+    StackHorizontally(String, String),
+    StackVertically(String, String),
+    RowReduce(String),
+    Rank(String),
+    Norms(String),
+    Apply(ElementFunction, String),
+}
+
+// Functions applied to each element of a matrix on its own
+#[derive(Debug, Clone, Copy)]
+pub enum ElementFunction {
+    Abs,
+    Square,
+    SquareRoot,
+    Reciprocal,
+    // Rounds to the given number of decimal places
+    Round(u32),
+    // a·x + b
+    Affine(f64, f64),
+}
+
+#[derive(Debug, Clone)]
+pub enum Outcome {
+    Matrix(&'static str, Arc<Matrix>),
+    Scalar(&'static str, f64),
+    // The norms and condition number, and whether `is_singular` holds
+    Norms {
+        values: Vec<(&'static str, f64)>,
+        singular: bool,
+    },
+    // The reduced row echelon form with its pivot columns
+    Reduced(Arc<Matrix>, Vec<usize>),
+    // The pivot columns and the total number of columns, whose difference gives the free variables
+    Rank(Vec<usize>, usize),
+    Solution(LinearSystemSolution),
+    Failed(String),
+}
+
+pub struct Entry {
+    pub operation: Operation,
+    pub outcome: Outcome,
+}
+
+impl Operation {
+    pub fn operands(&self) -> Vec<&str> {
+        match self {
+            Operation::Add(first, second)
+            | Operation::Subtract(first, second)
+            | Operation::Multiply(first, second)
+            | Operation::Solve(first, second)
+            | Operation::Augment(first, second)
+            | Operation::StackHorizontally(first, second)
+            | Operation::StackVertically(first, second) => vec![first, second],
+            Operation::Scale(_, matrix)
+            | Operation::Trace(matrix)
+            | Operation::Transpose(matrix)
+            | Operation::Determinant(matrix)
+            | Operation::Adjoint(matrix)
+            | Operation::Inverse(matrix)
+            | Operation::Power(matrix, _)
+            | Operation::Exponential(matrix)
+            | Operation::SquareRoot(matrix)
+            | Operation::RowReduce(matrix)
+            | Operation::Rank(matrix)
+            | Operation::Norms(matrix)
+            | Operation::Apply(_, matrix) => vec![matrix],
+        }
+    }
+
+    // Every operand must be present in the workspace
+    pub fn evaluate(&self, workspace: &HashMap<String, Arc<Matrix>>) -> Outcome {
+        let matrix = |name: &String| workspace[name].deref();
+
+        match self {
+            Operation::Add(first, second) => match matrix(first) + matrix(second) {
+                Err(err) => Outcome::Failed(format!(
+                    "Dimensions of the two matrices do not match ({err})"
+                )),
+                Ok(sum) => Outcome::Matrix("Sum", Arc::new(sum)),
+            },
+            Operation::Subtract(first, second) => match matrix(first) - matrix(second) {
+                Err(err) => Outcome::Failed(format!(
+                    "Dimensions of the two matrices do not match ({err})"
+                )),
+                Ok(difference) => Outcome::Matrix("Difference", Arc::new(difference)),
+            },
+            Operation::Multiply(first, second) => match matrix(first) * matrix(second) {
+                Err(err) => Outcome::Failed(format!("Number of columns of the first matrix is not equal to the number of rows of the second matrix ({err})")),
+                Ok(product) => Outcome::Matrix("Product", Arc::new(product)),
+            },
+            Operation::Scale(scalar, name) => {
+                Outcome::Matrix("Scaled Matrix", Arc::new(*scalar * matrix(name)))
+            }
+            Operation::Trace(name) => match matrix(name).trace() {
+                Err(_) => Outcome::Failed("Only square matrices have traces".into()),
+                Ok(trace) => Outcome::Scalar("Trace", trace),
+            },
+            Operation::Transpose(name) => {
+                Outcome::Matrix("Transpose", Arc::new(matrix(name).transpose()))
+            }
+            Operation::Determinant(name) => match matrix(name).determinant() {
+                Err(_) => Outcome::Failed("Only square matrices have determinants".into()),
+                Ok(determinant) => Outcome::Scalar("Determinant", determinant),
+            },
+            Operation::Adjoint(name) => match matrix(name).adjoint() {
+                Err(_) => Outcome::Failed("Only square matrices have adjoints".into()),
+                Ok(adjoint) => Outcome::Matrix("Adjoint", Arc::new(adjoint)),
+            },
+            Operation::Inverse(name) => match matrix(name).inverse() {
+                Err(MatrixError::SingularMatrix) => {
+                    Outcome::Failed("Singular matrices do not have inverse".into())
+                }
+                Err(_) => Outcome::Failed("Only square matrices have inverses".into()),
+                Ok(inverse) => Outcome::Matrix("Inverse", Arc::new(inverse)),
+            },
+            Operation::Power(name, exponent) => match matrix(name).power(*exponent) {
+                Err(MatrixError::SingularMatrix) => {
+                    Outcome::Failed("Singular matrices cannot be raised to negative powers".into())
+                }
+                Err(_) => Outcome::Failed("Only square matrices have powers".into()),
+                Ok(power) => Outcome::Matrix("Power", Arc::new(power)),
+            },
+            Operation::Exponential(name) => match matrix(name).exponential() {
+                Err(MatrixError::NonSquareMatrix) => {
+                    Outcome::Failed("Only square matrices have exponentials".into())
+                }
+                Err(_) => Outcome::Failed("The exponential is too large to represent".into()),
+                Ok(exponential) => Outcome::Matrix("Exponential", Arc::new(exponential)),
+            },
+            Operation::SquareRoot(name) => match matrix(name).square_root() {
+                Err(MatrixError::NonSquareMatrix) => {
+                    Outcome::Failed("Only square matrices have square roots".into())
+                }
+                Err(MatrixError::NotPositiveSemidefinite) => Outcome::Failed(
+                    "Symmetric matrices with negative eigenvalues have no real square root".into(),
+                ),
+                Err(_) => Outcome::Failed("The matrix has no real principal square root".into()),
+                Ok(root) => Outcome::Matrix("Square Root", Arc::new(root)),
+            },
+            Operation::Solve(coefficients, constants) => {
+                match matrix(coefficients).solve_general(matrix(constants)) {
+                    Err(err) => Outcome::Failed(format!(
+                        "A and b should have the same number of rows ({err})"
+                    )),
+                    Ok(solution) => Outcome::Solution(solution),
+                }
+            }
+            Operation::Augment(coefficients, constants) => {
+                match matrix(coefficients).augment(matrix(constants)) {
+                    Err(err) => Outcome::Failed(format!(
+                        "A and b should have the same number of rows ({err})"
+                    )),
+                    Ok(augmented) => Outcome::Matrix("Augmented Matrix", Arc::new(augmented)),
+                }
+            }
+            Operation::StackHorizontally(first, second) => {
+                match matrix(first).augment(matrix(second)) {
+                    Err(err) => Outcome::Failed(format!(
+                        "Matrices placed side by side should have the same number of rows ({err})"
+                    )),
+                    Ok(stacked) => Outcome::Matrix("Stacked Matrix", Arc::new(stacked)),
+                }
+            }
+            Operation::StackVertically(first, second) => {
+                match matrix(first).stack(matrix(second)) {
+                    Err(err) => Outcome::Failed(format!(
+                        "Matrices placed one above the other should have the same number of columns ({err})"
+                    )),
+                    Ok(stacked) => Outcome::Matrix("Stacked Matrix", Arc::new(stacked)),
+                }
+            }
+            Operation::RowReduce(name) => {
+                let (reduced, pivot_columns) = matrix(name).row_reduce();
+                Outcome::Reduced(Arc::new(reduced), pivot_columns)
+            }
+            Operation::Rank(name) => {
+                let matrix = matrix(name);
+                Outcome::Rank(matrix.row_reduce().1, matrix.columns())
+            }
+            Operation::Norms(name) => {
+                let matrix = matrix(name);
+                let mut values = vec![
+                    ("1-norm", matrix.norm(Norm::One)),
+                    ("∞-norm", matrix.norm(Norm::Infinity)),
+                    ("Frobenius norm", matrix.norm(Norm::Frobenius)),
+                    ("2-norm", matrix.norm(Norm::Two)),
+                ];
+                if let Ok(condition_number) = matrix.condition_number(Norm::Two) {
+                    values.push(("Condition number", condition_number));
+                }
+                Outcome::Norms {
+                    values,
+                    singular: matrix.is_singular(),
+                }
+            }
+            Operation::Apply(function, name) => match matrix(name).map(|num| function.apply(num))
+            {
+                Err(_) => Outcome::Failed(
+                    match function {
+                    ElementFunction::SquareRoot => {
+                        "Negative elements do not have real square roots"
+                    }
+                    ElementFunction::Reciprocal => "Elements equal to zero do not have reciprocals",
+                    _ => "Some of the results are too large to represent",
+                }
+                    .into(),
+                ),
+                Ok(result) => Outcome::Matrix("Result", Arc::new(result)),
+            },
+        }
+    }
+}
+
+impl ElementFunction {
+    pub fn apply(self, num: f64) -> f64 {
+        match self {
+            ElementFunction::Abs => num.abs(),
+            ElementFunction::Square => num * num,
+            ElementFunction::SquareRoot => num.sqrt(),
+            ElementFunction::Reciprocal => 1.0 / num,
+            ElementFunction::Round(decimals) => {
+                let factor = 10_f64.powi(decimals as i32);
+                (num * factor).round() / factor
+            }
+            ElementFunction::Affine(a, b) => a * num + b,
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Add(first, second) => write!(f, "{first} + {second}"),
+            Operation::Subtract(first, second) => write!(f, "{first} - {second}"),
+            Operation::Multiply(first, second) => write!(f, "{first} × {second}"),
+            Operation::Scale(scalar, matrix) => write!(f, "{scalar} × {matrix}"),
+            Operation::Trace(matrix) => write!(f, "tr({matrix})"),
+            Operation::Transpose(matrix) => write!(f, "{matrix}ᵀ"),
+            Operation::Determinant(matrix) => write!(f, "det({matrix})"),
+            Operation::Adjoint(matrix) => write!(f, "adj({matrix})"),
+            Operation::Inverse(matrix) => write!(f, "{matrix}⁻¹"),
+            Operation::Power(matrix, exponent) => write!(f, "{matrix}^{exponent}"),
+            Operation::Exponential(matrix) => write!(f, "exp({matrix})"),
+            Operation::SquareRoot(matrix) => write!(f, "sqrt({matrix})"),
+            Operation::Solve(coefficients, constants) => {
+                write!(f, "solve {coefficients}x = {constants}")
+            }
+            Operation::Augment(coefficients, constants) => {
+                write!(f, "[{coefficients} | {constants}]")
+            }
+            Operation::StackHorizontally(first, second) => write!(f, "[{first} {second}]"),
+            Operation::StackVertically(first, second) => write!(f, "[{first}; {second}]"),
+            Operation::RowReduce(matrix) => write!(f, "rref({matrix})"),
+            Operation::Rank(matrix) => write!(f, "rank({matrix})"),
+            Operation::Norms(matrix) => write!(f, "‖{matrix}‖"),
+            Operation::Apply(function, matrix) => match function {
+                ElementFunction::Abs => write!(f, "abs.({matrix})"),
+                ElementFunction::Square => write!(f, "{matrix}.^2"),
+                ElementFunction::SquareRoot => write!(f, "sqrt.({matrix})"),
+                ElementFunction::Reciprocal => write!(f, "1 ./ {matrix}"),
+                ElementFunction::Round(decimals) => write!(f, "round.({matrix}, {decimals})"),
+                ElementFunction::Affine(a, b) => write!(f, "{a} .* {matrix} .+ {b}"),
+            },
+        }
+    }
+}
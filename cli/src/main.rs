@@ -0,0 +1,2519 @@
+use clap::Parser;
+use cliclack::log;
+use csv::Delimiter;
+use history::{ElementFunction, Entry, Operation, Outcome, ILL_CONDITIONED};
+use matrix_core::{
+    stats, Border, Distribution, FormatOptions, LinearSystemSolution, Matrix, MatrixError,
+    MatrixRecord, Notation, Property,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    io::{self, IsTerminal},
+    ops::{Deref, Range},
+    process,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use transcript::Transcript;
+
+mod autosave;
+mod clipboard;
+mod commands;
+mod csv;
+mod display;
+mod editor;
+mod expression;
+mod history;
+mod progress;
+mod settings;
+mod transcript;
+
+fn main() -> io::Result<()> {
+    let args = commands::Args::parse();
+    if let Some(command) = args.command {
+        if let Err(err) = commands::run(command) {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Without a terminal there is no one to answer the prompts, so a piped matrix is handled instead
+    if args.piped.is_requested() || !io::stdin().is_terminal() {
+        if let Err(err) = commands::run_piped(args.piped) {
+            eprintln!("error: {err}");
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let cli = Cli {
+        matrices: RefCell::new(HashMap::new()),
+        format: Cell::new(settings::load()),
+        scalars: RefCell::new(BTreeMap::new()),
+        tags: RefCell::new(BTreeMap::new()),
+        descriptions: RefCell::new(BTreeMap::new()),
+        undo_stack: RefCell::new(vec![]),
+        history: RefCell::new(vec![]),
+        last_result: RefCell::new(None),
+        transcript: RefCell::new(None),
+        clipboard: RefCell::new(None),
+    };
+
+    cli.start()?;
+    Ok(())
+}
+
+// Maps a prompt cancelled with Esc or Ctrl-C to Ok(false), so the caller can return to its menu
+fn completed<T>(result: io::Result<T>) -> io::Result<bool> {
+    match result {
+        Err(err) if err.kind() == io::ErrorKind::Interrupted => Ok(false),
+        result => result.map(|_| true),
+    }
+}
+
+// Oldest snapshots are dropped once the undo stack grows past this
+const UNDO_LIMIT: usize = 50;
+
+type Workspace = HashMap<String, Arc<Matrix>>;
+
+// Name that refers to the matrix produced by the last operation
+const LAST_RESULT: &str = "ans";
+
+// Matrices are shared through Arc, so a snapshot only copies the names and pointers
+struct Snapshot {
+    matrices: Workspace,
+    scalars: BTreeMap<String, f64>,
+    tags: Tags,
+    descriptions: BTreeMap<String, String>,
+}
+
+// Tags of each matrix by its name, for grouping matrices in large workspaces
+type Tags = BTreeMap<String, BTreeSet<String>>;
+
+// Saved workspaces store scalars as plain numbers next to the matrices, which are written as records
+// with their name, tags and description. Matrices without metadata are plain records, so older files
+// still load
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum Variable {
+    Scalar(f64),
+    Matrix(MatrixRecord),
+    // How tagged matrices were saved before tags became part of the record
+    Tagged {
+        matrix: Matrix,
+        tags: BTreeSet<String>,
+    },
+}
+
+fn validate_name(input: &String) -> Result<(), &'static str> {
+    if input.starts_with(|charecter: char| charecter.is_numeric()) {
+        return Err("Names cannot start with a number");
+    }
+
+    if input
+        .chars()
+        .any(|charecter: char| charecter.is_whitespace())
+    {
+        return Err("Names cannot have whitespaces in them");
+    }
+
+    if input == LAST_RESULT {
+        return Err("This name is reserved for the result of the last operation");
+    }
+
+    Ok(())
+}
+
+fn parse_range(input: &str, count: usize) -> Option<Range<usize>> {
+    let (start, end) = input.split_once('-').unwrap_or((input, input));
+    let (start, end) = (
+        start.trim().parse::<usize>().ok()?,
+        end.trim().parse::<usize>().ok()?,
+    );
+
+    (1 <= start && start <= end && end <= count).then_some(start - 1..end)
+}
+
+fn range_label(range: &Range<usize>) -> String {
+    if range.len() == 1 {
+        (range.start + 1).to_string()
+    } else {
+        format!("{}-{}", range.start + 1, range.end)
+    }
+}
+
+// Columns are numbered from 1, and every column without a pivot is a free variable of Ax = 0
+fn pivot_report(pivot_columns: &[usize], columns: usize) -> String {
+    let list = |columns: Vec<String>| {
+        if columns.is_empty() {
+            "none".to_string()
+        } else {
+            columns.join(", ")
+        }
+    };
+
+    format!(
+        "Pivot columns: {}\nFree variables: {}",
+        list(
+            pivot_columns
+                .iter()
+                .map(|column| (column + 1).to_string())
+                .collect()
+        ),
+        list(
+            (0..columns)
+                .filter(|column| !pivot_columns.contains(column))
+                .map(|column| format!("x{}", column + 1))
+                .collect()
+        )
+    )
+}
+
+fn whole_number(num: f64) -> Result<i32, String> {
+    if num.fract() != 0.0 {
+        return Err(format!("{num} has a fractional part"));
+    }
+    if num.abs() > i32::MAX as f64 {
+        return Err(format!("{num} is too large"));
+    }
+
+    Ok(num as i32)
+}
+
+struct Cli {
+    matrices: RefCell<Workspace>,
+    scalars: RefCell<BTreeMap<String, f64>>,
+    tags: RefCell<Tags>,
+    descriptions: RefCell<BTreeMap<String, String>>,
+    format: Cell<FormatOptions>,
+    undo_stack: RefCell<Vec<Snapshot>>,
+    history: RefCell<Vec<Entry>>,
+    last_result: RefCell<Option<Arc<Matrix>>>,
+    // Some while the session is being recorded
+    transcript: RefCell<Option<Transcript>>,
+    // Created on the first copy and kept so the copied text stays available
+    clipboard: RefCell<Option<arboard::Clipboard>>,
+}
+
+impl Cli {
+    fn show_matrix(&self, title: &str, matrix: &Matrix) -> io::Result<()> {
+        self.record(transcript::Entry::Matrix(
+            title.to_string(),
+            Arc::new(matrix.clone()),
+        ));
+        display::show_matrix(title, matrix, &self.format.get())
+    }
+
+    fn record(&self, entry: transcript::Entry) {
+        if let Some(transcript) = self.transcript.borrow_mut().as_mut() {
+            transcript.push(entry);
+        }
+    }
+
+    // Logs a result and adds it to the transcript when recording
+    fn log_result(&self, log: fn(String) -> io::Result<()>, message: String) -> io::Result<()> {
+        self.record(transcript::Entry::Text(message.clone()));
+        log(message)
+    }
+
+    fn add_matrix(&self, name: String, matrix: Matrix) -> io::Result<()> {
+        self.record(transcript::Entry::Step(format!("Created {name}")));
+        self.show_matrix(&name, &matrix)?;
+        self.insert_matrix(name, Arc::new(matrix));
+        self.autosave()
+    }
+
+    fn format_number(&self, num: f64) -> String {
+        self.format.get().format_number(num)
+    }
+
+    fn start(&self) -> io::Result<()> {
+        cliclack::clear_screen()?;
+        cliclack::intro("Matrix Toolkit")?;
+        completed(self.restore_autosave())?;
+
+        loop {
+            match self.main_menu() {
+                Ok(true) => continue,
+                Err(err) if err.kind() != io::ErrorKind::Interrupted => return Err(err),
+                _ => break,
+            }
+        }
+
+        if self
+            .transcript
+            .borrow()
+            .as_ref()
+            .is_some_and(|transcript| !transcript.is_empty())
+            && cliclack::confirm("Do you want to export the session transcript?")
+                .initial_value(true)
+                .interact()?
+        {
+            completed(self.export_transcript())?;
+        }
+
+        cliclack::outro("Goodbye!")?;
+        Ok(())
+    }
+
+    // Returns Ok(false) once the user chooses to exit
+    fn main_menu(&self) -> io::Result<bool> {
+        let mut menu = cliclack::select("What do you want to do?")
+            .item("create", "Create a Matrix", "")
+            .item("edit", "Edit a Matrix", "")
+            .item("operate", "Perform Matrix Operations", "")
+            .item("properties", "Check Matrix Properties", "")
+            .item("markov", "Markov Chain Analysis", "")
+            .item("fit", "Curve Fitting", "least-squares polynomial")
+            .item(
+                "transforms",
+                "Transformation Playground",
+                "rotate, scale and move points",
+            )
+            .item("save", "Save Workspace", "")
+            .item("load", "Load Workspace", "");
+        if !self.history.borrow().is_empty() {
+            menu = menu.item("history", "Operation History", "");
+        }
+        if !self.undo_stack.borrow().is_empty() {
+            menu = menu.item("undo", "Undo", "revert the last change to the workspace");
+        }
+        if self.transcript.borrow().is_none() {
+            menu = menu.item("record", "Record Session Transcript", "");
+        } else {
+            menu = menu.item("record", "Export Session Transcript", "and stop recording");
+        }
+        let action = menu
+            .item("matrices", "Manage Matrices", "list, tag and describe them")
+            .item("scalars", "Scalar Variables", "")
+            .item("settings", "Display Settings", "")
+            .item("exit", "Exit", "")
+            .interact()?;
+
+        let snapshot = Snapshot {
+            matrices: self.matrices.borrow().clone(),
+            scalars: self.scalars.borrow().clone(),
+            tags: self.tags.borrow().clone(),
+            descriptions: self.descriptions.borrow().clone(),
+        };
+        let result = match action {
+            "create" => self.create_matrix(),
+            "edit" => self.edit_matrix(),
+            "operate" => self.perform_operations(),
+            "properties" => self.check_properties(),
+            "markov" => self.analyze_markov_chain(),
+            "fit" => self.fit_curve(),
+            "transforms" => self.transformation_playground(),
+            "save" => self.save_workspace(),
+            "load" => self.load_workspace(),
+            "history" => self.show_history(),
+            "undo" => return self.undo().and_then(|_| self.autosave()).map(|_| true),
+            "matrices" => self.manage_matrices(),
+            "scalars" => self.manage_scalars(),
+            "record" => self.toggle_recording(),
+            "settings" => self.change_settings(),
+            "exit" => return Ok(false),
+            _ => unreachable!(),
+        };
+
+        // Record the snapshot even when the action was cancelled part way, since earlier steps may have changed the workspace
+        let changed = {
+            let matrices = self.matrices.borrow();
+            *self.scalars.borrow() != snapshot.scalars
+                || *self.tags.borrow() != snapshot.tags
+                || *self.descriptions.borrow() != snapshot.descriptions
+                || matrices.len() != snapshot.matrices.len()
+                || matrices.iter().any(|(name, matrix)| {
+                    snapshot
+                        .matrices
+                        .get(name)
+                        .is_none_or(|previous| !Arc::ptr_eq(previous, matrix))
+                })
+        };
+        if changed {
+            {
+                let mut undo_stack = self.undo_stack.borrow_mut();
+                if undo_stack.len() == UNDO_LIMIT {
+                    undo_stack.remove(0);
+                }
+                undo_stack.push(snapshot);
+            }
+            self.autosave()?;
+        }
+
+        completed(result)?;
+        Ok(true)
+    }
+
+    fn undo(&self) -> io::Result<()> {
+        let Some(snapshot) = self.undo_stack.borrow_mut().pop() else {
+            return Ok(());
+        };
+
+        let mut matrices = self.matrices.borrow_mut();
+        let mut scalars = self.scalars.borrow_mut();
+        let mut removed: Vec<&str> = matrices
+            .keys()
+            .filter(|name| !snapshot.matrices.contains_key(*name))
+            .chain(
+                scalars
+                    .keys()
+                    .filter(|name| !snapshot.scalars.contains_key(*name)),
+            )
+            .map(String::as_str)
+            .collect();
+        let mut restored: Vec<&str> = snapshot
+            .matrices
+            .iter()
+            .filter(|(name, matrix)| {
+                matrices
+                    .get(*name)
+                    .is_none_or(|current| !Arc::ptr_eq(current, matrix))
+            })
+            .map(|(name, _)| name)
+            .chain(
+                snapshot
+                    .scalars
+                    .iter()
+                    .filter(|(name, value)| scalars.get(*name) != Some(value))
+                    .map(|(name, _)| name),
+            )
+            .map(String::as_str)
+            .collect();
+        removed.sort();
+        restored.sort();
+
+        let mut summary = vec![];
+        if !removed.is_empty() {
+            summary.push(format!("removed {}", removed.join(", ")));
+        }
+        if !restored.is_empty() {
+            summary.push(format!("restored {}", restored.join(", ")));
+        }
+        let mut tags = self.tags.borrow_mut();
+        if *tags != snapshot.tags {
+            summary.push("restored the tags".to_string());
+        }
+        let mut descriptions = self.descriptions.borrow_mut();
+        if *descriptions != snapshot.descriptions {
+            summary.push("restored the descriptions".to_string());
+        }
+        log::success(format!("Undid the last change: {}", summary.join(", ")))?;
+
+        *matrices = snapshot.matrices;
+        *scalars = snapshot.scalars;
+        *tags = snapshot.tags;
+        *descriptions = snapshot.descriptions;
+        Ok(())
+    }
+
+    fn prompt_name(&self) -> io::Result<String> {
+        self.prompt_variable_name("Name of the matrix")
+    }
+
+    // An existing name can be overwritten on purpose; Undo brings the old value back
+    fn prompt_variable_name(&self, prompt: &'static str) -> io::Result<String> {
+        let mut prompt = prompt;
+        loop {
+            let name: String = cliclack::input(prompt)
+                .validate_interactively(validate_name)
+                .interact()?;
+
+            let kind = if self.matrices.borrow().contains_key(&name) {
+                "matrix"
+            } else if self.scalars.borrow().contains_key(&name) {
+                "scalar"
+            } else {
+                return Ok(name);
+            };
+
+            match cliclack::select(format!("A {kind} named {name} already exists"))
+                .item("overwrite", format!("Overwrite {name}"), "")
+                .item("rename", "Choose a new name", "")
+                .item("cancel", "Cancel", "")
+                .interact()?
+            {
+                "overwrite" => return Ok(name),
+                "rename" => prompt = "Choose a new name",
+                _ => return Err(io::ErrorKind::Interrupted.into()),
+            }
+        }
+    }
+
+    // Matrices and scalars share one namespace, so storing one replaces the other
+    fn insert_matrix(&self, name: String, matrix: Arc<Matrix>) {
+        self.scalars.borrow_mut().remove(&name);
+        self.matrices.borrow_mut().insert(name, matrix);
+    }
+
+    fn insert_scalar(&self, name: String, value: f64) {
+        self.matrices.borrow_mut().remove(&name);
+        self.tags.borrow_mut().remove(&name);
+        self.descriptions.borrow_mut().remove(&name);
+        self.scalars.borrow_mut().insert(name, value);
+    }
+
+    fn prompt_size(&self, prompt: &'static str, err_msg: &'static str) -> io::Result<usize> {
+        let size = cliclack::input(prompt)
+            .validate_interactively(move |input: &String| {
+                if !input.parse::<usize>().is_ok_and(|num| num > 0) {
+                    Err(err_msg)
+                } else {
+                    Ok(())
+                }
+            })
+            .interact()?;
+
+        Ok(size)
+    }
+
+    // Asks for a single position from 1 to `count` and returns it counted from 0
+    fn prompt_index(&self, prompt: &'static str, count: usize) -> io::Result<usize> {
+        let index: usize = cliclack::input(prompt)
+            .placeholder(&format!("1 to {count}"))
+            .validate_interactively(move |input: &String| {
+                if input
+                    .parse::<usize>()
+                    .is_ok_and(|index| (1..=count).contains(&index))
+                {
+                    Ok(())
+                } else {
+                    Err(format!("It should be a whole number from 1 to {count}"))
+                }
+            })
+            .interact()?;
+
+        Ok(index - 1)
+    }
+
+    // Accepts a single position such as `2` or an inclusive span such as `2-4`, counted from 1
+    fn prompt_range(&self, prompt: &'static str, count: usize) -> io::Result<Range<usize>> {
+        let input: String = cliclack::input(prompt)
+            .placeholder(&format!("e.g. 1-{count}"))
+            .validate_interactively(move |input: &String| {
+                parse_range(input, count).map(|_| ()).ok_or(format!(
+                    "It should be a number or a span like 1-{count} between 1 and {count}"
+                ))
+            })
+            .interact()?;
+
+        Ok(parse_range(&input, count).unwrap())
+    }
+
+    // Evaluates numbers typed by the user, which may be fractions, expressions or names of scalar variables
+    fn evaluator(&self) -> impl Fn(&str) -> Result<f64, String> + 'static {
+        let scalars = self.scalars.borrow().clone();
+        move |input| expression::evaluate(input, &scalars)
+    }
+
+    fn prompt_number(&self, prompt: &'static str, err_msg: &'static str) -> io::Result<f64> {
+        let evaluate = self.evaluator();
+        let input: String = cliclack::input(prompt)
+            .validate_interactively(move |input: &String| match evaluate(input) {
+                Err(err) if !input.trim().is_empty() => Err(format!("{err_msg} ({err})")),
+                Err(_) => Err(err_msg.to_string()),
+                Ok(_) => Ok(()),
+            })
+            .interact()?;
+
+        Ok(self.evaluator()(&input).unwrap())
+    }
+
+    fn prompt_scalar(&self, prompt: &'static str) -> io::Result<f64> {
+        self.prompt_number(
+            prompt,
+            "The scalar should be a real number or the name of a scalar variable",
+        )
+    }
+
+    // Negative exponents are allowed and use the inverse
+    fn prompt_exponent(&self) -> io::Result<i32> {
+        let evaluate = self.evaluator();
+        let input: String = cliclack::input("The exponent k")
+            .validate_interactively(move |input: &String| {
+                match evaluate(input).and_then(whole_number) {
+                    Err(err) if !input.trim().is_empty() => {
+                        Err(format!("The exponent should be a whole number ({err})"))
+                    }
+                    Err(_) => Err("The exponent should be a whole number".to_string()),
+                    Ok(_) => Ok(()),
+                }
+            })
+            .interact()?;
+
+        Ok(self.evaluator()(&input).and_then(whole_number).unwrap())
+    }
+
+    // Dimensions followed by the tags, such as `2×3 #homework3 #temp`
+    fn describe_matrix(&self, name: &str, matrix: &Matrix) -> String {
+        let mut description = format!("{}×{}", matrix.rows(), matrix.columns());
+        if let Some(tags) = self.tags.borrow().get(name) {
+            for tag in tags {
+                description.push_str(&format!(" #{tag}"));
+            }
+        }
+        description
+    }
+
+    // Tags and descriptions belong to named matrices, not to the last result
+    fn prompt_named_matrix(&self, action: &str) -> io::Result<String> {
+        let mut name = self.prompt_matrix_name("Name of the matrix")?;
+        while name == LAST_RESULT {
+            log::error(format!("Save the result under a name before {action} it"))?;
+            name = self.prompt_matrix_name("Name of the matrix")?;
+        }
+        Ok(name)
+    }
+
+    fn manage_matrices(&self) -> io::Result<()> {
+        loop {
+            let mut menu = cliclack::select("Matrices").item("list", "List matrices", "");
+            if !self.matrices.borrow().is_empty() {
+                menu = menu
+                    .item("tag", "Tag a matrix", "such as homework3 or temp")
+                    .item("describe", "Describe a matrix", "a note shown in the list");
+            }
+
+            match menu.item("back", "Back to main menu", "").interact()? {
+                "list" => {
+                    let all_tags: BTreeSet<String> =
+                        self.tags.borrow().values().flatten().cloned().collect();
+                    let tag = if all_tags.is_empty() {
+                        None
+                    } else {
+                        let mut choice = cliclack::select("Which matrices?").filter_mode().item(
+                            None,
+                            "All matrices",
+                            "",
+                        );
+                        for tag in &all_tags {
+                            let count = self
+                                .tags
+                                .borrow()
+                                .values()
+                                .filter(|tags| tags.contains(tag))
+                                .count();
+                            choice = choice.item(Some(tag.clone()), tag, format!("{count} tagged"));
+                        }
+                        choice.interact()?
+                    };
+
+                    let matrices = self.matrices.borrow();
+                    let mut names: Vec<&String> = matrices
+                        .keys()
+                        .filter(|name| {
+                            tag.as_ref().is_none_or(|tag| {
+                                self.tags
+                                    .borrow()
+                                    .get(*name)
+                                    .is_some_and(|tags| tags.contains(tag))
+                            })
+                        })
+                        .collect();
+                    names.sort();
+
+                    if names.is_empty() {
+                        log::info("No matrices are created yet")?;
+                    } else {
+                        log::info(
+                            names
+                                .iter()
+                                .map(|name| {
+                                    let mut line = format!(
+                                        "{name}: {}",
+                                        self.describe_matrix(name, &matrices[*name])
+                                    );
+                                    if let Some(description) = self.descriptions.borrow().get(*name)
+                                    {
+                                        line.push_str(&format!(" — {description}"));
+                                    }
+                                    line
+                                })
+                                .collect::<Vec<_>>()
+                                .join("\n"),
+                        )?;
+                    }
+                }
+                "tag" => {
+                    let name = self.prompt_named_matrix("tagging")?;
+                    let current = self
+                        .tags
+                        .borrow()
+                        .get(&name)
+                        .map(|tags| tags.iter().cloned().collect::<Vec<_>>().join(" "))
+                        .unwrap_or_default();
+                    let input: String = cliclack::input("Tags")
+                        .placeholder("separated by spaces, or empty to remove them all")
+                        .default_input(&current)
+                        .required(false)
+                        .interact()?;
+                    let tags: BTreeSet<String> = input
+                        .split(|character: char| character == ',' || character.is_whitespace())
+                        .filter(|tag| !tag.is_empty())
+                        .map(|tag| tag.trim_start_matches('#').to_string())
+                        .filter(|tag| !tag.is_empty())
+                        .collect();
+
+                    if tags.is_empty() {
+                        self.tags.borrow_mut().remove(&name);
+                        log::success(format!("Removed the tags of {name}"))?;
+                    } else {
+                        log::success(format!(
+                            "Tagged {name} with {}",
+                            tags.iter().cloned().collect::<Vec<_>>().join(", ")
+                        ))?;
+                        self.tags.borrow_mut().insert(name, tags);
+                    }
+                }
+                "describe" => {
+                    let name = self.prompt_named_matrix("describing")?;
+                    let current = self
+                        .descriptions
+                        .borrow()
+                        .get(&name)
+                        .cloned()
+                        .unwrap_or_default();
+                    let input: String = cliclack::input("Description")
+                        .placeholder("or empty to remove it")
+                        .default_input(&current)
+                        .required(false)
+                        .interact()?;
+
+                    let description = input.trim();
+                    if description.is_empty() {
+                        self.descriptions.borrow_mut().remove(&name);
+                        log::success(format!("Removed the description of {name}"))?;
+                    } else {
+                        self.descriptions
+                            .borrow_mut()
+                            .insert(name.clone(), description.to_string());
+                        log::success(format!("Described {name}"))?;
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn manage_scalars(&self) -> io::Result<()> {
+        loop {
+            let mut menu = cliclack::select("Scalar variables")
+                .item("define", "Define a scalar", "")
+                .item("list", "List scalars", "");
+            if !self.scalars.borrow().is_empty() {
+                menu = menu.item("delete", "Delete a scalar", "");
+            }
+
+            match menu.item("back", "Back to main menu", "").interact()? {
+                "define" => {
+                    let name = self.prompt_variable_name("Name of the scalar")?;
+                    let value = self.prompt_scalar("Value of the scalar")?;
+                    self.record(transcript::Entry::Step(format!("Defined {name}")));
+                    self.log_result(
+                        log::success,
+                        format!("{name} = {}", self.format_number(value)),
+                    )?;
+                    self.insert_scalar(name, value);
+                }
+                "list" => {
+                    let scalars = self.scalars.borrow();
+                    if scalars.is_empty() {
+                        log::info("No scalars are defined yet")?;
+                    } else {
+                        log::info(
+                            scalars
+                                .iter()
+                                .map(|(name, value)| {
+                                    format!("{name} = {}", self.format_number(*value))
+                                })
+                                .collect::<Vec<_>>()
+                                .join("\n"),
+                        )?;
+                    }
+                }
+                "delete" => {
+                    let names: Vec<String> = self.scalars.borrow().keys().cloned().collect();
+                    let mut choice = cliclack::select("Which scalar?");
+                    for name in &names {
+                        choice = choice.item(name.as_str(), name, "");
+                    }
+                    let name = choice.interact()?;
+                    self.scalars.borrow_mut().remove(name);
+                    log::success(format!("Deleted {name}"))?;
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn create_matrix(&self) -> io::Result<()> {
+        loop {
+            let creation_method = cliclack::select("How do you want to create the matrix?")
+                .item("manual", "Enter matrix elements manually", "")
+                .item("grid", "Enter matrix elements in a grid editor", "")
+                .item("file", "Import from a CSV file", "")
+                .item("zero", "Create a zero matrix", "")
+                .item("identity", "Create an identity matrix", "")
+                .item("scalar", "Create a scalar matrix", "")
+                .item("diagonal", "Create a diagonal matrix", "")
+                .item("random", "Create a random matrix", "")
+                .item("special", "Create a special matrix", "")
+                .item(
+                    "extract",
+                    "Extract part of a matrix",
+                    "a submatrix, row, column or diagonal",
+                )
+                .item("back", "Back to main menu", "")
+                .interact()?;
+
+            if let "back" = creation_method {
+                return Ok(());
+            }
+
+            if completed(self.create(creation_method))?
+                && !cliclack::confirm("Do you want to create another matrix?").interact()?
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    fn create(&self, creation_method: &str) -> io::Result<()> {
+        match creation_method {
+            "manual" => {
+                let matrix_name = self.prompt_name()?;
+                let evaluate = self.evaluator();
+                let input_string: String = cliclack::input("Enter the elements")
+                    .multiline()
+                    .validate_interactively(move |input: &String| {
+                        // Matrices pasted from MATLAB or Octave, such as [1 2; 3 4]
+                        if input.contains('[') {
+                            return Matrix::parse_matlab(input).map(|_| ()).map_err(|err| {
+                                format!("It should be a matrix like [1 2; 3 4] ({err})")
+                            });
+                        }
+
+                        if let Some(err) = input
+                            .split_whitespace()
+                            .find_map(|value| evaluate(value).err())
+                        {
+                            return Err(format!(
+                                "It should be real numbers seperated by a whitespace on each line ({err})"
+                            ));
+                        }
+
+                        let mut lines = input.lines();
+                        let first_row_length =
+                            lines.next().unwrap_or("").split_whitespace().count();
+
+                        if first_row_length == 0 {
+                            return Err("The first row should have at least one element".to_string());
+                        }
+
+                        if !lines.all(|line| line.split_whitespace().count() == first_row_length) {
+                            return Err(
+                                "Every row should have the same number of elements".to_string()
+                            );
+                        }
+
+                        Ok(())
+                    })
+                    .interact()?;
+
+                let matrix = if input_string.contains('[') {
+                    Matrix::parse_matlab(&input_string).unwrap()
+                } else {
+                    let evaluate = self.evaluator();
+                    let values = input_string
+                        .lines()
+                        .map(|line| {
+                            line.split_whitespace()
+                                .map(|num| evaluate(num).unwrap())
+                                .collect()
+                        })
+                        .collect();
+                    Matrix::new_with_data(values).unwrap()
+                };
+                self.add_matrix(matrix_name, matrix)?;
+            }
+            "grid" => {
+                let matrix_name = self.prompt_name()?;
+                let rows = self.prompt_size(
+                    "Number of rows",
+                    "Number of rows should be a whole number greater than 0",
+                )?;
+                let columns = self.prompt_size(
+                    "Number of columns",
+                    "Number of columns should be a whole number greater than 0",
+                )?;
+
+                let zero_matrix = Matrix::new_zero_matrix(rows, columns).unwrap();
+                let Some(matrix) =
+                    editor::edit_matrix(&matrix_name, &zero_matrix, &self.scalars.borrow())?
+                else {
+                    return Err(io::ErrorKind::Interrupted.into());
+                };
+                self.add_matrix(matrix_name, matrix)?;
+            }
+            "file" => {
+                let matrix_name = self.prompt_name()?;
+                let path: String = cliclack::input("Path of the file").interact()?;
+                let delimiter = cliclack::select("How are the elements separated?")
+                    .item(
+                        Delimiter::Auto,
+                        "Detect automatically",
+                        "commas or whitespace",
+                    )
+                    .item(Delimiter::Char(','), "Commas", "")
+                    .item(Delimiter::Char(';'), "Semicolons", "")
+                    .item(Delimiter::Char('\t'), "Tabs", "")
+                    .item(Delimiter::Whitespace, "Whitespace", "")
+                    .interact()?;
+                let has_header = cliclack::confirm("Does the file have a header row?")
+                    .initial_value(false)
+                    .interact()?;
+
+                match csv::import_matrix(path, delimiter, has_header) {
+                    Err(err) => log::error(format!("Could not import the matrix: {err}"))?,
+                    Ok(matrix) => {
+                        self.add_matrix(matrix_name, matrix)?;
+                    }
+                };
+            }
+            "zero" => {
+                let matrix_name = self.prompt_name()?;
+                let rows = self.prompt_size(
+                    "Number of rows",
+                    "Number of rows should be a whole number greater than 0",
+                )?;
+                let columns = self.prompt_size(
+                    "Number of columns",
+                    "Number of columns should be a whole number greater than 0",
+                )?;
+
+                let matrix = Matrix::new_zero_matrix(rows, columns).unwrap();
+                self.add_matrix(matrix_name, matrix)?;
+            }
+            "identity" => {
+                let matrix_name = self.prompt_name()?;
+                let size = self.prompt_size(
+                    "Size of the matrix",
+                    "Size should be a whole number greater than 0",
+                )?;
+
+                let matrix = Matrix::nth_identity(size).unwrap();
+                self.add_matrix(matrix_name, matrix)?;
+            }
+            "scalar" => {
+                let matrix_name = self.prompt_name()?;
+                let scalar = self.prompt_scalar("The scalar value")?;
+
+                let size = self.prompt_size(
+                    "Size of the matrix",
+                    "Size should be a whole number greater than 0",
+                )?;
+
+                let matrix: Matrix = Matrix::new_scalar_matrix(scalar, size).unwrap();
+                self.add_matrix(matrix_name, matrix)?;
+            }
+            "diagonal" => {
+                let matrix_name = self.prompt_name()?;
+                let evaluate = self.evaluator();
+                let input_string: String = cliclack::input("Diagonal elements")
+                    .validate_interactively(move |input: &String| {
+                        match input
+                            .split_whitespace()
+                            .find_map(|value| evaluate(value).err())
+                        {
+                            Some(err) => Err(format!(
+                                "It should be real numbers seperated by a whitespace ({err})"
+                            )),
+                            None => Ok(()),
+                        }
+                    })
+                    .interact()?;
+
+                let evaluate = self.evaluator();
+                let values = input_string
+                    .split_whitespace()
+                    .map(|num| evaluate(num).unwrap())
+                    .collect::<Vec<_>>();
+
+                let matrix: Matrix = Matrix::new_diagonal_matrix(&values).unwrap();
+                self.add_matrix(matrix_name, matrix)?;
+            }
+            "random" => {
+                let matrix_name = self.prompt_name()?;
+                let rows = self.prompt_size(
+                    "Number of rows",
+                    "Number of rows should be a whole number greater than 0",
+                )?;
+                let columns = self.prompt_size(
+                    "Number of columns",
+                    "Number of columns should be a whole number greater than 0",
+                )?;
+
+                let distribution = match cliclack::select("Distribution of the elements")
+                    .item(
+                        "uniform",
+                        "Uniform",
+                        "every value in a range is equally likely",
+                    )
+                    .item("normal", "Normal", "values cluster around a mean")
+                    .interact()?
+                {
+                    "uniform" => {
+                        let low = self.prompt_number(
+                            "Lower bound of the range",
+                            "The bound should be a real number",
+                        )?;
+                        let mut high = self.prompt_number(
+                            "Upper bound of the range",
+                            "The bound should be a real number",
+                        )?;
+                        while high < low {
+                            log::error("The upper bound cannot be less than the lower bound")?;
+                            high = self.prompt_number(
+                                "Upper bound of the range",
+                                "The bound should be a real number",
+                            )?;
+                        }
+                        Distribution::Uniform { low, high }
+                    }
+                    _ => {
+                        let mean =
+                            self.prompt_number("Mean", "The mean should be a real number")?;
+                        let mut standard_deviation = self.prompt_number(
+                            "Standard deviation",
+                            "The standard deviation should be a real number",
+                        )?;
+                        while standard_deviation < 0.0 {
+                            log::error("The standard deviation cannot be negative")?;
+                            standard_deviation = self.prompt_number(
+                                "Standard deviation",
+                                "The standard deviation should be a real number",
+                            )?;
+                        }
+                        Distribution::Normal {
+                            mean,
+                            standard_deviation,
+                        }
+                    }
+                };
+
+                let seed: String = cliclack::input("Seed")
+                    .placeholder("leave empty for a random seed")
+                    .required(false)
+                    .validate_interactively(|input: &String| {
+                        if !input.is_empty() && input.parse::<u64>().is_err() {
+                            Err("The seed should be a whole number")
+                        } else {
+                            Ok(())
+                        }
+                    })
+                    .interact()?;
+                let seed = seed.parse().unwrap_or_else(|_| {
+                    SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .map_or(0, |duration| duration.as_nanos() as u64)
+                });
+
+                let matrix = Matrix::new_random(rows, columns, distribution, seed).unwrap();
+                log::info(format!("Generated with seed {seed}"))?;
+                self.add_matrix(matrix_name, matrix)?;
+            }
+            "special" => {
+                let kind = cliclack::select("Which special matrix?")
+                    .item("hilbert", "Hilbert", "elements 1 / (i + j - 1)")
+                    .item("vandermonde", "Vandermonde", "powers of the given points")
+                    .item(
+                        "rotation",
+                        "Rotation",
+                        "rotates within the plane of two axes",
+                    )
+                    .item(
+                        "permutation",
+                        "Permutation",
+                        "reorders rows when multiplied",
+                    )
+                    .interact()?;
+                let matrix_name = self.prompt_name()?;
+
+                let matrix = match kind {
+                    "hilbert" => {
+                        let size = self.prompt_size(
+                            "Size of the matrix",
+                            "Size should be a whole number greater than 0",
+                        )?;
+                        Matrix::new_hilbert_matrix(size).unwrap()
+                    }
+                    "vandermonde" => {
+                        let evaluate = self.evaluator();
+                        let input_string: String = cliclack::input("Points")
+                            .validate_interactively(move |input: &String| {
+                                let err = input
+                                    .split_whitespace()
+                                    .find_map(|value| evaluate(value).err());
+                                if input.split_whitespace().next().is_none() || err.is_some() {
+                                    Err(format!(
+                                        "It should be real numbers seperated by a whitespace{}",
+                                        err.map_or(String::new(), |err| format!(" ({err})"))
+                                    ))
+                                } else {
+                                    Ok(())
+                                }
+                            })
+                            .interact()?;
+                        let evaluate = self.evaluator();
+                        let points = input_string
+                            .split_whitespace()
+                            .map(|num| evaluate(num).unwrap())
+                            .collect::<Vec<_>>();
+                        let columns = self.prompt_size(
+                            "Number of columns",
+                            "Number of columns should be a whole number greater than 0",
+                        )?;
+                        Matrix::new_vandermonde_matrix(&points, columns).unwrap()
+                    }
+                    "rotation" => {
+                        let mut size = self.prompt_size(
+                            "Size of the matrix",
+                            "Size should be a whole number greater than 1",
+                        )?;
+                        while size < 2 {
+                            log::error("A rotation needs at least two dimensions")?;
+                            size = self.prompt_size(
+                                "Size of the matrix",
+                                "Size should be a whole number greater than 1",
+                            )?;
+                        }
+                        let (i, j) = if size == 2 {
+                            (0, 1)
+                        } else {
+                            let mut axes = (
+                                self.prompt_size(
+                                    "First axis of the plane",
+                                    "The axis should be a whole number greater than 0",
+                                )?,
+                                self.prompt_size(
+                                    "Second axis of the plane",
+                                    "The axis should be a whole number greater than 0",
+                                )?,
+                            );
+                            while axes.0 == axes.1 || axes.0.max(axes.1) > size {
+                                log::error(format!(
+                                    "The axes should be two different numbers from 1 to {size}"
+                                ))?;
+                                axes = (
+                                    self.prompt_size(
+                                        "First axis of the plane",
+                                        "The axis should be a whole number greater than 0",
+                                    )?,
+                                    self.prompt_size(
+                                        "Second axis of the plane",
+                                        "The axis should be a whole number greater than 0",
+                                    )?,
+                                );
+                            }
+                            (axes.0 - 1, axes.1 - 1)
+                        };
+                        let degrees = self.prompt_number(
+                            "Angle in degrees",
+                            "The angle should be a real number",
+                        )?;
+
+                        match Matrix::new_rotation_matrix(size, i, j, degrees.to_radians()) {
+                            Ok(matrix) => matrix,
+                            Err(err) => {
+                                log::error(format!("Could not create the matrix: {err}"))?;
+                                return Ok(());
+                            }
+                        }
+                    }
+                    _ => {
+                        let input_string: String = cliclack::input("New order of the rows")
+                            .placeholder("e.g. 2 3 1")
+                            .validate_interactively(|input: &String| {
+                                let mut order = input
+                                    .split_whitespace()
+                                    .map(|value| value.parse::<usize>())
+                                    .collect::<Result<Vec<_>, _>>()
+                                    .unwrap_or_default();
+                                order.sort_unstable();
+                                if order.is_empty() || !order.iter().copied().eq(1..=order.len()) {
+                                    Err("It should be each number from 1 to the size exactly once")
+                                } else {
+                                    Ok(())
+                                }
+                            })
+                            .interact()?;
+                        let permutation = input_string
+                            .split_whitespace()
+                            .map(|num| num.parse::<usize>().unwrap() - 1)
+                            .collect::<Vec<_>>();
+                        Matrix::new_permutation_matrix(&permutation).unwrap()
+                    }
+                };
+
+                self.add_matrix(matrix_name, matrix)?;
+            }
+            "extract" => {
+                let source_name = self.prompt_matrix_name("Matrix to extract from")?;
+                let source = Arc::clone(&self.workspace()[&source_name]);
+                let kind = cliclack::select("What do you want to extract?")
+                    .item("submatrix", "A submatrix", "a block of rows and columns")
+                    .item("row", "A row", "")
+                    .item("column", "A column", "")
+                    .item("diagonal", "The diagonal", "as a column vector")
+                    .interact()?;
+
+                let (matrix, description) = match kind {
+                    "submatrix" => {
+                        let rows = self.prompt_range("Rows", source.rows())?;
+                        let columns = self.prompt_range("Columns", source.columns())?;
+                        let description = format!(
+                            "rows {} and columns {} of {source_name}",
+                            range_label(&rows),
+                            range_label(&columns)
+                        );
+                        (source.submatrix(rows, columns).unwrap(), description)
+                    }
+                    "row" => {
+                        let row = self.prompt_index("Row", source.rows())?;
+                        let description = format!("row {} of {source_name}", row + 1);
+                        (source.row_vector(row).unwrap(), description)
+                    }
+                    "column" => {
+                        let column = self.prompt_index("Column", source.columns())?;
+                        let description = format!("column {} of {source_name}", column + 1);
+                        (source.column_vector(column).unwrap(), description)
+                    }
+                    _ => (source.diagonal(), format!("diagonal of {source_name}")),
+                };
+
+                let matrix_name = self.prompt_name()?;
+                self.record(transcript::Entry::Step(format!(
+                    "Extracted the {description}"
+                )));
+                self.add_matrix(matrix_name, matrix)?;
+            }
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    // The matrices together with the result of the last operation as `ans`
+    fn workspace(&self) -> Workspace {
+        let mut workspace = self.matrices.borrow().clone();
+        if let Some(result) = self.last_result.borrow().as_ref() {
+            workspace.insert(LAST_RESULT.to_string(), Arc::clone(result));
+        }
+        workspace
+    }
+
+    // Typing filters the list of matrices, which is sorted by name and shows their dimensions
+    fn prompt_matrix_name(&self, prompt: &'static str) -> io::Result<String> {
+        loop {
+            let workspace = self.workspace();
+            if workspace.is_empty() {
+                log::warning("No matrices are created yet")?;
+                if !cliclack::confirm("Do you want to create a new matrix?")
+                    .initial_value(true)
+                    .interact()?
+                {
+                    return Err(io::ErrorKind::Interrupted.into());
+                }
+
+                self.create_matrix()?;
+                continue;
+            }
+
+            let mut names: Vec<(&String, &Arc<Matrix>)> = workspace.iter().collect();
+            names.sort_by_key(|(name, _)| *name);
+
+            let mut choice = cliclack::select(prompt).filter_mode();
+            for (name, matrix) in names {
+                let hint = if name == LAST_RESULT {
+                    format!(
+                        "{}×{}, result of the last operation",
+                        matrix.rows(),
+                        matrix.columns()
+                    )
+                } else {
+                    self.describe_matrix(name, matrix)
+                };
+                choice = choice.item(Some(name.clone()), name, hint);
+            }
+
+            match choice.item(None, "Create a new matrix", "").interact()? {
+                Some(matrix_name) => return Ok(matrix_name),
+                None => self.create_matrix()?,
+            }
+        }
+    }
+
+    fn edit_matrix(&self) -> io::Result<()> {
+        let mut matrix_name = self.prompt_matrix_name("Name of the matrix")?;
+        while matrix_name == LAST_RESULT {
+            log::error("Save the result under a name before editing it")?;
+            matrix_name = self.prompt_matrix_name("Name of the matrix")?;
+        }
+
+        let matrix = Arc::clone(&self.matrices.borrow()[&matrix_name]);
+        if let Some(edited) = editor::edit_matrix(&matrix_name, &matrix, &self.scalars.borrow())? {
+            self.record(transcript::Entry::Step(format!("Edited {matrix_name}")));
+            self.show_matrix(&matrix_name, &edited)?;
+            self.matrices
+                .borrow_mut()
+                .insert(matrix_name, Arc::new(edited));
+        }
+
+        Ok(())
+    }
+
+    fn perform_operations(&self) -> io::Result<()> {
+        let mut chained = false;
+        loop {
+            let prompt = if chained {
+                "Choose an operation on the result"
+            } else {
+                "Choose an operation"
+            };
+            let operation = cliclack::select(prompt)
+                .item("add", "Addition", "")
+                .item("subtract", "Subtraction", "")
+                .item("multiply", "Matrix Multiplication", "")
+                .item("scale", "Scalar Multiplication", "")
+                .item("trace", "Trace", "")
+                .item("transpose", "Transpose", "")
+                .item("determinant", "Determinant", "")
+                .item("adjoint", "Adjoint", "")
+                .item("inverse", "Inverse", "")
+                .item("power", "Matrix Power", "A^k")
+                .item("exponential", "Matrix Exponential", "exp(A)")
+                .item("sqrt", "Matrix Square Root", "sqrt(A)")
+                .item("solve", "Solve Ax = b", "")
+                .item("augment", "Augment [A | b]", "")
+                .item("hstack", "Stack Horizontally", "side by side")
+                .item("vstack", "Stack Vertically", "one above the other")
+                .item("rref", "Row Reduce (RREF)", "")
+                .item("rank", "Rank", "")
+                .item("norms", "Norms & Conditioning", "")
+                .item("apply", "Apply Function", "to every element")
+                .item("back", "Back to main menu", "")
+                .interact()?;
+
+            if let "back" = operation {
+                return Ok(());
+            }
+
+            let produced_matrix = match self.perform_operation(operation, chained) {
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {
+                    chained = false;
+                    continue;
+                }
+                result => result?,
+            };
+
+            let next = if produced_matrix {
+                loop {
+                    let next = cliclack::select("What do you want to do next?")
+                        .item("chain", "Use the result as input for another operation", "")
+                        .item("save", "Save the result as a new matrix", "")
+                        .item("copy", "Copy the result to the clipboard", "")
+                        .item("other", "Perform another operation", "")
+                        .item("back", "Back to main menu", "")
+                        .interact()?;
+                    match next {
+                        "save" => completed(self.save_last_result())?,
+                        "copy" => completed(self.copy_last_result())?,
+                        _ => break next,
+                    };
+                }
+            } else if cliclack::confirm("Do you want to perform any other matrix operations?")
+                .interact()?
+            {
+                "other"
+            } else {
+                "back"
+            };
+
+            if let "back" = next {
+                return Ok(());
+            }
+            chained = next == "chain";
+        }
+    }
+
+    fn save_last_result(&self) -> io::Result<()> {
+        let Some(result) = self.last_result.borrow().clone() else {
+            return Ok(());
+        };
+
+        let matrix_name = self.prompt_name()?;
+        self.record(transcript::Entry::Step(format!(
+            "Saved {LAST_RESULT} as {matrix_name}"
+        )));
+        log::success(format!("Saved the result as {matrix_name}"))?;
+        self.insert_matrix(matrix_name, result);
+        Ok(())
+    }
+
+    fn copy_last_result(&self) -> io::Result<()> {
+        let Some(result) = self.last_result.borrow().clone() else {
+            return Ok(());
+        };
+
+        let format = cliclack::select("Copy it as")
+            .item(clipboard::Format::Plain, "Plain rows", "")
+            .item(clipboard::Format::Csv, "CSV", "")
+            .item(clipboard::Format::Latex, "LaTeX", "bmatrix")
+            .item(clipboard::Format::Matlab, "MATLAB", "[1 2; 3 4]")
+            .interact()?;
+        let text = clipboard::render(&result, format, &self.format.get());
+
+        match clipboard::copy(&mut self.clipboard.borrow_mut(), text) {
+            Err(err) => log::error(format!("Could not copy to the clipboard: {err}"))?,
+            Ok(()) => log::success("Copied the result to the clipboard")?,
+        };
+        Ok(())
+    }
+
+    // When `chained`, the result of the previous operation is used as the first matrix instead of prompting for it
+    fn perform_operation(&self, operation: &str, chained: bool) -> io::Result<bool> {
+        let first_matrix = |prompt| {
+            if chained {
+                Ok(LAST_RESULT.to_string())
+            } else {
+                self.prompt_matrix_name(prompt)
+            }
+        };
+
+        let operation = match operation {
+            "add" => Operation::Add(
+                first_matrix("Name of the first matrix")?,
+                self.prompt_matrix_name("Name of the second matrix")?,
+            ),
+            "subtract" => Operation::Subtract(
+                first_matrix("Name of the first matrix")?,
+                self.prompt_matrix_name("Name of the second matrix")?,
+            ),
+            "multiply" => Operation::Multiply(
+                first_matrix("Name of the first matrix")?,
+                self.prompt_matrix_name("Name of the second matrix")?,
+            ),
+            "scale" => {
+                let scalar = self.prompt_scalar("The scalar value")?;
+
+                Operation::Scale(scalar, first_matrix("Name of the matrix")?)
+            }
+            "trace" => Operation::Trace(first_matrix("Name of the matrix")?),
+            "transpose" => Operation::Transpose(first_matrix("Name of the matrix")?),
+            "determinant" => Operation::Determinant(first_matrix("Name of the matrix")?),
+            "adjoint" => Operation::Adjoint(first_matrix("Name of the matrix")?),
+            "inverse" => {
+                let name = first_matrix("Name of the matrix")?;
+                // The 1-norm estimate reuses the LU factors the inverse needs anyway, and an infinite
+                // condition number is the worst case of all, so it warns as well
+                let matrix = Arc::clone(&self.workspace()[&name]);
+                if let Some(condition_number) =
+                    progress::compute("Estimating the condition number", move || matrix.condest())?
+                        .ok()
+                        .filter(|number| *number > ILL_CONDITIONED)
+                {
+                    let shown = match condition_number.is_infinite() {
+                        true => "∞".to_string(),
+                        false => self.format_number(condition_number),
+                    };
+                    log::warning(format!(
+                        "{name} is ill-conditioned (estimated condition number {shown}), so its inverse may be inaccurate"
+                    ))?;
+                    if !cliclack::confirm("Do you want to invert it anyway?")
+                        .initial_value(true)
+                        .interact()?
+                    {
+                        return Err(io::ErrorKind::Interrupted.into());
+                    }
+                }
+
+                Operation::Inverse(name)
+            }
+            "power" => {
+                let name = first_matrix("Name of the matrix")?;
+                Operation::Power(name, self.prompt_exponent()?)
+            }
+            "exponential" => Operation::Exponential(first_matrix("Name of the matrix")?),
+            "sqrt" => Operation::SquareRoot(first_matrix("Name of the matrix")?),
+            "augment" => Operation::Augment(
+                first_matrix("Name of the matrix A")?,
+                self.prompt_matrix_name("Name of the matrix b")?,
+            ),
+            "hstack" => Operation::StackHorizontally(
+                first_matrix("Name of the left matrix")?,
+                self.prompt_matrix_name("Name of the right matrix")?,
+            ),
+            "vstack" => Operation::StackVertically(
+                first_matrix("Name of the top matrix")?,
+                self.prompt_matrix_name("Name of the bottom matrix")?,
+            ),
+            "rref" => Operation::RowReduce(first_matrix("Name of the matrix")?),
+            "rank" => Operation::Rank(first_matrix("Name of the matrix")?),
+            "norms" => Operation::Norms(first_matrix("Name of the matrix")?),
+            "apply" => {
+                let name = first_matrix("Name of the matrix")?;
+                let function = match cliclack::select("Function to apply to every element")
+                    .item("abs", "Absolute value", "|x|")
+                    .item("square", "Square", "x²")
+                    .item("sqrt", "Square root", "√x")
+                    .item("reciprocal", "Reciprocal", "1/x")
+                    .item("round", "Round", "to a number of decimal places")
+                    .item("affine", "Affine map", "ax + b")
+                    .interact()?
+                {
+                    "abs" => ElementFunction::Abs,
+                    "square" => ElementFunction::Square,
+                    "sqrt" => ElementFunction::SquareRoot,
+                    "reciprocal" => ElementFunction::Reciprocal,
+                    "round" => {
+                        let decimals: u32 = cliclack::input("Number of decimal places")
+                            .validate_interactively(|input: &String| {
+                                if input.parse::<u32>().is_ok_and(|decimals| decimals <= 5) {
+                                    Ok(())
+                                } else {
+                                    Err("It should be a whole number from 0 to 5")
+                                }
+                            })
+                            .interact()?;
+                        ElementFunction::Round(decimals)
+                    }
+                    _ => ElementFunction::Affine(
+                        self.prompt_scalar("The scale a")?,
+                        self.prompt_scalar("The offset b")?,
+                    ),
+                };
+
+                Operation::Apply(function, name)
+            }
+            "solve" => Operation::Solve(
+                first_matrix("Name of the coefficient matrix A")?,
+                self.prompt_matrix_name("Name of the right-hand side b")?,
+            ),
+            _ => unreachable!(),
+        };
+
+        self.run_operation(operation)
+    }
+
+    // Returns whether the operation produced a matrix, which then becomes `ans`
+    fn run_operation(&self, operation: Operation) -> io::Result<bool> {
+        let workspace = self.workspace();
+        let missing: Vec<&str> = operation
+            .operands()
+            .into_iter()
+            .filter(|name| !workspace.contains_key(*name))
+            .collect();
+        if !missing.is_empty() {
+            log::error(format!(
+                "The workspace no longer has the matrix {}",
+                missing.join(", ")
+            ))?;
+            return Ok(false);
+        }
+
+        self.record(transcript::Entry::Step(operation.to_string()));
+        let task = operation.clone();
+        let (outcome, stats) = progress::compute(&format!("Computing {operation}"), move || {
+            stats::measure(|| task.evaluate(&workspace))
+        })?;
+        self.show_outcome(&outcome)?;
+        if !matches!(outcome, Outcome::Failed(_)) {
+            log::remark(format!("Result {stats}"))?;
+        }
+
+        let result = match &outcome {
+            Outcome::Matrix(_, matrix) | Outcome::Reduced(matrix, _) => Some(Arc::clone(matrix)),
+            Outcome::Solution(LinearSystemSolution::Unique(solution)) => {
+                Some(Arc::new(solution.clone()))
+            }
+            _ => None,
+        };
+        self.history.borrow_mut().push(Entry { operation, outcome });
+
+        let produced_matrix = result.is_some();
+        if produced_matrix {
+            *self.last_result.borrow_mut() = result;
+        }
+        Ok(produced_matrix)
+    }
+
+    fn show_outcome(&self, outcome: &Outcome) -> io::Result<()> {
+        match outcome {
+            Outcome::Failed(message) => self.log_result(log::error, message.clone())?,
+            Outcome::Matrix(title, matrix) => self.show_matrix(title, matrix)?,
+            Outcome::Scalar(label, value) => self.log_result(
+                log::success,
+                format!("{label} = {}", self.format_number(*value)),
+            )?,
+            Outcome::Norms { values, singular } => {
+                let report = values
+                    .iter()
+                    .map(|(label, value)| {
+                        if value.is_infinite() {
+                            format!("{label} = ∞")
+                        } else {
+                            format!("{label} = {}", self.format_number(*value))
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                self.log_result(log::success, report)?;
+
+                let ill_conditioned = values
+                    .iter()
+                    .any(|(label, value)| *label == "Condition number" && *value > ILL_CONDITIONED);
+                if *singular || ill_conditioned {
+                    let message = if *singular {
+                        "The matrix is singular, so it has no inverse"
+                    } else {
+                        "The matrix is ill-conditioned, so its inverse and the solutions of systems with it may be inaccurate"
+                    };
+                    self.log_result(log::warning, message.to_string())?;
+                }
+            }
+            Outcome::Reduced(matrix, pivot_columns) => {
+                self.show_matrix("Reduced Row Echelon Form", matrix)?;
+                self.log_result(log::info, pivot_report(pivot_columns, matrix.columns()))?;
+            }
+            Outcome::Rank(pivot_columns, columns) => {
+                self.log_result(log::success, format!("Rank = {}", pivot_columns.len()))?;
+                self.log_result(log::info, pivot_report(pivot_columns, *columns))?;
+            }
+            Outcome::Solution(LinearSystemSolution::NoSolution) => self.log_result(
+                log::warning,
+                "The system is inconsistent and has no solution".to_string(),
+            )?,
+            Outcome::Solution(LinearSystemSolution::Unique(solution)) => {
+                self.log_result(log::success, "The system has a unique solution".to_string())?;
+                self.show_matrix("x", solution)?;
+            }
+            Outcome::Solution(LinearSystemSolution::Infinite {
+                particular,
+                null_space,
+                free_variables,
+            }) => {
+                let free_variables = free_variables
+                    .iter()
+                    .map(|variable| format!("x{}", variable + 1))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let parameters = (1..=null_space.columns())
+                    .map(|parameter| format!("t{parameter}·n{parameter}"))
+                    .collect::<Vec<_>>()
+                    .join(" + ");
+
+                self.log_result(
+                    log::success,
+                    format!(
+                    "The system has infinitely many solutions with free variables {free_variables}"
+                ),
+                )?;
+                self.log_result(log::info, format!(
+                    "x = p + {parameters}, where n1, n2, ... are the columns of the null space basis and t1, t2, ... are any real numbers"
+                ))?;
+                self.show_matrix("Particular solution p", particular)?;
+                self.show_matrix("Null space basis", null_space)?;
+            }
+        };
+
+        Ok(())
+    }
+
+    fn toggle_recording(&self) -> io::Result<()> {
+        if self.transcript.borrow().is_none() {
+            *self.transcript.borrow_mut() = Some(Transcript::default());
+            log::success(
+                "Recording the session. It can be exported from the main menu or on exit",
+            )?;
+            return Ok(());
+        }
+
+        self.export_transcript()?;
+        *self.transcript.borrow_mut() = None;
+        Ok(())
+    }
+
+    fn export_transcript(&self) -> io::Result<()> {
+        let format = cliclack::select("Format of the transcript")
+            .item(transcript::Format::Markdown, "Markdown", "")
+            .item(transcript::Format::Latex, "LaTeX", "")
+            .interact()?;
+        let path: String = cliclack::input("File to export the transcript to")
+            .default_input(&format!("session.{}", format.extension()))
+            .interact()?;
+
+        let document = match self.transcript.borrow().as_ref() {
+            Some(transcript) => transcript.render(format, &self.format.get()),
+            None => return Ok(()),
+        };
+
+        match fs::write(&path, document) {
+            Err(err) => log::error(format!("Could not export the transcript: {err}"))?,
+            Ok(()) => log::success(format!("Exported the transcript to {path}"))?,
+        };
+
+        Ok(())
+    }
+
+    fn show_history(&self) -> io::Result<()> {
+        loop {
+            let labels: Vec<String> = self
+                .history
+                .borrow()
+                .iter()
+                .map(|entry| match &entry.outcome {
+                    Outcome::Matrix(_, matrix) => format!(
+                        "{} → {}×{} matrix",
+                        entry.operation,
+                        matrix.rows(),
+                        matrix.columns()
+                    ),
+                    Outcome::Scalar(_, value) => {
+                        format!("{} = {}", entry.operation, self.format_number(*value))
+                    }
+                    Outcome::Norms { .. } => format!("{} → norms", entry.operation),
+                    Outcome::Reduced(_, pivot_columns) => {
+                        format!("{} → rank {}", entry.operation, pivot_columns.len())
+                    }
+                    Outcome::Rank(pivot_columns, _) => {
+                        format!("{} = {}", entry.operation, pivot_columns.len())
+                    }
+                    Outcome::Solution(LinearSystemSolution::NoSolution) => {
+                        format!("{} → no solution", entry.operation)
+                    }
+                    Outcome::Solution(LinearSystemSolution::Unique(_)) => {
+                        format!("{} → unique solution", entry.operation)
+                    }
+                    Outcome::Solution(LinearSystemSolution::Infinite { .. }) => {
+                        format!("{} → infinitely many solutions", entry.operation)
+                    }
+                    Outcome::Failed(_) => format!("{} → failed", entry.operation),
+                })
+                .collect();
+
+            let mut menu = cliclack::select("Operation history");
+            for (index, label) in labels.iter().enumerate().rev() {
+                menu = menu.item(Some(index), format!("{}. {label}", index + 1), "");
+            }
+            let Some(index) = menu.item(None, "Back to main menu", "").interact()? else {
+                return Ok(());
+            };
+
+            completed(self.history_entry(index))?;
+        }
+    }
+
+    fn history_entry(&self, index: usize) -> io::Result<()> {
+        let (operation, outcome) = {
+            let history = self.history.borrow();
+            (
+                history[index].operation.clone(),
+                history[index].outcome.clone(),
+            )
+        };
+
+        let results: Vec<(&str, Arc<Matrix>)> = match &outcome {
+            Outcome::Matrix(title, matrix) => vec![(title, Arc::clone(matrix))],
+            Outcome::Reduced(matrix, _) => vec![("Reduced Row Echelon Form", Arc::clone(matrix))],
+            Outcome::Solution(LinearSystemSolution::Unique(solution)) => {
+                vec![("x", Arc::new(solution.clone()))]
+            }
+            Outcome::Solution(LinearSystemSolution::Infinite {
+                particular,
+                null_space,
+                ..
+            }) => vec![
+                ("Particular solution p", Arc::new(particular.clone())),
+                ("Null space basis", Arc::new(null_space.clone())),
+            ],
+            _ => vec![],
+        };
+
+        let mut menu = cliclack::select(format!("{operation}"))
+            .item("show", "Show the result", "")
+            .item("rerun", "Run again", "with the current matrices");
+        if !results.is_empty()
+            || matches!(
+                outcome,
+                Outcome::Scalar(..) | Outcome::Norms { .. } | Outcome::Rank(..)
+            )
+        {
+            menu = menu.item("keep", "Save the result to the workspace", "");
+        }
+
+        match menu.item("back", "Back to history", "").interact()? {
+            "show" => self.show_outcome(&outcome),
+            "rerun" => self.run_operation(operation).map(|_| ()),
+            "keep" => {
+                let scalar = match &outcome {
+                    Outcome::Scalar(_, value) => Some(*value),
+                    Outcome::Rank(pivot_columns, _) => Some(pivot_columns.len() as f64),
+                    Outcome::Norms { values, .. } => {
+                        let mut choice = cliclack::select("Which result?");
+                        for (index, (label, value)) in values.iter().enumerate() {
+                            choice = choice.item(index, label, self.format_number(*value));
+                        }
+                        Some(values[choice.interact()?].1)
+                    }
+                    _ => None,
+                };
+
+                if let Some(value) = scalar {
+                    let name = self.prompt_variable_name("Name of the scalar")?;
+                    self.record(transcript::Entry::Step(format!(
+                        "Saved {operation} as {name}"
+                    )));
+                    log::success(format!("Saved the result as {name}"))?;
+                    self.insert_scalar(name, value);
+                    return Ok(());
+                }
+
+                let matrix = match results.len() {
+                    1 => Arc::clone(&results[0].1),
+                    _ => {
+                        let mut choice = cliclack::select("Which result?");
+                        for (index, (title, _)) in results.iter().enumerate() {
+                            choice = choice.item(index, title, "");
+                        }
+                        Arc::clone(&results[choice.interact()?].1)
+                    }
+                };
+                let matrix_name = self.prompt_name()?;
+                self.record(transcript::Entry::Step(format!(
+                    "Saved {operation} as {matrix_name}"
+                )));
+                log::success(format!("Saved the result as {matrix_name}"))?;
+                self.insert_matrix(matrix_name, matrix);
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn check_properties(&self) -> io::Result<()> {
+        loop {
+            let scope = cliclack::select("What do you want to check?")
+                .item("all", "All properties", "")
+                .item("choose", "Choose properties", "")
+                .item("back", "Back to main menu", "")
+                .interact()?;
+
+            if let "back" = scope {
+                return Ok(());
+            }
+
+            if completed(self.report_properties(scope))?
+                && !cliclack::confirm("Do you want to check properties of any other matrices?")
+                    .interact()?
+            {
+                return Ok(());
+            }
+        }
+    }
+
+    // States are numbered from 1 in every message, while the library counts them from 0
+    fn analyze_markov_chain(&self) -> io::Result<()> {
+        let name = self.prompt_matrix_name("Name of the transition matrix")?;
+        let matrix = Arc::clone(&self.workspace()[&name]);
+
+        if !matrix.is_row_stochastic() {
+            let problems = if !matrix.is_square() {
+                vec!["it is not square".to_string()]
+            } else {
+                (0..matrix.rows())
+                    .filter_map(|row| {
+                        let values = matrix.get_row(row).unwrap();
+                        let sum: f64 = values.iter().sum();
+                        if values.iter().any(|num| *num < 0.0) {
+                            Some(format!("row {} has a negative element", row + 1))
+                        } else if (sum - 1.0).abs() > 1e-4 {
+                            Some(format!(
+                                "row {} sums to {}",
+                                row + 1,
+                                self.format_number(sum)
+                            ))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect()
+            };
+            log::error(format!(
+                "{name} is not a transition matrix, since {}. Each row should hold the non-negative probabilities of moving from one state to every state, summing to 1",
+                problems.join(", ")
+            ))?;
+            return Ok(());
+        }
+
+        self.record(transcript::Entry::Step(format!(
+            "Markov chain analysis of {name}"
+        )));
+        loop {
+            match cliclack::select("What do you want to find?")
+                .item("steps", "n-step transition matrix", "Pⁿ")
+                .item("stationary", "Stationary distribution", "long-run behaviour")
+                .item("absorbing", "Absorbing states", "where the chain gets stuck")
+                .item("back", "Back to main menu", "")
+                .interact()?
+            {
+                "steps" => {
+                    let steps = self.prompt_size(
+                        "Number of steps n",
+                        "The number of steps should be a whole number greater than 0",
+                    )?;
+                    let Ok(steps) = i32::try_from(steps) else {
+                        log::error("The number of steps is too large")?;
+                        continue;
+                    };
+                    let power = matrix.power(steps).unwrap();
+                    self.show_matrix(&format!("{steps}-step transition matrix"), &power)?;
+                    self.log_result(
+                        log::info,
+                        format!("Row i, column j is the probability of being in state j after {steps} steps when starting in state i"),
+                    )?;
+                    *self.last_result.borrow_mut() = Some(Arc::new(power));
+                }
+                "stationary" => match matrix.stationary_distribution().unwrap() {
+                    Some(distribution) => {
+                        self.show_matrix("Stationary distribution π", &distribution)?;
+                        self.log_result(
+                            log::info,
+                            "In the long run the chain spends these fractions of the time in each state, and πP = π".to_string(),
+                        )?;
+                        *self.last_result.borrow_mut() = Some(Arc::new(distribution));
+                    }
+                    None => self.log_result(
+                        log::warning,
+                        "The chain has more than one stationary distribution, since it has several closed groups of states that it can never leave, and its long-run behaviour depends on where it starts".to_string(),
+                    )?,
+                },
+                "absorbing" => self.explain_absorbing_states(&matrix)?,
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn explain_absorbing_states(&self, matrix: &Matrix) -> io::Result<()> {
+        let states = |states: &[usize]| {
+            states
+                .iter()
+                .map(|state| (state + 1).to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let absorbing_states = matrix.absorbing_states();
+        if absorbing_states.is_empty() {
+            return self.log_result(
+                log::info,
+                "A state is absorbing when the probability of staying in it is 1, so the chain never leaves it once it gets there. This chain has no absorbing states".to_string(),
+            );
+        }
+
+        self.log_result(
+            log::info,
+            format!(
+                "A state is absorbing when the probability of staying in it is 1, so the chain never leaves it once it gets there. The absorbing states are {}",
+                states(&absorbing_states)
+            ),
+        )?;
+
+        match matrix.absorbing_chain() {
+            Err(MatrixError::SingularMatrix) => self.log_result(
+                log::warning,
+                "Some of the other states can never reach an absorbing state, so the chain is not guaranteed to be absorbed".to_string(),
+            ),
+            Err(_) => self.log_result(log::info, "Every state is absorbing".to_string()),
+            Ok(chain) => {
+                let steps = chain
+                    .transient_states()
+                    .iter()
+                    .zip(chain.expected_steps())
+                    .map(|(state, steps)| {
+                        format!("from state {}: {}", state + 1, self.format_number(*steps))
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                self.log_result(
+                    log::success,
+                    format!("The chain is eventually absorbed from every state. Expected number of steps until then\n{steps}"),
+                )?;
+                self.show_matrix("Absorption probabilities", chain.absorption_probabilities())?;
+                self.log_result(
+                    log::info,
+                    format!(
+                        "Rows are the starting states {} and columns the absorbing states {}, so each entry is the probability of ending up in that absorbing state",
+                        states(chain.transient_states()),
+                        states(chain.absorbing_states())
+                    ),
+                )
+            }
+        }
+    }
+
+    fn fit_curve(&self) -> io::Result<()> {
+        let source = cliclack::select("Where is the data?")
+            .item("paste", "Type or paste it", "one x y pair per line")
+            .item("file", "Import it from a CSV file", "")
+            .item("workspace", "Use a matrix from the workspace", "")
+            .interact()?;
+
+        let data = match source {
+            "paste" => {
+                let input: String = cliclack::input("The data points")
+                    .placeholder("x y")
+                    .multiline()
+                    .validate_interactively(|input: &String| {
+                        csv::parse_matrix(input, Delimiter::Auto, false)
+                            .map(|_| ())
+                            .map_err(|err| {
+                                format!("Each line should have an x and a y value ({err})")
+                            })
+                    })
+                    .interact()?;
+                Arc::new(csv::parse_matrix(&input, Delimiter::Auto, false).unwrap())
+            }
+            "file" => {
+                let path: String = cliclack::input("Path of the file").interact()?;
+                let has_header = cliclack::confirm("Does the file have a header row?")
+                    .initial_value(false)
+                    .interact()?;
+                match csv::import_matrix(path, Delimiter::Auto, has_header) {
+                    Err(err) => {
+                        log::error(format!("Could not import the data: {err}"))?;
+                        return Ok(());
+                    }
+                    Ok(matrix) => Arc::new(matrix),
+                }
+            }
+            _ => {
+                let name = self.prompt_matrix_name("Name of the data matrix")?;
+                Arc::clone(&self.workspace()[&name])
+            }
+        };
+
+        let (x_column, y_column) = match data.columns() {
+            1 => {
+                log::error("The data should have a column of x values and a column of y values")?;
+                return Ok(());
+            }
+            2 => (0, 1),
+            columns => (
+                self.prompt_index("Column of the x values", columns)?,
+                self.prompt_index("Column of the y values", columns)?,
+            ),
+        };
+        let x = data.get_column(x_column).unwrap();
+        let y = Matrix::new_with_data(
+            data.get_column(y_column)
+                .unwrap()
+                .into_iter()
+                .map(|num| vec![num])
+                .collect(),
+        )
+        .unwrap();
+
+        let max_degree = x.len() - 1;
+        let degree: usize = cliclack::input("Degree of the polynomial")
+            .placeholder("1 for a straight line")
+            .validate_interactively(move |input: &String| {
+                if input.parse::<usize>().is_ok_and(|degree| degree <= max_degree) {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "It should be a whole number from 0 to {max_degree}, one less than the number of points"
+                    ))
+                }
+            })
+            .interact()?;
+
+        let design = Matrix::new_vandermonde_matrix(&x, degree + 1).unwrap();
+        let coefficients = match design.least_squares(&y) {
+            Err(_) => {
+                log::error(format!(
+                    "A polynomial of degree {degree} needs at least {} different x values",
+                    degree + 1
+                ))?;
+                return Ok(());
+            }
+            Ok(coefficients) => coefficients,
+        };
+
+        let fitted = (&design * &coefficients).unwrap();
+        let residuals = (&y - &fitted).unwrap();
+        let table = Matrix::new_with_data(
+            (0..x.len())
+                .map(|row| {
+                    vec![
+                        x[row],
+                        y.get_element(row, 0).unwrap(),
+                        fitted.get_element(row, 0).unwrap(),
+                        residuals.get_element(row, 0).unwrap(),
+                    ]
+                })
+                .collect(),
+        )
+        .unwrap();
+
+        let polynomial = coefficients
+            .get_column(0)
+            .unwrap()
+            .iter()
+            .enumerate()
+            .map(|(power, coefficient)| {
+                let term = match power {
+                    0 => self.format_number(coefficient.abs()),
+                    1 => format!("{}x", self.format_number(coefficient.abs())),
+                    _ => format!("{}x^{power}", self.format_number(coefficient.abs())),
+                };
+                match (power, coefficient.is_sign_negative()) {
+                    (0, true) => format!("-{term}"),
+                    (0, false) => term,
+                    (_, true) => format!(" - {term}"),
+                    (_, false) => format!(" + {term}"),
+                }
+            })
+            .collect::<String>();
+
+        let residual_sum: f64 = residuals.get_column(0).unwrap().iter().map(|r| r * r).sum();
+        let mean = y.get_column(0).unwrap().iter().sum::<f64>() / x.len() as f64;
+        let total_sum: f64 = y
+            .get_column(0)
+            .unwrap()
+            .iter()
+            .map(|num| (num - mean) * (num - mean))
+            .sum();
+        let mut summary = format!(
+            "y = {polynomial}\nResidual sum of squares = {}",
+            self.format_number(residual_sum)
+        );
+        if total_sum > 0.0 {
+            summary.push_str(&format!(
+                "\nR² = {}",
+                self.format_number(1.0 - residual_sum / total_sum)
+            ));
+        }
+
+        self.record(transcript::Entry::Step(format!(
+            "Least-squares fit of a polynomial of degree {degree}"
+        )));
+        self.log_result(log::success, summary)?;
+        self.show_matrix("Coefficients, from the constant term up", &coefficients)?;
+        self.show_matrix("x, y, fitted y and residual", &table)?;
+        *self.last_result.borrow_mut() = Some(Arc::new(coefficients));
+        Ok(())
+    }
+
+    // Composes rotations, scalings and translations in homogeneous coordinates, so that an n-dimensional
+    // transformation is an (n + 1)×(n + 1) matrix, and applies them to points typed by the user
+    fn transformation_playground(&self) -> io::Result<()> {
+        let dimension = cliclack::select("Which space?")
+            .item(2, "2D plane", "")
+            .item(3, "3D space", "")
+            .interact()?;
+
+        let mut transform = Matrix::nth_identity(dimension + 1).unwrap();
+        let mut steps: Vec<String> = vec![];
+        loop {
+            let mut menu = cliclack::select("Add a transformation")
+                .item("rotate", "Rotation", "by an angle in degrees")
+                .item("scale", "Scaling", "along each axis")
+                .item("translate", "Translation", "by an offset along each axis");
+            if !steps.is_empty() {
+                menu = menu.item("apply", "Apply it to points", "");
+            }
+
+            let (step, description) = match menu.interact()? {
+                "rotate" => {
+                    // Each rotation turns one axis towards the next, counterclockwise when viewed
+                    // with the remaining axis pointing at the viewer
+                    let (i, j, about) = match dimension {
+                        2 => (0, 1, ""),
+                        _ => match cliclack::select("Rotate about which axis?")
+                            .item("x", "x axis", "")
+                            .item("y", "y axis", "")
+                            .item("z", "z axis", "")
+                            .interact()?
+                        {
+                            "x" => (1, 2, " about the x axis"),
+                            "y" => (2, 0, " about the y axis"),
+                            _ => (0, 1, " about the z axis"),
+                        },
+                    };
+                    let degrees = self.prompt_number(
+                        "Angle in degrees",
+                        "The angle should be a real number, positive for counterclockwise",
+                    )?;
+                    (
+                        Matrix::new_rotation_matrix(dimension + 1, i, j, degrees.to_radians())
+                            .unwrap(),
+                        format!("rotate by {}°{about}", self.format_number(degrees)),
+                    )
+                }
+                "scale" => {
+                    let factors = (0..dimension)
+                        .map(|axis| {
+                            self.prompt_number(
+                                ["Factor along x", "Factor along y", "Factor along z"][axis],
+                                "The factor should be a real number",
+                            )
+                        })
+                        .collect::<io::Result<Vec<f64>>>()?;
+                    (
+                        Matrix::new_scaling_matrix(&factors).unwrap(),
+                        format!("scale by {}", self.coordinates(&factors)),
+                    )
+                }
+                "translate" => {
+                    let offsets = (0..dimension)
+                        .map(|axis| {
+                            self.prompt_number(
+                                ["Offset along x", "Offset along y", "Offset along z"][axis],
+                                "The offset should be a real number",
+                            )
+                        })
+                        .collect::<io::Result<Vec<f64>>>()?;
+                    (
+                        Matrix::new_translation_matrix(&offsets).unwrap(),
+                        format!("move by {}", self.coordinates(&offsets)),
+                    )
+                }
+                _ => break,
+            };
+
+            // Later steps act on the result of earlier ones, so they multiply from the left
+            transform = (&step * &transform).unwrap();
+            steps.push(description);
+            self.log_result(
+                log::info,
+                format!("The transformation so far: {}", steps.join(", then ")),
+            )?;
+            self.show_matrix("Combined transformation", &transform)?;
+        }
+
+        let placeholder = ["x", "y", "z"][..dimension].join(" ");
+        let input: String = cliclack::input("The points")
+            .placeholder(&format!("one point per line, {placeholder}"))
+            .multiline()
+            .validate_interactively(move |input: &String| {
+                match csv::parse_matrix(input, Delimiter::Auto, false) {
+                    Err(err) => Err(format!(
+                        "Each line should hold the coordinates of a point ({err})"
+                    )),
+                    Ok(points) if points.columns() != dimension => Err(format!(
+                        "Each line should hold {dimension} coordinates, {placeholder}"
+                    )),
+                    Ok(_) => Ok(()),
+                }
+            })
+            .interact()?;
+        let points = csv::parse_matrix(&input, Delimiter::Auto, false).unwrap();
+
+        let moved = match transform.transform_points(&points) {
+            Err(_) => {
+                log::error("Some of the transformed coordinates are too large")?;
+                return Ok(());
+            }
+            Ok(moved) => moved,
+        };
+
+        let report = (0..points.rows())
+            .map(|row| {
+                format!(
+                    "{} → {}",
+                    self.coordinates(&points.get_row(row).unwrap()),
+                    self.coordinates(&moved.get_row(row).unwrap())
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        self.record(transcript::Entry::Step(format!(
+            "Transformation of {} points: {}",
+            points.rows(),
+            steps.join(", then ")
+        )));
+        self.log_result(log::success, report)?;
+        self.show_matrix("Transformed points", &moved)?;
+        *self.last_result.borrow_mut() = Some(Arc::new(transform));
+        Ok(())
+    }
+
+    fn coordinates(&self, values: &[f64]) -> String {
+        format!(
+            "({})",
+            values
+                .iter()
+                .map(|num| self.format_number(*num))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+
+    fn report_properties(&self, scope: &str) -> io::Result<()> {
+        let matrix_name = self.prompt_matrix_name("Name of the matrix")?;
+        let matrix = Arc::clone(&self.workspace()[&matrix_name]);
+
+        let properties = if let "all" = scope {
+            None
+        } else {
+            Some(
+                cliclack::multiselect("What properties do you want to check?")
+                    .items(&Property::ALL.map(|property| (property, property.label(), "")))
+                    .interact()?,
+            )
+        };
+
+        let format = self.format.get();
+        let output = progress::compute("Checking the properties", move || {
+            let report = match properties {
+                None => matrix.property_report(),
+                Some(properties) => matrix.check_properties(&properties),
+            };
+            report.formatted(format).to_string()
+        })?;
+
+        self.record(transcript::Entry::Step(format!(
+            "Properties of {matrix_name}"
+        )));
+        self.log_result(log::success, output)
+    }
+
+    fn variables(&self) -> BTreeMap<String, Variable> {
+        self.matrices
+            .borrow()
+            .iter()
+            .map(|(name, matrix)| {
+                let record = MatrixRecord {
+                    name: Some(name.clone()),
+                    description: self.descriptions.borrow().get(name).cloned(),
+                    tags: self.tags.borrow().get(name).cloned().unwrap_or_default(),
+                    matrix: matrix.deref().clone(),
+                };
+                (name.clone(), Variable::Matrix(record))
+            })
+            .chain(
+                self.scalars
+                    .borrow()
+                    .iter()
+                    .map(|(name, value)| (name.clone(), Variable::Scalar(*value))),
+            )
+            .collect()
+    }
+
+    fn replace_variables(&self, workspace: HashMap<String, Variable>) {
+        self.matrices.borrow_mut().clear();
+        self.scalars.borrow_mut().clear();
+        self.tags.borrow_mut().clear();
+        self.descriptions.borrow_mut().clear();
+        self.merge_variables(workspace);
+    }
+
+    // Variables with a name already in the workspace replace it, tags and description included. The
+    // key a matrix is stored under is its name, even if its record says otherwise
+    fn merge_variables(&self, workspace: HashMap<String, Variable>) {
+        for (name, variable) in workspace {
+            let record = match variable {
+                Variable::Scalar(value) => {
+                    self.insert_scalar(name, value);
+                    continue;
+                }
+                Variable::Matrix(record) => record,
+                Variable::Tagged { matrix, tags } => MatrixRecord {
+                    tags,
+                    ..MatrixRecord::from(matrix)
+                },
+            };
+
+            if record.tags.is_empty() {
+                self.tags.borrow_mut().remove(&name);
+            } else {
+                self.tags.borrow_mut().insert(name.clone(), record.tags);
+            }
+            if let Some(description) = record.description {
+                self.descriptions
+                    .borrow_mut()
+                    .insert(name.clone(), description);
+            } else {
+                self.descriptions.borrow_mut().remove(&name);
+            }
+            self.insert_matrix(name, Arc::new(record.matrix));
+        }
+    }
+
+    // Failing to autosave only warns, since the workspace itself is still intact
+    fn autosave(&self) -> io::Result<()> {
+        if let Err(err) = autosave::save(&self.variables()) {
+            log::warning(format!("Could not autosave the workspace: {err}"))?;
+        }
+        Ok(())
+    }
+
+    fn restore_autosave(&self) -> io::Result<()> {
+        let Some(workspace) = autosave::load::<HashMap<String, Variable>>() else {
+            return Ok(());
+        };
+
+        if !workspace.is_empty()
+            && cliclack::confirm(format!(
+                "Restore the {} matrices and scalars from your last session?",
+                workspace.len()
+            ))
+            .initial_value(true)
+            .interact()?
+        {
+            self.replace_variables(workspace);
+        }
+
+        Ok(())
+    }
+
+    fn save_workspace(&self) -> io::Result<()> {
+        let path: String = cliclack::input("File to save the workspace to")
+            .default_input("workspace.json")
+            .interact()?;
+
+        let workspace = self.variables();
+        let result = serde_json::to_string_pretty(&workspace)
+            .map_err(io::Error::from)
+            .and_then(|json| fs::write(&path, json));
+
+        match result {
+            Err(err) => log::error(format!("Could not save the workspace: {err}"))?,
+            Ok(()) => log::success(format!(
+                "Saved {} matrices and scalars to {path}",
+                workspace.len()
+            ))?,
+        };
+
+        Ok(())
+    }
+
+    fn load_workspace(&self) -> io::Result<()> {
+        let path: String = cliclack::input("File to load the workspace from")
+            .default_input("workspace.json")
+            .interact()?;
+
+        let result = fs::read_to_string(&path).and_then(|json| {
+            serde_json::from_str::<HashMap<String, Variable>>(&json).map_err(io::Error::from)
+        });
+
+        match result {
+            Err(err) => log::error(format!("Could not load the workspace: {err}"))?,
+            Ok(workspace) => {
+                let action =
+                    if self.matrices.borrow().is_empty() && self.scalars.borrow().is_empty() {
+                        "replace"
+                    } else {
+                        cliclack::select("The workspace already has matrices or scalars")
+                            .item(
+                                "merge",
+                                "Add the loaded ones to it",
+                                "shared names are overwritten",
+                            )
+                            .item("replace", "Replace it", "")
+                            .item("cancel", "Cancel", "")
+                            .interact()?
+                    };
+
+                let mut overwritten: Vec<&String> = workspace
+                    .keys()
+                    .filter(|name| {
+                        self.matrices.borrow().contains_key(*name)
+                            || self.scalars.borrow().contains_key(*name)
+                    })
+                    .collect();
+                overwritten.sort();
+                let mut message = format!(
+                    "Loaded {} matrices and scalars from {path}",
+                    workspace.len()
+                );
+
+                match action {
+                    "merge" => {
+                        if !overwritten.is_empty() {
+                            message.push_str(&format!(
+                                ", overwriting {}",
+                                overwritten
+                                    .iter()
+                                    .map(|name| name.as_str())
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ));
+                        }
+                        self.record(transcript::Entry::Step(format!("Merged {path}")));
+                        log::success(message)?;
+                        self.merge_variables(workspace);
+                    }
+                    "replace" => {
+                        self.record(transcript::Entry::Step(format!("Loaded {path}")));
+                        log::success(message)?;
+                        self.replace_variables(workspace);
+                    }
+                    _ => {}
+                }
+            }
+        };
+
+        Ok(())
+    }
+
+    fn change_settings(&self) -> io::Result<()> {
+        let current = self.format.get();
+
+        let precision: String = cliclack::input("Number of decimal places")
+            .placeholder("Leave empty to show every digit")
+            .default_input(&current.precision.map_or(String::new(), |p| p.to_string()))
+            .required(false)
+            .validate_interactively(|input: &String| {
+                if !input.is_empty() && input.parse::<usize>().is_err() {
+                    Err("Number of decimal places should be a whole number")
+                } else {
+                    Ok(())
+                }
+            })
+            .interact()?;
+
+        let notation = cliclack::select("Number format")
+            .item(Notation::Decimal, "Decimal", "1234.5")
+            .item(Notation::Scientific, "Scientific", "1.2345e3")
+            .initial_value(current.notation)
+            .interact()?;
+
+        let border = cliclack::select("Matrix borders")
+            .item(Border::None, "None", "")
+            .item(Border::Ascii, "ASCII", "+---+")
+            .item(Border::Unicode, "Unicode", "┌   ┐")
+            .initial_value(current.border)
+            .interact()?;
+
+        let options = FormatOptions {
+            precision: precision.parse().ok(),
+            notation,
+            border,
+        };
+        self.format.set(options);
+
+        match settings::save(&options) {
+            Err(err) => log::warning(format!(
+                "Settings apply to this session only, as they could not be saved: {err}"
+            ))?,
+            Ok(()) => log::success("Settings saved")?,
+        };
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use std::{
+    io,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+// Computations that finish sooner than this never show the spinner, so quick results don't flicker
+const SPINNER_DELAY: Duration = Duration::from_millis(200);
+
+// Runs `task` on a worker thread and shows a spinner while it takes longer than SPINNER_DELAY
+pub fn compute<T: Send + 'static>(
+    message: &str,
+    task: impl FnOnce() -> T + Send + 'static,
+) -> io::Result<T> {
+    let started = Instant::now();
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let _ = sender.send(task());
+    });
+
+    match receiver.recv_timeout(SPINNER_DELAY) {
+        Ok(result) => return Ok(result),
+        Err(RecvTimeoutError::Disconnected) => {
+            return Err(io::Error::other("the computation failed unexpectedly"))
+        }
+        Err(RecvTimeoutError::Timeout) => {}
+    }
+
+    let spinner = cliclack::spinner();
+    spinner.start(message);
+    match receiver.recv() {
+        Ok(result) => {
+            spinner.stop(format!("Finished in {:.1?}", started.elapsed()));
+            Ok(result)
+        }
+        Err(_) => {
+            spinner.error("The computation failed unexpectedly");
+            Err(io::Error::other("the computation failed unexpectedly"))
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use matrix_core::FormatOptions;
+use std::{fs, io, path::PathBuf};
+
+fn config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("matrix_toolkit").join("settings.json"))
+}
+
+// Missing or unreadable settings fall back to the defaults rather than stopping the CLI from starting
+pub fn load() -> FormatOptions {
+    config_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(options: &FormatOptions) -> io::Result<()> {
+    let path = config_path().ok_or(io::ErrorKind::NotFound)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(path, serde_json::to_string_pretty(options)?)
+}
@@ -0,0 +1,132 @@
+use matrix_core::{Border, FormatOptions, Matrix};
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Format {
+    Markdown,
+    Latex,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Markdown => "md",
+            Format::Latex => "tex",
+        }
+    }
+}
+
+pub enum Entry {
+    Step(String),
+    Text(String),
+    Matrix(String, Arc<Matrix>),
+}
+
+#[derive(Default)]
+pub struct Transcript {
+    entries: Vec<Entry>,
+}
+
+impl Transcript {
+    pub fn push(&mut self, entry: Entry) {
+        self.entries.push(entry);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn render(&self, format: Format, options: &FormatOptions) -> String {
+        match format {
+            Format::Markdown => self.markdown(options),
+            Format::Latex => self.latex(options),
+        }
+    }
+
+    fn markdown(&self, options: &FormatOptions) -> String {
+        let options = FormatOptions {
+            border: Border::None,
+            ..*options
+        };
+
+        self.entries.iter().fold(
+            String::from("# Matrix Toolkit Session\n"),
+            |output, entry| match entry {
+                Entry::Step(step) => output + &format!("\n## {step}\n"),
+                Entry::Text(text) => output + &format!("\n{}\n", text.trim_end()),
+                Entry::Matrix(title, matrix) => {
+                    output
+                        + &format!(
+                            "\n**{title}**\n\n```text\n{}```\n",
+                            matrix.formatted(options)
+                        )
+                }
+            },
+        )
+    }
+
+    fn latex(&self, options: &FormatOptions) -> String {
+        let body = self
+            .entries
+            .iter()
+            .map(|entry| match entry {
+                Entry::Step(step) => format!("\\section*{{{}}}\n", escape(step)),
+                Entry::Text(text) => format!(
+                    "{}\n",
+                    text.lines().map(escape).collect::<Vec<_>>().join("\\\\\n")
+                ),
+                Entry::Matrix(title, matrix) => format!(
+                    "\\[\n\\text{{{}}} = {}\n\\]\n",
+                    escape(title),
+                    latex_matrix(matrix, options)
+                ),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            "\\documentclass{{article}}\n\\usepackage{{amsmath}}\n\\setcounter{{MaxMatrixCols}}{{100}}\n\n\\begin{{document}}\n\\section*{{Matrix Toolkit Session}}\n\n{body}\n\\end{{document}}\n"
+        )
+    }
+}
+
+pub fn latex_matrix(matrix: &Matrix, options: &FormatOptions) -> String {
+    let rows = (0..matrix.rows())
+        .map(|row| {
+            matrix
+                .get_row(row)
+                .unwrap()
+                .iter()
+                .map(|num| options.format_number(*num))
+                .collect::<Vec<_>>()
+                .join(" & ")
+        })
+        .collect::<Vec<_>>()
+        .join(" \\\\\n");
+    format!("\\begin{{bmatrix}}\n{rows}\n\\end{{bmatrix}}")
+}
+
+// Escapes LaTeX special characters and spells out the symbols the CLI uses in its messages
+fn escape(text: &str) -> String {
+    text.chars()
+        .map(|character| match character {
+            '\\' => "\\textbackslash{}".to_string(),
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => format!("\\{character}"),
+            '~' => "\\textasciitilde{}".to_string(),
+            '^' => "\\textasciicircum{}".to_string(),
+            'ᵀ' => "$^T$".to_string(),
+            '⁻' => "$^{-".to_string(),
+            '¹' => "1}$".to_string(),
+            '²' => "$^2$".to_string(),
+            '×' => "$\\times$".to_string(),
+            '·' => "$\\cdot$".to_string(),
+            '→' => "$\\rightarrow$".to_string(),
+            '∞' => "$\\infty$".to_string(),
+            '°' => "$^\\circ$".to_string(),
+            '‖' => "$\\|$".to_string(),
+            '✅' => "yes".to_string(),
+            '❌' => "no".to_string(),
+            _ => character.to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,16 @@
+fn main() {
+    #[cfg(feature = "ffi")]
+    generate_header();
+}
+
+// The header is checked in, so C users can find it without building the crate first
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    cbindgen::generate(&crate_dir)
+        .expect("failed to generate the C header")
+        .write_to_file(format!("{crate_dir}/include/matrix_core.h"));
+}
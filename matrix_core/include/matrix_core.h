@@ -0,0 +1,71 @@
+#ifndef MATRIX_CORE_H
+#define MATRIX_CORE_H
+
+/* Generated by cbindgen from src/ffi.rs when building with the ffi feature. Do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define Matrix_SINGULARITY_TOLERANCE 1e-10
+
+typedef enum MatrixStatus {
+  MATRIX_STATUS_OK = 0,
+  MATRIX_STATUS_NULL_POINTER,
+  MATRIX_STATUS_ZERO_DIMENSION,
+  MATRIX_STATUS_INCONSISTENT_COLUMN_SIZE,
+  MATRIX_STATUS_DIMENSION_MISMATCH,
+  MATRIX_STATUS_NON_SQUARE_MATRIX,
+  MATRIX_STATUS_NON_SYMMETRIC_MATRIX,
+  MATRIX_STATUS_SINGULAR_MATRIX,
+  MATRIX_STATUS_INDEX_OUT_OF_BOUNDS,
+  MATRIX_STATUS_NOT_POSITIVE_SEMIDEFINITE,
+  MATRIX_STATUS_INVALID_ARGUMENT,
+  MATRIX_STATUS_NO_UNIQUE_SOLUTION,
+  MATRIX_STATUS_NOT_POSITIVE_DEFINITE,
+} MatrixStatus;
+
+typedef struct Matrix Matrix;
+
+typedef struct Property Property;
+
+
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+enum MatrixStatus matrix_new(size_t rows,
+                             size_t columns,
+                             const double *elements,
+                             struct Matrix **out);
+
+enum MatrixStatus matrix_identity(size_t size, struct Matrix **out);
+
+void matrix_free(struct Matrix *matrix);
+
+size_t matrix_rows(const struct Matrix *matrix);
+
+size_t matrix_columns(const struct Matrix *matrix);
+
+enum MatrixStatus matrix_get(const struct Matrix *matrix, size_t row, size_t column, double *out);
+
+enum MatrixStatus matrix_set(struct Matrix *matrix, size_t row, size_t column, double value);
+
+enum MatrixStatus matrix_multiply(const struct Matrix *a,
+                                  const struct Matrix *b,
+                                  struct Matrix **out);
+
+enum MatrixStatus matrix_determinant(const struct Matrix *matrix, double *out);
+
+enum MatrixStatus matrix_solve(const struct Matrix *a, const struct Matrix *b, struct Matrix **out);
+
+const char *matrix_status_message(enum MatrixStatus status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* MATRIX_CORE_H */
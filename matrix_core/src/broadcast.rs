@@ -0,0 +1,172 @@
+use crate::{round_result, Matrix, MatrixError};
+
+impl Matrix {
+    // Combines every row with a 1 × n vector, element by element, as NumPy broadcasts
+    fn broadcast_row<F>(&self, vector: &Matrix, operation: F) -> Result<Self, MatrixError>
+    where
+        F: Fn(f64, f64) -> f64 + Sync,
+    {
+        if vector.shape() != (1, self.columns) {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (1, self.columns),
+                actual: vector.shape(),
+            });
+        }
+
+        self.broadcast(|row, column| operation(self.data[row][column], vector.data[0][column]))
+    }
+
+    // Combines every column with an m × 1 vector, element by element
+    fn broadcast_column<F>(&self, vector: &Matrix, operation: F) -> Result<Self, MatrixError>
+    where
+        F: Fn(f64, f64) -> f64 + Sync,
+    {
+        if vector.shape() != (self.rows, 1) {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.rows, 1),
+                actual: vector.shape(),
+            });
+        }
+
+        self.broadcast(|row, column| operation(self.data[row][column], vector.data[row][0]))
+    }
+
+    // Fails when an element is not a finite number, as after dividing by a zero in the vector
+    fn broadcast<F>(&self, element: F) -> Result<Self, MatrixError>
+    where
+        F: Fn(usize, usize) -> f64 + Sync,
+    {
+        let data = self.rows_from(|row| {
+            (0..self.columns)
+                .map(|column| element(row, column))
+                .collect()
+        });
+
+        if data.iter().flatten().any(|num| !num.is_finite()) {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        Ok(Self::new_with_data(
+            data.into_iter()
+                .map(|row| row.into_iter().map(round_result).collect())
+                .collect(),
+        )
+        .unwrap())
+    }
+
+    // Adds a 1 × n vector to every row, such as to shift each column by its own offset
+    pub fn add_row_vector(&self, vector: &Matrix) -> Result<Self, MatrixError> {
+        self.broadcast_row(vector, |a, b| a + b)
+    }
+
+    // Subtracts a 1 × n vector from every row, such as the column means to center a data matrix
+    pub fn sub_row_vector(&self, vector: &Matrix) -> Result<Self, MatrixError> {
+        self.broadcast_row(vector, |a, b| a - b)
+    }
+
+    pub fn mul_row_vector(&self, vector: &Matrix) -> Result<Self, MatrixError> {
+        self.broadcast_row(vector, |a, b| a * b)
+    }
+
+    // Divides every row by a 1 × n vector, such as the column standard deviations to scale a data
+    // matrix
+    pub fn div_row_vector(&self, vector: &Matrix) -> Result<Self, MatrixError> {
+        self.broadcast_row(vector, |a, b| a / b)
+    }
+
+    pub fn add_column_vector(&self, vector: &Matrix) -> Result<Self, MatrixError> {
+        self.broadcast_column(vector, |a, b| a + b)
+    }
+
+    pub fn sub_column_vector(&self, vector: &Matrix) -> Result<Self, MatrixError> {
+        self.broadcast_column(vector, |a, b| a - b)
+    }
+
+    pub fn mul_column_vector(&self, vector: &Matrix) -> Result<Self, MatrixError> {
+        self.broadcast_column(vector, |a, b| a * b)
+    }
+
+    pub fn div_column_vector(&self, vector: &Matrix) -> Result<Self, MatrixError> {
+        self.broadcast_column(vector, |a, b| a / b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn row_vector() {
+        let [matrix_2x3, _, _] = test_utils::generic_examples();
+        let vector = Matrix::new_with_data(vec![vec![1.0, 2.0, 4.0]]).unwrap();
+
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![8.2, 15.8, 9.1], vec![10.3, 4.7, 10.4]]).unwrap(),
+            matrix_2x3.add_row_vector(&vector).unwrap()
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![6.2, 11.8, 1.1], vec![8.3, 0.7, 2.4]]).unwrap(),
+            matrix_2x3.sub_row_vector(&vector).unwrap()
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![7.2, 27.6, 20.4], vec![9.3, 5.4, 25.6]]).unwrap(),
+            matrix_2x3.mul_row_vector(&vector).unwrap()
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![7.2, 6.9, 1.275], vec![9.3, 1.35, 1.6]]).unwrap(),
+            matrix_2x3.div_row_vector(&vector).unwrap()
+        );
+
+        // Centering a data matrix leaves every column with a zero mean
+        let means = Matrix::new_with_data(vec![vec![8.25, 8.25, 5.75]]).unwrap();
+        let centered = matrix_2x3.sub_row_vector(&means).unwrap();
+        assert_eq!(Ok(vec![-1.05, 1.05]), centered.get_column(0));
+
+        let zero = Matrix::new_with_data(vec![vec![1.0, 0.0, 1.0]]).unwrap();
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            matrix_2x3.div_row_vector(&zero).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (1, 3),
+                actual: (3, 1)
+            },
+            matrix_2x3.add_row_vector(&vector.transpose()).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn column_vector() {
+        let [matrix_2x3, _, _] = test_utils::generic_examples();
+        let vector = Matrix::new_with_data(vec![vec![2.0], vec![-1.0]]).unwrap();
+
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![9.2, 15.8, 7.1], vec![8.3, 1.7, 5.4]]).unwrap(),
+            matrix_2x3.add_column_vector(&vector).unwrap()
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![5.2, 11.8, 3.1], vec![10.3, 3.7, 7.4]]).unwrap(),
+            matrix_2x3.sub_column_vector(&vector).unwrap()
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![14.4, 27.6, 10.2], vec![-9.3, -2.7, -6.4]]).unwrap(),
+            matrix_2x3.mul_column_vector(&vector).unwrap()
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![3.6, 6.9, 2.55], vec![-9.3, -2.7, -6.4]]).unwrap(),
+            matrix_2x3.div_column_vector(&vector).unwrap()
+        );
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 1),
+                actual: (1, 2)
+            },
+            matrix_2x3
+                .mul_column_vector(&vector.transpose())
+                .unwrap_err()
+        );
+    }
+}
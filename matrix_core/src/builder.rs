@@ -0,0 +1,110 @@
+use crate::{Matrix, MatrixError};
+
+// Collects a matrix one row at a time into memory reserved up front, checking each row as it
+// arrives instead of the whole data at the end
+#[derive(Debug, PartialEq, Clone)]
+pub struct MatrixBuilder {
+    columns: usize,
+    data: Vec<Vec<f64>>,
+}
+
+impl Matrix {
+    // Room for `rows` rows is reserved, though more can still be pushed
+    pub fn with_capacity(rows: usize, columns: usize) -> Result<MatrixBuilder, MatrixError> {
+        if rows == 0 || columns == 0 {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        Ok(MatrixBuilder {
+            columns,
+            data: Vec::with_capacity(rows),
+        })
+    }
+}
+
+impl MatrixBuilder {
+    // The rows pushed so far
+    pub fn rows(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    pub fn push_row(&mut self, row: Vec<f64>) -> Result<(), MatrixError> {
+        if row.len() != self.columns {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (1, self.columns),
+                actual: (1, row.len()),
+            });
+        }
+
+        self.data.push(row);
+        Ok(())
+    }
+
+    pub fn build(self) -> Result<Matrix, MatrixError> {
+        if self.data.is_empty() {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        Ok(Matrix {
+            rows: self.data.len(),
+            columns: self.columns,
+            data: self.data.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn build() {
+        for matrix in test_utils::generic_examples() {
+            let mut builder = Matrix::with_capacity(matrix.rows, matrix.columns).unwrap();
+            assert!(builder.capacity() >= matrix.rows);
+
+            for row in matrix.get_data() {
+                builder.push_row(row).unwrap();
+            }
+            assert_eq!(matrix.rows, builder.rows());
+            assert_eq!(matrix, builder.build().unwrap());
+        }
+    }
+
+    #[test]
+    fn invalid_rows() {
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::with_capacity(0, 3).unwrap_err()
+        );
+
+        let mut builder = Matrix::with_capacity(2, 3).unwrap();
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (1, 3),
+                actual: (1, 2)
+            },
+            builder.push_row(vec![1.0, 2.0]).unwrap_err()
+        );
+        assert_eq!(0, builder.rows());
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            builder.clone().build().unwrap_err()
+        );
+
+        // Going past the reserved rows only costs a reallocation
+        for _ in 0..3 {
+            builder.push_row(vec![1.0, 2.0, 3.0]).unwrap();
+        }
+        assert_eq!((3, 3), builder.build().unwrap().shape());
+    }
+}
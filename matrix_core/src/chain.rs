@@ -0,0 +1,188 @@
+use crate::{
+    functions::{self, Data},
+    trace::Span,
+    Matrix, MatrixError,
+};
+
+// The product of a chain of matrices, together with the order it was multiplied in and what that
+// order saved over multiplying from left to right
+#[derive(Debug, PartialEq, Clone)]
+pub struct ChainProduct {
+    product: Matrix,
+    parenthesization: String,
+    cost: u64,
+    left_to_right_cost: u64,
+}
+
+impl ChainProduct {
+    pub fn product(&self) -> &Matrix {
+        &self.product
+    }
+
+    pub fn into_product(self) -> Matrix {
+        self.product
+    }
+
+    // The order the matrices were multiplied in, naming them A1, A2 and so on, as in "(A1A2)A3"
+    pub fn parenthesization(&self) -> &str {
+        &self.parenthesization
+    }
+
+    // Scalar multiplications in the chosen order, where a p × q times q × r product takes pqr
+    pub fn cost(&self) -> u64 {
+        self.cost
+    }
+
+    pub fn left_to_right_cost(&self) -> u64 {
+        self.left_to_right_cost
+    }
+
+    pub fn saved_cost(&self) -> u64 {
+        self.left_to_right_cost - self.cost
+    }
+}
+
+// The cheapest split of every run of the chain, by the textbook dynamic program over run lengths.
+// `dimensions` holds the rows of each matrix followed by the columns of the last one
+fn optimal_splits(dimensions: &[u64]) -> (u64, Vec<Vec<usize>>) {
+    let n = dimensions.len() - 1;
+    let mut costs = vec![vec![0u64; n]; n];
+    let mut splits = vec![vec![0; n]; n];
+    for length in 2..=n {
+        for first in 0..=n - length {
+            let last = first + length - 1;
+            (costs[first][last], splits[first][last]) = (first..last)
+                .map(|split| {
+                    let cost = costs[first][split]
+                        + costs[split + 1][last]
+                        + dimensions[first] * dimensions[split + 1] * dimensions[last + 1];
+                    (cost, split)
+                })
+                .min()
+                .unwrap();
+        }
+    }
+
+    (costs[0][n - 1], splits)
+}
+
+fn multiply_run(matrices: &[&Matrix], splits: &[Vec<usize>], first: usize, last: usize) -> Data {
+    if first == last {
+        return matrices[first].data.to_vec();
+    }
+
+    let split = splits[first][last];
+    functions::product(
+        &multiply_run(matrices, splits, first, split),
+        &multiply_run(matrices, splits, split + 1, last),
+    )
+}
+
+fn parenthesize(splits: &[Vec<usize>], first: usize, last: usize) -> String {
+    let run = |first, last| match first == last {
+        true => format!("A{}", first + 1),
+        false => format!("({})", parenthesize(splits, first, last)),
+    };
+
+    let split = splits[first][last];
+    format!("{}{}", run(first, split), run(split + 1, last))
+}
+
+impl Matrix {
+    // Multiplies A1·A2·…·An in the order with the fewest scalar multiplications. The product doesn't
+    // depend on the order, but the cost can differ by orders of magnitude, as for a column vector
+    // at the end of a chain of square matrices
+    pub fn multiply_chain(matrices: &[&Matrix]) -> Result<ChainProduct, MatrixError> {
+        let (first, rest) = matrices.split_first().ok_or(MatrixError::ZeroDimension)?;
+        let mut previous = first;
+        for matrix in rest {
+            if previous.columns != matrix.rows {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (previous.columns, matrix.columns),
+                    actual: matrix.shape(),
+                });
+            }
+            previous = matrix;
+        }
+
+        let dimensions: Vec<u64> = matrices
+            .iter()
+            .map(|matrix| matrix.rows as u64)
+            .chain([previous.columns as u64])
+            .collect();
+        let (cost, splits) = optimal_splits(&dimensions);
+        let left_to_right_cost = (1..matrices.len())
+            .map(|k| dimensions[0] * dimensions[k] * dimensions[k + 1])
+            .sum();
+
+        let shapes: Vec<(usize, usize)> = matrices.iter().map(|matrix| matrix.shape()).collect();
+        let _span = Span::new("multiply_chain", &shapes, 2 * cost);
+        let last = matrices.len() - 1;
+
+        Ok(ChainProduct {
+            product: functions::rounded(multiply_run(matrices, &splits, 0, last)),
+            parenthesization: match last {
+                0 => "A1".to_string(),
+                _ => parenthesize(&splits, 0, last),
+            },
+            cost,
+            left_to_right_cost,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn multiply_chain() {
+        let [matrix_2x3, another_matrix_2x3, matrix_4x2] = test_utils::generic_examples();
+        let matrix_3x2 = another_matrix_2x3.transpose();
+
+        // 4×2 · 2×3 · 3×2: (A1A2)A3 takes 24 + 24, A1(A2A3) takes 12 + 16
+        let chain = Matrix::multiply_chain(&[&matrix_4x2, &matrix_2x3, &matrix_3x2]).unwrap();
+        assert_eq!("A1(A2A3)", chain.parenthesization());
+        assert_eq!(28, chain.cost());
+        assert_eq!(48, chain.left_to_right_cost());
+        assert_eq!(20, chain.saved_cost());
+
+        let expected = (&(&matrix_4x2 * &matrix_2x3).unwrap() * &matrix_3x2).unwrap();
+        assert!(
+            chain
+                .product()
+                .distance(&expected, crate::Norm::Infinity)
+                .unwrap()
+                < 1e-3
+        );
+
+        // The classic example from CLRS, with dimensions 30, 35, 15, 5, 10, 20 and 25
+        let dimensions = [30, 35, 15, 5, 10, 20, 25];
+        let matrices: Vec<Matrix> = dimensions
+            .windows(2)
+            .map(|pair| Matrix::new_zero_matrix(pair[0], pair[1]).unwrap())
+            .collect();
+        let chain = Matrix::multiply_chain(&matrices.iter().collect::<Vec<_>>()).unwrap();
+        assert_eq!("(A1(A2A3))((A4A5)A6)", chain.parenthesization());
+        assert_eq!(15125, chain.cost());
+        assert_eq!((30, 25), chain.product().shape());
+
+        let single = Matrix::multiply_chain(&[&matrix_2x3]).unwrap();
+        assert_eq!("A1", single.parenthesization());
+        assert_eq!(0, single.saved_cost());
+        assert_eq!(matrix_2x3, single.into_product());
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (3, 3),
+                actual: (2, 3)
+            },
+            Matrix::multiply_chain(&[&matrix_2x3, &another_matrix_2x3]).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::multiply_chain(&[]).unwrap_err()
+        );
+    }
+}
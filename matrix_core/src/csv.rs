@@ -0,0 +1,197 @@
+use crate::{Matrix, MatrixError};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::Path,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Delimiter {
+    // Commas on lines that have them, whitespace otherwise. Writing uses commas
+    #[default]
+    Auto,
+    Char(char),
+    Whitespace,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct CsvOptions {
+    pub delimiter: Delimiter,
+    // Skips the first non-empty line when reading
+    pub has_header: bool,
+    // Decimal places when writing, or every digit when None
+    pub precision: Option<usize>,
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CsvError {
+    Io(io::Error),
+    InvalidNumber(String),
+    Matrix(MatrixError),
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(err) => write!(f, "{err}"),
+            CsvError::InvalidNumber(field) => write!(f, "'{field}' is not a real number"),
+            CsvError::Matrix(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Io(err) => Some(err),
+            CsvError::InvalidNumber(_) => None,
+            CsvError::Matrix(err) => Some(err),
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(err: io::Error) -> Self {
+        CsvError::Io(err)
+    }
+}
+
+impl From<MatrixError> for CsvError {
+    fn from(err: MatrixError) -> Self {
+        CsvError::Matrix(err)
+    }
+}
+
+impl Matrix {
+    pub fn from_csv_str(text: &str, options: CsvOptions) -> Result<Self, CsvError> {
+        let data = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .skip(if options.has_header { 1 } else { 0 })
+            .map(|line| {
+                let fields: Vec<&str> = match options.delimiter {
+                    Delimiter::Char(delimiter) => line.split(delimiter).collect(),
+                    Delimiter::Auto if line.contains(',') => line.split(',').collect(),
+                    Delimiter::Auto | Delimiter::Whitespace => line.split_whitespace().collect(),
+                };
+
+                fields
+                    .into_iter()
+                    .map(|field| {
+                        field
+                            .trim()
+                            .parse::<f64>()
+                            .map_err(|_| CsvError::InvalidNumber(field.trim().to_string()))
+                    })
+                    .collect::<Result<Vec<f64>, _>>()
+            })
+            .collect::<Result<Vec<Vec<f64>>, _>>()?;
+
+        Ok(Matrix::new_with_data(data)?)
+    }
+
+    pub fn from_csv_path(path: impl AsRef<Path>, options: CsvOptions) -> Result<Self, CsvError> {
+        Matrix::from_csv_str(&fs::read_to_string(path)?, options)
+    }
+
+    pub fn to_csv(&self, options: CsvOptions) -> String {
+        let delimiter = match options.delimiter {
+            Delimiter::Auto => ",".to_string(),
+            Delimiter::Char(delimiter) => delimiter.to_string(),
+            Delimiter::Whitespace => " ".to_string(),
+        };
+
+        self.data
+            .iter()
+            .map(|row| {
+                row.iter()
+                    // Adding 0.0 turns -0.0 into 0.0 so results like the inverse don't print "-0"
+                    .map(|num| match options.precision {
+                        None => (num + 0.0).to_string(),
+                        Some(precision) => format!("{:.precision$}", num + 0.0),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(&delimiter)
+            })
+            .fold(String::new(), |csv, line| csv + &line + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn from_csv_str() {
+        let expected = Matrix::new_with_data(vec![vec![1.0, 2.5], vec![-3.0, 4.0]]).unwrap();
+
+        assert_eq!(
+            expected,
+            Matrix::from_csv_str("1,2.5\n-3, 4\n", CsvOptions::default()).unwrap()
+        );
+        assert_eq!(
+            expected,
+            Matrix::from_csv_str("1 2.5\n\n  -3\t4", CsvOptions::default()).unwrap()
+        );
+        assert_eq!(
+            expected,
+            Matrix::from_csv_str(
+                "x;y\n1;2.5\n-3;4",
+                CsvOptions {
+                    delimiter: Delimiter::Char(';'),
+                    has_header: true,
+                    ..Default::default()
+                }
+            )
+            .unwrap()
+        );
+
+        assert!(matches!(
+            Matrix::from_csv_str("1,a", CsvOptions::default()),
+            Err(CsvError::InvalidNumber(field)) if field == "a"
+        ));
+        assert!(matches!(
+            Matrix::from_csv_str("1,2\n3", CsvOptions::default()),
+            Err(CsvError::Matrix(MatrixError::InconsistentColumnSize))
+        ));
+        assert!(matches!(
+            Matrix::from_csv_str("\n", CsvOptions::default()),
+            Err(CsvError::Matrix(MatrixError::ZeroDimension))
+        ));
+    }
+
+    #[test]
+    fn to_csv() {
+        let matrix = Matrix::new_with_data(vec![vec![1.0, -0.0], vec![0.125, 2.0]]).unwrap();
+
+        assert_eq!("1,0\n0.125,2\n", matrix.to_csv(CsvOptions::default()));
+        assert_eq!(
+            "1.00\t0.00\n0.12\t2.00\n",
+            matrix.to_csv(CsvOptions {
+                delimiter: Delimiter::Char('\t'),
+                precision: Some(2),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn csv_file_round_trip() {
+        let matrix = &test_utils::generic_examples()[0];
+        let path = std::env::temp_dir().join(format!("matrix_core_csv_{}.csv", std::process::id()));
+
+        fs::write(&path, matrix.to_csv(CsvOptions::default())).unwrap();
+        let read = Matrix::from_csv_path(&path, CsvOptions::default());
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(matrix, &read.unwrap());
+        assert!(matches!(
+            Matrix::from_csv_path(&path, CsvOptions::default()),
+            Err(CsvError::Io(_))
+        ));
+    }
+}
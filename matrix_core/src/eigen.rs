@@ -0,0 +1,382 @@
+use crate::{Matrix, MatrixError};
+
+// Sweeps of the cyclic Jacobi method; it converges quadratically, so this is never reached in practice
+const MAX_SWEEPS: usize = 100;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SymmetricEigen {
+    eigenvalues: Vec<f64>,
+    eigenvectors: Matrix,
+}
+
+impl SymmetricEigen {
+    // In descending order
+    pub fn eigenvalues(&self) -> &[f64] {
+        &self.eigenvalues
+    }
+
+    // Column i is a unit eigenvector for eigenvalues[i], and the columns are orthogonal
+    pub fn eigenvectors(&self) -> &Matrix {
+        &self.eigenvectors
+    }
+}
+
+fn rounded_eigen(eigenvalues: Vec<f64>, eigenvectors: Vec<Vec<f64>>) -> SymmetricEigen {
+    SymmetricEigen {
+        eigenvalues: eigenvalues.into_iter().map(crate::round_result).collect(),
+        eigenvectors: Matrix::new_with_data(
+            eigenvectors
+                .into_iter()
+                .map(|row| row.into_iter().map(crate::round_result).collect())
+                .collect(),
+        )
+        .unwrap(),
+    }
+}
+
+// L⁻¹X, solving for one row at a time from the top
+fn forward_substitute(lower: &[Vec<f64>], x: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let mut result: Vec<Vec<f64>> = Vec::with_capacity(x.len());
+    for (i, row) in x.iter().enumerate() {
+        let solved = (0..row.len())
+            .map(|column| {
+                let dot: f64 = (0..i).map(|k| lower[i][k] * result[k][column]).sum();
+                (row[column] - dot) / lower[i][i]
+            })
+            .collect();
+        result.push(solved);
+    }
+    result
+}
+
+// L⁻ᵀY, one column of Y at a time
+fn back_substitute_transpose(lower: &[Vec<f64>], mut y: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let n = lower.len();
+    for column in 0..y[0].len() {
+        for i in (0..n).rev() {
+            let dot: f64 = ((i + 1)..n).map(|k| lower[k][i] * y[k][column]).sum();
+            y[i][column] = (y[i][column] - dot) / lower[i][i];
+        }
+    }
+    y
+}
+
+impl Matrix {
+    // Always real for a symmetric matrix, and more accurate than a general eigenvalue algorithm
+    pub fn eigen_symmetric(&self) -> Result<SymmetricEigen, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        if !self.is_symmetric() {
+            return Err(MatrixError::NonSymmetricMatrix);
+        }
+
+        let (eigenvalues, eigenvectors) = self.symmetric_eigen_decomposition();
+        Ok(rounded_eigen(eigenvalues, eigenvectors))
+    }
+
+    // Solves Av = λBv for a symmetric A and a symmetric positive definite B. With B = LLᵀ this is
+    // the ordinary problem for L⁻¹AL⁻ᵀ, whose eigenvectors y give v = L⁻ᵀy. The eigenvectors are
+    // B-orthonormal, so VᵀBV = I instead of VᵀV = I
+    pub fn eigen_generalized(&self, b: &Matrix) -> Result<SymmetricEigen, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        if b.shape() != self.shape() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.shape(),
+                actual: b.shape(),
+            });
+        }
+
+        if !self.is_symmetric() {
+            return Err(MatrixError::NonSymmetricMatrix);
+        }
+
+        // Fails for a B that is not symmetric positive definite, with the same errors as `cholesky`
+        let lower = b.unrounded_cholesky()?;
+        let half = forward_substitute(&lower, &self.data);
+        let reduced = forward_substitute(
+            &lower,
+            &Matrix::new_with_data(half).unwrap().transpose().data,
+        );
+
+        // Rounding leaves the two triangles slightly apart, and the Jacobi method assumes they match
+        let n = self.rows;
+        let reduced: Vec<Vec<f64>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| (reduced[i][j] + reduced[j][i]) / 2.0)
+                    .collect()
+            })
+            .collect();
+
+        let (eigenvalues, eigenvectors) = Matrix::new_with_data(reduced)
+            .unwrap()
+            .symmetric_eigen_decomposition();
+        Ok(rounded_eigen(
+            eigenvalues,
+            back_substitute_transpose(&lower, eigenvectors),
+        ))
+    }
+
+    // The positive semidefinite matrix closest to A in the Frobenius norm: the symmetric part of A
+    // with its negative eigenvalues replaced by zero. Repairs covariance matrices that rounding or
+    // pairwise estimation has pushed slightly indefinite
+    pub fn nearest_psd(&self) -> Result<Self, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        let n = self.rows;
+        let symmetric: Vec<Vec<f64>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| (self.data[i][j] + self.data[j][i]) / 2.0)
+                    .collect()
+            })
+            .collect();
+        let (eigenvalues, eigenvectors) = Matrix::new_with_data(symmetric)
+            .unwrap()
+            .symmetric_eigen_decomposition();
+
+        let mut nearest = Matrix::new_zero_matrix(n, n).unwrap();
+        for i in 0..n {
+            for j in 0..n {
+                let sum = crate::compensated_sum(eigenvalues.iter().enumerate().map(
+                    |(k, eigenvalue)| eigenvalue.max(0.0) * eigenvectors[i][k] * eigenvectors[j][k],
+                ));
+                nearest.data[i][j] = crate::round_result(sum);
+            }
+        }
+
+        Ok(nearest)
+    }
+
+    // Eigenvalues of a symmetric matrix in descending order, by the cyclic Jacobi method
+    pub(crate) fn symmetric_eigenvalues(&self) -> Vec<f64> {
+        self.symmetric_eigen_decomposition().0
+    }
+
+    // Eigenvalues in descending order along with the matrix whose columns are the matching unit eigenvectors
+    pub(crate) fn symmetric_eigen_decomposition(&self) -> (Vec<f64>, Vec<Vec<f64>>) {
+        let n = self.rows;
+        let mut a = self.data.to_vec();
+        let mut v = Matrix::nth_identity(n).unwrap().take_data();
+
+        for _ in 0..MAX_SWEEPS {
+            let off_diagonal: f64 = (0..n)
+                .flat_map(|i| (0..n).filter(move |j| *j != i).map(move |j| (i, j)))
+                .map(|(i, j)| a[i][j] * a[i][j])
+                .sum();
+            let total: f64 = a.iter().flatten().map(|num| num * num).sum();
+            if off_diagonal <= f64::EPSILON * f64::EPSILON * total {
+                break;
+            }
+
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    if a[p][q] == 0.0 {
+                        continue;
+                    }
+
+                    let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    for row in a.iter_mut().chain(v.iter_mut()) {
+                        let (row_p, row_q) = (row[p], row[q]);
+                        row[p] = c * row_p - s * row_q;
+                        row[q] = s * row_p + c * row_q;
+                    }
+                    let (row_p, row_q) = (a[p].clone(), a[q].clone());
+                    a[p] = row_p
+                        .iter()
+                        .zip(row_q.iter())
+                        .map(|(x, y)| c * x - s * y)
+                        .collect();
+                    a[q] = row_p
+                        .iter()
+                        .zip(row_q.iter())
+                        .map(|(x, y)| s * x + c * y)
+                        .collect();
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|i, j| a[*j][*j].total_cmp(&a[*i][*i]));
+
+        (
+            order.iter().map(|i| a[*i][*i]).collect(),
+            v.iter()
+                .map(|row| order.iter().map(|i| row[*i]).collect())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn symmetric_eigenvalues() {
+        let eigenvalues = Matrix::new_with_data(vec![
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 2.0],
+        ])
+        .unwrap()
+        .symmetric_eigenvalues();
+
+        let expected = [
+            2.0 + std::f64::consts::SQRT_2,
+            2.0,
+            2.0 - std::f64::consts::SQRT_2,
+        ];
+        assert!(eigenvalues
+            .iter()
+            .zip(expected.iter())
+            .all(|(actual, expected)| (actual - expected).abs() < 1e-12));
+
+        assert_eq!(
+            vec![3.0, 1.0],
+            Matrix::new_with_data(vec![vec![1.0, 0.0], vec![0.0, 3.0]])
+                .unwrap()
+                .symmetric_eigenvalues()
+        );
+    }
+
+    #[test]
+    fn symmetric_eigen_decomposition() {
+        let matrix = Matrix::new_with_data(vec![vec![2.0, 1.0], vec![1.0, 2.0]]).unwrap();
+        let (eigenvalues, eigenvectors) = matrix.symmetric_eigen_decomposition();
+
+        assert!((eigenvalues[0] - 3.0).abs() < 1e-12 && (eigenvalues[1] - 1.0).abs() < 1e-12);
+        for (column, eigenvalue) in eigenvalues.iter().enumerate() {
+            for row in 0..2 {
+                let product: f64 = (0..2)
+                    .map(|k| matrix.data[row][k] * eigenvectors[k][column])
+                    .sum();
+                assert!((product - eigenvalue * eigenvectors[row][column]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn eigen_symmetric() {
+        let matrix = Matrix::new_with_data(vec![
+            vec![4.0, 1.0, 2.0],
+            vec![1.0, 3.0, 0.0],
+            vec![2.0, 0.0, 5.0],
+        ])
+        .unwrap();
+        let eigen = matrix.eigen_symmetric().unwrap();
+        let eigenvectors = eigen.eigenvectors();
+
+        assert!(eigen
+            .eigenvalues()
+            .windows(2)
+            .all(|pair| pair[0] >= pair[1]));
+        let orthogonality = (&eigenvectors.transpose() * eigenvectors).unwrap();
+        let reconstruction =
+            (&(eigenvectors * &Matrix::new_diagonal_matrix(eigen.eigenvalues()).unwrap()).unwrap()
+                * &eigenvectors.transpose())
+                .unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                let identity = if i == j { 1.0 } else { 0.0 };
+                assert!((orthogonality.data[i][j] - identity).abs() < 1e-4);
+                assert!((reconstruction.data[i][j] - matrix.data[i][j]).abs() < 1e-4);
+            }
+        }
+
+        assert_eq!(
+            MatrixError::NonSymmetricMatrix,
+            test_utils::square_examples()[2]
+                .eigen_symmetric()
+                .unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[0]
+                .eigen_symmetric()
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn eigen_generalized() {
+        let a = Matrix::new_with_data(vec![vec![6.0, 2.0], vec![2.0, 3.0]]).unwrap();
+        let b = Matrix::new_with_data(vec![vec![2.0, 0.0], vec![0.0, 1.0]]).unwrap();
+        let eigen = a.eigen_generalized(&b).unwrap();
+        let v = eigen.eigenvectors();
+
+        // det(A - λB) = 2λ² - 12λ + 14
+        assert_eq!([4.41421, 1.58579], eigen.eigenvalues());
+        let b_orthogonality = (&(&v.transpose() * &b).unwrap() * v).unwrap();
+        for i in 0..2 {
+            for j in 0..2 {
+                let identity = if i == j { 1.0 } else { 0.0 };
+                assert!((b_orthogonality.data[i][j] - identity).abs() < 1e-4);
+
+                let av: f64 = (0..2).map(|k| a.data[i][k] * v.data[k][j]).sum();
+                let bv: f64 = (0..2).map(|k| b.data[i][k] * v.data[k][j]).sum();
+                assert!((av - eigen.eigenvalues()[j] * bv).abs() < 1e-4);
+            }
+        }
+
+        assert_eq!(
+            MatrixError::NotPositiveDefinite,
+            a.eigen_generalized(&-&b).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::NotPositiveDefinite,
+            a.eigen_generalized(&Matrix::new_diagonal_matrix(&[1.0, -1.0]).unwrap())
+                .unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::NotPositiveDefinite,
+            a.eigen_generalized(&Matrix::new_diagonal_matrix(&[1.0, 0.0]).unwrap())
+                .unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::NonSymmetricMatrix,
+            a.eigen_generalized(&test_utils::square_examples()[1])
+                .unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 2),
+                actual: (3, 3)
+            },
+            a.eigen_generalized(&Matrix::nth_identity(3).unwrap())
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn nearest_psd() {
+        // Eigenvalues 3 and -1, so only the first survives
+        let indefinite = Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 1.0]]).unwrap();
+        let nearest = indefinite.nearest_psd().unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1.5, 1.5], vec![1.5, 1.5]]).unwrap(),
+            nearest
+        );
+        assert!(nearest.pivoted_cholesky(1e-9).is_ok());
+
+        let psd = Matrix::new_with_data(vec![vec![2.0, 1.0], vec![1.0, 2.0]]).unwrap();
+        assert_eq!(psd, psd.nearest_psd().unwrap());
+
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[0].nearest_psd().unwrap_err()
+        );
+    }
+}
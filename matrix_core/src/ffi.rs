@@ -0,0 +1,292 @@
+// The C API. Matrices are opaque heap pointers created by the `matrix_*` constructors and released
+// with `matrix_free`. Every pointer passed in must be null or come from this API, and `elements`
+// must point to rows * columns doubles. Results are written through the `out` pointers, which are
+// left untouched unless MATRIX_STATUS_OK is returned
+#![allow(clippy::missing_safety_doc)]
+
+use crate::{LinearSystemSolution, Matrix, MatrixError};
+use std::{ffi::c_char, slice};
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MatrixStatus {
+    Ok = 0,
+    NullPointer,
+    ZeroDimension,
+    InconsistentColumnSize,
+    DimensionMismatch,
+    NonSquareMatrix,
+    NonSymmetricMatrix,
+    SingularMatrix,
+    IndexOutOfBounds,
+    NotPositiveSemidefinite,
+    InvalidArgument,
+    NoUniqueSolution,
+    NotPositiveDefinite,
+}
+
+impl From<MatrixError> for MatrixStatus {
+    fn from(err: MatrixError) -> Self {
+        match err {
+            MatrixError::ZeroDimension => MatrixStatus::ZeroDimension,
+            MatrixError::InconsistentColumnSize => MatrixStatus::InconsistentColumnSize,
+            MatrixError::DimensionMismatch { .. } => MatrixStatus::DimensionMismatch,
+            MatrixError::NonSquareMatrix => MatrixStatus::NonSquareMatrix,
+            MatrixError::NonSymmetricMatrix => MatrixStatus::NonSymmetricMatrix,
+            MatrixError::SingularMatrix => MatrixStatus::SingularMatrix,
+            MatrixError::IndexOutOfBounds { .. } => MatrixStatus::IndexOutOfBounds,
+            MatrixError::NotPositiveSemidefinite => MatrixStatus::NotPositiveSemidefinite,
+            MatrixError::NotPositiveDefinite => MatrixStatus::NotPositiveDefinite,
+            MatrixError::InvalidArgument => MatrixStatus::InvalidArgument,
+        }
+    }
+}
+
+unsafe fn write<T>(result: Result<T, MatrixError>, out: *mut T) -> MatrixStatus {
+    match (out.is_null(), result) {
+        (true, _) => MatrixStatus::NullPointer,
+        (false, Ok(value)) => {
+            *out = value;
+            MatrixStatus::Ok
+        }
+        (false, Err(err)) => err.into(),
+    }
+}
+
+unsafe fn write_matrix(result: Result<Matrix, MatrixError>, out: *mut *mut Matrix) -> MatrixStatus {
+    write(result.map(|matrix| Box::into_raw(Box::new(matrix))), out)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn matrix_new(
+    rows: usize,
+    columns: usize,
+    elements: *const f64,
+    out: *mut *mut Matrix,
+) -> MatrixStatus {
+    if rows == 0 || columns == 0 {
+        return MatrixStatus::ZeroDimension;
+    }
+    let Some(len) = rows.checked_mul(columns) else {
+        return MatrixStatus::InvalidArgument;
+    };
+    if elements.is_null() {
+        return MatrixStatus::NullPointer;
+    }
+
+    let elements = slice::from_raw_parts(elements, len);
+    write_matrix(
+        Matrix::new_with_data(elements.chunks(columns).map(<[f64]>::to_vec).collect()),
+        out,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn matrix_identity(size: usize, out: *mut *mut Matrix) -> MatrixStatus {
+    write_matrix(Matrix::nth_identity(size), out)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn matrix_free(matrix: *mut Matrix) {
+    if !matrix.is_null() {
+        drop(Box::from_raw(matrix));
+    }
+}
+
+// Zero for a null matrix, since no real matrix has zero rows or columns
+#[no_mangle]
+pub unsafe extern "C" fn matrix_rows(matrix: *const Matrix) -> usize {
+    matrix.as_ref().map_or(0, |matrix| matrix.rows)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn matrix_columns(matrix: *const Matrix) -> usize {
+    matrix.as_ref().map_or(0, |matrix| matrix.columns)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn matrix_get(
+    matrix: *const Matrix,
+    row: usize,
+    column: usize,
+    out: *mut f64,
+) -> MatrixStatus {
+    let Some(matrix) = matrix.as_ref() else {
+        return MatrixStatus::NullPointer;
+    };
+    write(matrix.get_element(row, column), out)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn matrix_set(
+    matrix: *mut Matrix,
+    row: usize,
+    column: usize,
+    value: f64,
+) -> MatrixStatus {
+    let Some(matrix) = matrix.as_mut() else {
+        return MatrixStatus::NullPointer;
+    };
+    match matrix.set_element(row, column, value) {
+        Ok(()) => MatrixStatus::Ok,
+        Err(err) => err.into(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn matrix_multiply(
+    a: *const Matrix,
+    b: *const Matrix,
+    out: *mut *mut Matrix,
+) -> MatrixStatus {
+    let (Some(a), Some(b)) = (a.as_ref(), b.as_ref()) else {
+        return MatrixStatus::NullPointer;
+    };
+    write_matrix(a * b, out)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn matrix_determinant(matrix: *const Matrix, out: *mut f64) -> MatrixStatus {
+    let Some(matrix) = matrix.as_ref() else {
+        return MatrixStatus::NullPointer;
+    };
+    write(matrix.determinant(), out)
+}
+
+// Solves `a x = b`, failing with MATRIX_STATUS_NO_UNIQUE_SOLUTION for inconsistent or
+// underdetermined systems
+#[no_mangle]
+pub unsafe extern "C" fn matrix_solve(
+    a: *const Matrix,
+    b: *const Matrix,
+    out: *mut *mut Matrix,
+) -> MatrixStatus {
+    let (Some(a), Some(b)) = (a.as_ref(), b.as_ref()) else {
+        return MatrixStatus::NullPointer;
+    };
+    match a.solve_general(b) {
+        Ok(LinearSystemSolution::Unique(solution)) => write_matrix(Ok(solution), out),
+        Ok(_) => MatrixStatus::NoUniqueSolution,
+        Err(err) => err.into(),
+    }
+}
+
+// A static, null-terminated description of the status, which must not be freed
+#[no_mangle]
+pub extern "C" fn matrix_status_message(status: MatrixStatus) -> *const c_char {
+    let message = match status {
+        MatrixStatus::Ok => c"success",
+        MatrixStatus::NullPointer => c"a required pointer is null",
+        MatrixStatus::ZeroDimension => c"matrix dimensions must be greater than zero",
+        MatrixStatus::InconsistentColumnSize => c"every row must have the same number of elements",
+        MatrixStatus::DimensionMismatch => c"matrix dimensions do not match",
+        MatrixStatus::NonSquareMatrix => c"matrix is not square",
+        MatrixStatus::NonSymmetricMatrix => c"matrix is not symmetric",
+        MatrixStatus::SingularMatrix => c"matrix is singular",
+        MatrixStatus::IndexOutOfBounds => c"index is out of bounds",
+        MatrixStatus::NotPositiveSemidefinite => c"matrix is not positive semidefinite",
+        MatrixStatus::InvalidArgument => c"invalid argument",
+        MatrixStatus::NoUniqueSolution => c"the system does not have a unique solution",
+        MatrixStatus::NotPositiveDefinite => c"matrix is not positive definite",
+    };
+    message.as_ptr()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use std::{ffi::CStr, ptr};
+
+    #[test]
+    fn create_and_access() {
+        unsafe {
+            let mut matrix = ptr::null_mut();
+            let elements = [7.2, 13.8, 5.1, 9.3, 2.7, 6.4];
+            assert_eq!(
+                MatrixStatus::Ok,
+                matrix_new(2, 3, elements.as_ptr(), &mut matrix)
+            );
+            assert_eq!(test_utils::generic_examples()[0], *matrix);
+            assert_eq!((2, 3), (matrix_rows(matrix), matrix_columns(matrix)));
+
+            let mut element = 0.0;
+            assert_eq!(MatrixStatus::Ok, matrix_set(matrix, 1, 2, 1.5));
+            assert_eq!(MatrixStatus::Ok, matrix_get(matrix, 1, 2, &mut element));
+            assert_eq!(1.5, element);
+            assert_eq!(
+                MatrixStatus::IndexOutOfBounds,
+                matrix_get(matrix, 2, 0, &mut element)
+            );
+            assert_eq!(
+                MatrixStatus::NullPointer,
+                matrix_get(matrix, 0, 0, ptr::null_mut())
+            );
+            matrix_free(matrix);
+
+            assert_eq!(
+                MatrixStatus::ZeroDimension,
+                matrix_new(0, 3, ptr::null(), &mut matrix)
+            );
+            assert_eq!(
+                MatrixStatus::NullPointer,
+                matrix_new(2, 3, ptr::null(), &mut matrix)
+            );
+            assert_eq!(0, matrix_rows(ptr::null()));
+            matrix_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn operations() {
+        unsafe {
+            let (mut a, mut b, mut product, mut solution) = (
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+            );
+            matrix_new(2, 2, [2.0, 1.0, 1.0, 3.0].as_ptr(), &mut a);
+            matrix_new(2, 1, [3.0, 5.0].as_ptr(), &mut b);
+
+            let mut determinant = 0.0;
+            assert_eq!(MatrixStatus::Ok, matrix_determinant(a, &mut determinant));
+            assert_eq!(5.0, determinant);
+
+            assert_eq!(MatrixStatus::Ok, matrix_multiply(a, b, &mut product));
+            assert_eq!((&*a * &*b).unwrap(), *product);
+            assert_eq!(
+                MatrixStatus::DimensionMismatch,
+                matrix_multiply(b, b, &mut product)
+            );
+
+            assert_eq!(MatrixStatus::Ok, matrix_solve(a, b, &mut solution));
+            assert_eq!(vec![vec![0.8], vec![1.4]], (*solution).get_data());
+            assert_eq!(
+                MatrixStatus::NonSquareMatrix,
+                matrix_determinant(b, &mut determinant)
+            );
+
+            let mut singular = ptr::null_mut();
+            matrix_new(2, 2, [1.0, 2.0, 2.0, 4.0].as_ptr(), &mut singular);
+            assert_eq!(
+                MatrixStatus::NoUniqueSolution,
+                matrix_solve(singular, b, &mut solution)
+            );
+
+            for matrix in [a, b, product, solution, singular] {
+                matrix_free(matrix);
+            }
+        }
+    }
+
+    #[test]
+    fn status_message() {
+        let message =
+            unsafe { CStr::from_ptr(matrix_status_message(MatrixStatus::SingularMatrix)) };
+        assert_eq!(
+            MatrixError::SingularMatrix.to_string(),
+            message.to_str().unwrap()
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use crate::Matrix;
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Notation {
+    #[default]
+    Decimal,
+    Scientific,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Border {
+    #[default]
+    None,
+    Ascii,
+    Unicode,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FormatOptions {
+    pub precision: Option<usize>,
+    pub notation: Notation,
+    pub border: Border,
+}
+
+impl FormatOptions {
+    pub fn format_number(&self, num: f64) -> String {
+        match (self.notation, self.precision) {
+            (Notation::Decimal, None) => num.to_string(),
+            (Notation::Decimal, Some(precision)) => format!("{num:.precision$}"),
+            (Notation::Scientific, None) => format!("{num:e}"),
+            (Notation::Scientific, Some(precision)) => format!("{num:.precision$e}"),
+        }
+    }
+
+    pub fn render_cells(&self, cells: &[Vec<String>]) -> String {
+        let columns = cells.first().map_or(0, Vec::len);
+        let max_column_lengths: Vec<usize> = (0..columns)
+            .map(|column| {
+                cells
+                    .iter()
+                    .map(|row| row[column].chars().count())
+                    .max()
+                    .expect("Getting maximum column length")
+            })
+            .collect();
+
+        let lines: Vec<String> = cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(column, cell)| {
+                        let total_pad_len = max_column_lengths[column] - cell.chars().count();
+                        let right_pad_len = total_pad_len / 2;
+                        let left_pad_len = total_pad_len - right_pad_len + 1;
+                        format!(
+                            "{}{cell}{}",
+                            " ".repeat(left_pad_len),
+                            " ".repeat(right_pad_len)
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let width = lines.first().map_or(0, |line| line.chars().count()) + 1;
+        let (top, side, bottom) = match self.border {
+            Border::None => {
+                return lines
+                    .iter()
+                    .fold(String::new(), |output, line| output + line + "\n")
+            }
+            Border::Ascii => (
+                format!("+{}+", "-".repeat(width)),
+                "|",
+                format!("+{}+", "-".repeat(width)),
+            ),
+            Border::Unicode => (
+                format!("┌{}┐", " ".repeat(width)),
+                "│",
+                format!("└{}┘", " ".repeat(width)),
+            ),
+        };
+
+        let mut output = top + "\n";
+        lines
+            .iter()
+            .for_each(|line| output.push_str(&format!("{side}{line} {side}\n")));
+        output + &bottom + "\n"
+    }
+}
+
+pub struct FormattedMatrix<'a> {
+    matrix: &'a Matrix,
+    options: FormatOptions,
+}
+
+impl Display for FormattedMatrix<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let cells: Vec<Vec<String>> = self
+            .matrix
+            .data
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|num| self.options.format_number(*num))
+                    .collect()
+            })
+            .collect();
+
+        write!(f, "{}", self.options.render_cells(&cells))
+    }
+}
+
+impl Matrix {
+    pub fn formatted(&self, options: FormatOptions) -> FormattedMatrix<'_> {
+        FormattedMatrix {
+            matrix: self,
+            options,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn format_number() {
+        let mut options = FormatOptions::default();
+        assert_eq!("2.5", options.format_number(2.5));
+
+        options.precision = Some(3);
+        assert_eq!("2.500", options.format_number(2.5));
+
+        options.notation = Notation::Scientific;
+        assert_eq!("1.235e3", options.format_number(1234.6));
+
+        options.precision = None;
+        assert_eq!("1.2346e3", options.format_number(1234.6));
+    }
+
+    #[test]
+    fn display() {
+        let matrix_4x2 = &test_utils::generic_examples()[2];
+
+        assert_eq!(
+            "  5.6 9.8\n  2.9 7.4\n 11.2 3.1\n  6.3 8.7\n",
+            matrix_4x2.to_string()
+        );
+    }
+
+    #[test]
+    fn borders() {
+        let matrix = Matrix::new_with_data(vec![vec![1.0, 20.0], vec![3.0, 4.0]]).unwrap();
+
+        let ascii = FormatOptions {
+            border: Border::Ascii,
+            ..Default::default()
+        };
+        assert_eq!(
+            "+------+\n| 1 20 |\n| 3  4 |\n+------+\n",
+            matrix.formatted(ascii).to_string()
+        );
+
+        let unicode = FormatOptions {
+            precision: Some(1),
+            border: Border::Unicode,
+            ..Default::default()
+        };
+        assert_eq!(
+            "┌          ┐\n│ 1.0 20.0 │\n│ 3.0  4.0 │\n└          ┘\n",
+            matrix.formatted(unicode).to_string()
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{
+    functions::{self, Data},
+    Matrix, MatrixError,
+};
+use std::f64::consts::PI;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ComplexMatrix {
+    real: Matrix,
+    imaginary: Matrix,
+}
+
+impl ComplexMatrix {
+    pub fn new(real: Matrix, imaginary: Matrix) -> Result<Self, MatrixError> {
+        if real.shape() != imaginary.shape() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: real.shape(),
+                actual: imaginary.shape(),
+            });
+        }
+
+        Ok(Self { real, imaginary })
+    }
+
+    pub fn real(&self) -> &Matrix {
+        &self.real
+    }
+
+    pub fn imaginary(&self) -> &Matrix {
+        &self.imaginary
+    }
+
+    pub fn rows(&self) -> usize {
+        self.real.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.real.columns
+    }
+}
+
+// The unrounded real and imaginary parts of the n × n DFT matrix, with entry (j, k) = e^(-2πijk/n)
+fn twiddle_factors(n: usize) -> (Data, Data) {
+    let mut real = vec![vec![0.0; n]; n];
+    let mut imaginary = vec![vec![0.0; n]; n];
+    for j in 0..n {
+        for k in 0..n {
+            // Reducing jk modulo n keeps the angle small, so large sizes don't lose precision
+            let angle = -2.0 * PI * ((j * k) % n) as f64 / n as f64;
+            real[j][k] = angle.cos();
+            imaginary[j][k] = angle.sin();
+        }
+    }
+
+    (real, imaginary)
+}
+
+impl Matrix {
+    pub fn dft(n: usize) -> Result<ComplexMatrix, MatrixError> {
+        if let 0 = n {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        let (real, imaginary) = twiddle_factors(n);
+        Ok(ComplexMatrix {
+            real: functions::rounded(real),
+            imaginary: functions::rounded(imaginary),
+        })
+    }
+
+    // Multiplies by the unrounded DFT matrix and rounds only the result, since rounding every twiddle
+    // factor first would add an error of up to 1e-5 per term
+    pub fn fourier_transform(&self) -> ComplexMatrix {
+        let (real, imaginary) = twiddle_factors(self.rows);
+        let signals = self.data.to_vec();
+
+        ComplexMatrix {
+            real: functions::rounded(functions::product(&real, &signals)),
+            imaginary: functions::rounded(functions::product(&imaginary, &signals)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn dft() {
+        assert_eq!(MatrixError::ZeroDimension, Matrix::dft(0).unwrap_err());
+
+        let dft_2 = Matrix::dft(2).unwrap();
+        assert_eq!(
+            &Matrix::new_with_data(vec![vec![1.0, 1.0], vec![1.0, -1.0]]).unwrap(),
+            dft_2.real()
+        );
+        assert!(dft_2.imaginary().is_zero());
+
+        let dft_4 = Matrix::dft(4).unwrap();
+        assert_eq!(
+            &Matrix::new_with_data(vec![
+                vec![1.0, 1.0, 1.0, 1.0],
+                vec![1.0, 0.0, -1.0, 0.0],
+                vec![1.0, -1.0, 1.0, -1.0],
+                vec![1.0, 0.0, -1.0, 0.0],
+            ])
+            .unwrap(),
+            dft_4.real()
+        );
+        assert_eq!(
+            &Matrix::new_with_data(vec![
+                vec![0.0, 0.0, 0.0, 0.0],
+                vec![0.0, -1.0, 0.0, 1.0],
+                vec![0.0, 0.0, 0.0, 0.0],
+                vec![0.0, 1.0, 0.0, -1.0],
+            ])
+            .unwrap(),
+            dft_4.imaginary()
+        );
+    }
+
+    #[test]
+    fn fourier_transform() {
+        let signals = Matrix::new_with_data(vec![
+            vec![1.0, 1.0],
+            vec![0.0, 1.0],
+            vec![0.0, 1.0],
+            vec![0.0, 1.0],
+        ])
+        .unwrap();
+        let transform = signals.fourier_transform();
+
+        assert_eq!(
+            &Matrix::new_with_data(vec![
+                vec![1.0, 4.0],
+                vec![1.0, 0.0],
+                vec![1.0, 0.0],
+                vec![1.0, 0.0],
+            ])
+            .unwrap(),
+            transform.real()
+        );
+        assert!(transform.imaginary().is_zero());
+
+        // sin(-2π/3) rounds to -0.86603, which would be off by 0.46 once scaled by 10⁵
+        let impulse = Matrix::new_with_data(vec![vec![0.0], vec![100000.0], vec![0.0]]).unwrap();
+        assert_eq!(
+            &Matrix::new_with_data(vec![vec![0.0], vec![-86602.54038], vec![86602.54038]]).unwrap(),
+            impulse.fourier_transform().imaginary()
+        );
+    }
+
+    #[test]
+    fn complex_matrix() {
+        let [matrix_2x3, another_matrix_2x3, matrix_4x2] = test_utils::generic_examples();
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 3),
+                actual: (4, 2)
+            },
+            ComplexMatrix::new(matrix_2x3.clone(), matrix_4x2).unwrap_err()
+        );
+
+        let complex = ComplexMatrix::new(matrix_2x3.clone(), another_matrix_2x3.clone()).unwrap();
+        assert_eq!((2, 3), (complex.rows(), complex.columns()));
+        assert_eq!(&matrix_2x3, complex.real());
+        assert_eq!(&another_matrix_2x3, complex.imaginary());
+    }
+}
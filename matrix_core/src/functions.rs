@@ -0,0 +1,426 @@
+use crate::{
+    trace::{self, Span},
+    Matrix, MatrixError,
+};
+
+// Iterations of the Denman–Beavers square root iteration, which converges quadratically once it gets close
+const MAX_ITERATIONS: usize = 100;
+
+// Taylor terms per step of `expm_multiply`. With the step scaled to a 1-norm of at most 1 the terms
+// fall below rounding error well before this
+const MAX_TAYLOR_TERMS: usize = 40;
+
+pub(crate) type Data = Vec<Vec<f64>>;
+
+impl Matrix {
+    // Negative exponents raise the inverse to the corresponding positive power
+    pub fn power(&self, exponent: i32) -> Result<Self, MatrixError> {
+        // Up to two products for each bit of the exponent
+        let products = 2 * (u32::BITS - exponent.unsigned_abs().leading_zeros()) as u64;
+        let _span = Span::new(
+            "power",
+            &[self.shape()],
+            products * 2 * trace::cube(self.rows),
+        );
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        let mut base = if exponent < 0 {
+            self.inverse()?.take_data()
+        } else {
+            self.data.to_vec()
+        };
+        let mut result = Matrix::nth_identity(self.rows).unwrap().take_data();
+
+        // Binary exponentiation needs only about log2(exponent) products
+        let mut remaining = exponent.unsigned_abs();
+        while remaining > 0 {
+            if remaining % 2 == 1 {
+                result = product(&result, &base);
+            }
+            remaining /= 2;
+            if remaining > 0 {
+                base = product(&base, &base);
+            }
+        }
+
+        Ok(rounded(result))
+    }
+
+    pub fn exponential(&self) -> Result<Self, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        // Scaling and squaring: exp(A) = exp(A / 2^s)^(2^s), with the norm of A / 2^s small
+        // enough for the Taylor series to converge within a few terms
+        let norm = self
+            .data
+            .iter()
+            .map(|row| row.iter().map(|num| num.abs()).sum::<f64>())
+            .fold(0.0, f64::max);
+        let squarings = if norm > 0.5 {
+            (norm / 0.5).log2().ceil() as i32
+        } else {
+            0
+        };
+        let products = 20 + squarings as u64;
+        let _span = Span::new(
+            "exponential",
+            &[self.shape()],
+            products * 2 * trace::cube(self.rows),
+        );
+        let scale = 0.5_f64.powi(squarings);
+        let scaled: Data = self
+            .data
+            .iter()
+            .map(|row| row.iter().map(|num| num * scale).collect())
+            .collect();
+
+        let mut result = Matrix::nth_identity(self.rows).unwrap().take_data();
+        let mut term = result.clone();
+        for k in 1..=20 {
+            term = product(&term, &scaled)
+                .into_iter()
+                .map(|row| row.into_iter().map(|num| num / k as f64).collect())
+                .collect();
+            result = sum(&result, &term, 1.0);
+        }
+
+        for _ in 0..squarings {
+            result = product(&result, &result);
+        }
+
+        if result.iter().flatten().any(|num| !num.is_finite()) {
+            return Err(MatrixError::InvalidArgument);
+        }
+        Ok(rounded(result))
+    }
+
+    // exp(tA)·V for a matrix V with as many rows as A, from the action of A on V alone, so each
+    // step costs n² per column of V instead of the n³ of forming exp(tA). The interval is split into
+    // steps short enough for ‖hA‖₁ ≤ 1, and the Taylor series of each step is cut off once its
+    // terms no longer change the result
+    pub fn expm_multiply(&self, v: &Matrix, t: f64) -> Result<Self, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+        if v.rows != self.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.columns, v.columns),
+                actual: v.shape(),
+            });
+        }
+        if !t.is_finite() {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        let steps = (t.abs() * self.unrounded_norm(crate::Norm::One))
+            .ceil()
+            .max(1.0);
+        if steps > u32::MAX as f64 {
+            return Err(MatrixError::InvalidArgument);
+        }
+        let h = t / steps;
+        let largest = |data: &Data| {
+            data.iter()
+                .flatten()
+                .fold(0.0, |max: f64, num| max.max(num.abs()))
+        };
+
+        let mut result = v.data.to_vec();
+        for _ in 0..steps as u32 {
+            let mut term = result.clone();
+            for k in 1..=MAX_TAYLOR_TERMS {
+                term = product(&self.data, &term)
+                    .into_iter()
+                    .map(|row| row.into_iter().map(|num| num * h / k as f64).collect())
+                    .collect();
+                result = sum(&result, &term, 1.0);
+                if largest(&term) <= f64::EPSILON * largest(&result) {
+                    break;
+                }
+            }
+        }
+
+        if result.iter().flatten().any(|num| !num.is_finite()) {
+            return Err(MatrixError::InvalidArgument);
+        }
+        Ok(rounded(result))
+    }
+
+    // The principal square root, whose eigenvalues all have non-negative real parts
+    pub fn square_root(&self) -> Result<Self, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        if self.is_symmetric() {
+            let (eigenvalues, eigenvectors) = self.symmetric_eigen_decomposition();
+            let tolerance = f64::EPSILON * self.rows as f64 * eigenvalues[0].abs().max(1.0);
+            if eigenvalues[eigenvalues.len() - 1] < -tolerance {
+                return Err(MatrixError::NotPositiveSemidefinite);
+            }
+
+            let roots: Vec<f64> = eigenvalues
+                .iter()
+                .map(|value| value.max(0.0).sqrt())
+                .collect();
+            let scaled: Data = eigenvectors
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(&roots)
+                        .map(|(num, root)| num * root)
+                        .collect()
+                })
+                .collect();
+            return Ok(rounded(product(&scaled, &transpose(&eigenvectors))));
+        }
+
+        // Y converges to √A and Z to its inverse. Matrices with eigenvalues on the negative real
+        // axis, and singular ones, have no real principal square root and make it break down
+        let mut y = self.data.to_vec();
+        let mut z = Matrix::nth_identity(self.rows).unwrap().take_data();
+        for _ in 0..MAX_ITERATIONS {
+            let (Some(y_inverse), Some(z_inverse)) = (inverse(&y), inverse(&z)) else {
+                return Err(MatrixError::InvalidArgument);
+            };
+            let next_y = scaled_sum(&y, &z_inverse);
+            let next_z = scaled_sum(&z, &y_inverse);
+
+            let change = frobenius(&sum(&next_y, &y, -1.0));
+            y = next_y;
+            z = next_z;
+            if change <= 1e-14 * frobenius(&y) {
+                return Ok(rounded(y));
+            }
+        }
+
+        Err(MatrixError::InvalidArgument)
+    }
+}
+
+// The helpers below work on unrounded data so that errors do not build up between steps
+pub(crate) fn product(a: &Data, b: &Data) -> Data {
+    a.iter()
+        .map(|row| {
+            (0..b[0].len())
+                .map(|j| crate::compensated_sum(row.iter().zip(b).map(|(x, b_row)| x * b_row[j])))
+                .collect()
+        })
+        .collect()
+}
+
+pub(crate) fn sum(a: &Data, b: &Data, factor: f64) -> Data {
+    a.iter()
+        .zip(b)
+        .map(|(a_row, b_row)| {
+            a_row
+                .iter()
+                .zip(b_row)
+                .map(|(x, y)| x + factor * y)
+                .collect()
+        })
+        .collect()
+}
+
+fn scaled_sum(a: &Data, b: &Data) -> Data {
+    sum(a, b, 1.0)
+        .into_iter()
+        .map(|row| row.into_iter().map(|num| num / 2.0).collect())
+        .collect()
+}
+
+fn transpose(a: &Data) -> Data {
+    (0..a[0].len())
+        .map(|j| a.iter().map(|row| row[j]).collect())
+        .collect()
+}
+
+fn frobenius(a: &Data) -> f64 {
+    a.iter().flatten().map(|num| num * num).sum::<f64>().sqrt()
+}
+
+// Gauss-Jordan elimination with partial pivoting, None when the matrix is numerically singular
+pub(crate) fn inverse(a: &Data) -> Option<Data> {
+    let n = a.len();
+    let scale = frobenius(a);
+    let mut left = a.clone();
+    let mut right = Matrix::nth_identity(n).unwrap().take_data();
+
+    for column in 0..n {
+        let pivot_row = (column..n)
+            .max_by(|i, j| left[*i][column].abs().total_cmp(&left[*j][column].abs()))
+            .unwrap();
+        if left[pivot_row][column].abs() <= f64::EPSILON * scale {
+            return None;
+        }
+        left.swap(column, pivot_row);
+        right.swap(column, pivot_row);
+
+        let pivot = left[column][column];
+        for k in 0..n {
+            left[column][k] /= pivot;
+            right[column][k] /= pivot;
+        }
+
+        for row in 0..n {
+            let factor = left[row][column];
+            if row == column || factor == 0.0 {
+                continue;
+            }
+            for k in 0..n {
+                left[row][k] -= factor * left[column][k];
+                right[row][k] -= factor * right[column][k];
+            }
+        }
+    }
+
+    Some(right)
+}
+
+pub(crate) fn rounded(data: Data) -> Matrix {
+    Matrix::new_with_data(
+        data.into_iter()
+            .map(|row| row.into_iter().map(crate::round_result).collect())
+            .collect(),
+    )
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn power() {
+        let matrix = Matrix::new_with_data(vec![vec![1.0, 1.0], vec![1.0, 0.0]]).unwrap();
+
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![89.0, 55.0], vec![55.0, 34.0]]).unwrap(),
+            matrix.power(10).unwrap()
+        );
+        assert_eq!(Matrix::nth_identity(2).unwrap(), matrix.power(0).unwrap());
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![2.0, -3.0], vec![-3.0, 5.0]]).unwrap(),
+            matrix.power(-4).unwrap()
+        );
+
+        let singular = Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert_eq!(MatrixError::SingularMatrix, singular.power(-1).unwrap_err());
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[2].power(2).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn exponential() {
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![7.38906, 0.0], vec![0.0, 0.36788]]).unwrap(),
+            Matrix::new_diagonal_matrix(&[2.0, -1.0])
+                .unwrap()
+                .exponential()
+                .unwrap()
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1.0, 1.0], vec![0.0, 1.0]]).unwrap(),
+            Matrix::new_with_data(vec![vec![0.0, 1.0], vec![0.0, 0.0]])
+                .unwrap()
+                .exponential()
+                .unwrap()
+        );
+
+        // A rotation generator exponentiates to a rotation, here by 10 radians
+        let rotation = Matrix::new_with_data(vec![vec![0.0, -10.0], vec![10.0, 0.0]])
+            .unwrap()
+            .exponential()
+            .unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![-0.83907, 0.54402], vec![-0.54402, -0.83907]]).unwrap(),
+            rotation
+        );
+    }
+
+    #[test]
+    fn expm_multiply() {
+        let [_, _, matrix_3x3, matrix_5x5] = test_utils::square_examples();
+        for matrix in [matrix_3x3, matrix_5x5] {
+            let v = Matrix::new_with_data(
+                (0..matrix.rows)
+                    .map(|i| vec![1.0, i as f64 - 1.5])
+                    .collect(),
+            )
+            .unwrap();
+            let expected = (&(0.1 * &matrix).exponential().unwrap() * &v).unwrap();
+            let actual = matrix.expm_multiply(&v, 0.1).unwrap();
+            assert!(actual.distance(&expected, crate::Norm::Infinity).unwrap() < 1e-4);
+        }
+
+        // A rotation generator turns the vector through t radians, here over many steps
+        let generator = Matrix::new_with_data(vec![vec![0.0, -1.0], vec![1.0, 0.0]]).unwrap();
+        let x = Matrix::new_with_data(vec![vec![1.0], vec![0.0]]).unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![-0.83907], vec![-0.54402]]).unwrap(),
+            generator.expm_multiply(&x, 10.0).unwrap()
+        );
+        assert_eq!(x, generator.expm_multiply(&x, 0.0).unwrap());
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 2),
+                actual: (4, 2)
+            },
+            generator
+                .expm_multiply(&test_utils::generic_examples()[2], 1.0)
+                .unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            generator.expm_multiply(&x, f64::NAN).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn square_root() {
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![2.0, 1.0], vec![1.0, 2.0]]).unwrap(),
+            Matrix::new_with_data(vec![vec![5.0, 4.0], vec![4.0, 5.0]])
+                .unwrap()
+                .square_root()
+                .unwrap()
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1.0, 1.0], vec![0.0, 2.0]]).unwrap(),
+            Matrix::new_with_data(vec![vec![1.0, 3.0], vec![0.0, 4.0]])
+                .unwrap()
+                .square_root()
+                .unwrap()
+        );
+        assert_eq!(
+            Matrix::new_zero_matrix(2, 2).unwrap(),
+            Matrix::new_zero_matrix(2, 2)
+                .unwrap()
+                .square_root()
+                .unwrap()
+        );
+
+        assert_eq!(
+            MatrixError::NotPositiveSemidefinite,
+            Matrix::new_diagonal_matrix(&[4.0, -1.0])
+                .unwrap()
+                .square_root()
+                .unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            Matrix::new_with_data(vec![vec![-1.0, 1.0], vec![0.0, -4.0]])
+                .unwrap()
+                .square_root()
+                .unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::Matrix;
+use std::hash::{Hash, Hasher};
+
+// 64-bit FNV-1a, written out so that hashes stay the same across Rust versions and platforms,
+// unlike those of `DefaultHasher`
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xCBF2_9CE4_8422_2325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01B3)
+    })
+}
+
+// Equal elements get equal bits: -0.0 becomes 0.0 and every NaN the same quiet NaN
+fn canonical_bits(num: f64) -> u64 {
+    if num.is_nan() {
+        f64::NAN.to_bits()
+    } else if num == 0.0 {
+        0
+    } else {
+        num.to_bits()
+    }
+}
+
+impl Matrix {
+    // The rows and columns as little-endian u64s followed by the bits of the elements in row-major
+    // order, so that matrices that compare equal have the same bytes
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8 * (2 + self.rows * self.columns));
+        bytes.extend((self.rows as u64).to_le_bytes());
+        bytes.extend((self.columns as u64).to_le_bytes());
+        for num in self.data.iter().flatten() {
+            bytes.extend(canonical_bits(*num).to_le_bytes());
+        }
+
+        bytes
+    }
+
+    pub fn content_hash(&self) -> u64 {
+        fnv1a(&self.canonical_bytes())
+    }
+}
+
+impl Hash for Matrix {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(&self.canonical_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use std::collections::HashSet;
+
+    #[test]
+    fn fnv1a() {
+        assert_eq!(0xCBF2_9CE4_8422_2325, super::fnv1a(b""));
+        assert_eq!(0xAF63_DC4C_8601_EC8C, super::fnv1a(b"a"));
+    }
+
+    #[test]
+    fn content_hash() {
+        let [matrix_2x3, another_matrix_2x3, matrix_4x2] = test_utils::generic_examples();
+        assert_eq!(matrix_2x3.content_hash(), matrix_2x3.clone().content_hash());
+        assert_ne!(matrix_2x3.content_hash(), another_matrix_2x3.content_hash());
+
+        // The same elements in another shape are another matrix
+        let reshaped = Matrix::new_with_data(
+            matrix_4x2
+                .get_data()
+                .concat()
+                .chunks(4)
+                .map(<[f64]>::to_vec)
+                .collect(),
+        )
+        .unwrap();
+        assert_ne!(matrix_4x2.content_hash(), reshaped.content_hash());
+
+        let zero = Matrix::new_with_data(vec![vec![0.0, f64::NAN]]).unwrap();
+        let negative_zero = Matrix::new_with_data(vec![vec![-0.0, -f64::NAN]]).unwrap();
+        assert_eq!(zero.content_hash(), negative_zero.content_hash());
+        assert_eq!(
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0],
+            zero.canonical_bytes()[..16]
+        );
+
+        let hashes: HashSet<u64> = test_utils::square_examples()
+            .iter()
+            .map(Matrix::content_hash)
+            .collect();
+        assert_eq!(4, hashes.len());
+    }
+}
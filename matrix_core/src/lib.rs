@@ -0,0 +1,1355 @@
+use std::{
+    borrow::Cow,
+    fmt::{self, Display, Formatter},
+    ops::{Deref, DerefMut},
+    sync::{Arc, OnceLock},
+};
+
+// Places beyond what an f64 can resolve leave the number as it is
+fn round_to(num: f64, decimals: u32) -> f64 {
+    let scale = 10f64.powi(decimals.min(400) as i32);
+    let scaled = num * scale;
+    if !scaled.is_finite() {
+        return num;
+    }
+
+    scaled.round() / scale
+}
+
+// Every result is rounded on its way out, to five decimal places unless `with_precision` says
+// otherwise
+fn round_result(num: f64) -> f64 {
+    precision::round(num)
+}
+
+// Neumaier's variant of Kahan summation, which also stays exact when a term outweighs the running sum
+fn compensated_sum(values: impl IntoIterator<Item = f64>) -> f64 {
+    let mut sum = 0.0;
+    let mut compensation = 0.0;
+    for value in values {
+        let total = sum + value;
+        if f64::abs(sum) >= value.abs() {
+            compensation += (sum - total) + value;
+        } else {
+            compensation += (value - total) + sum;
+        }
+        sum = total;
+    }
+
+    sum + compensation
+}
+
+fn check_index(index: usize, len: usize) -> Result<(), MatrixError> {
+    if index >= len {
+        return Err(MatrixError::IndexOutOfBounds { index, len });
+    }
+
+    Ok(())
+}
+
+mod broadcast;
+mod builder;
+mod chain;
+mod csv;
+mod eigen;
+#[cfg(feature = "ffi")]
+mod ffi;
+mod format;
+mod fourier;
+mod functions;
+mod hash;
+mod lu;
+mod markov;
+mod matlab;
+mod modular;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+mod norms;
+mod npy;
+mod operations;
+mod packed;
+mod precision;
+mod properties;
+#[cfg(feature = "python")]
+mod python;
+mod random;
+#[cfg(feature = "serde")]
+mod serialization;
+mod settings;
+mod slicing;
+mod solver;
+mod sorting;
+mod sparse;
+mod special_matrices;
+mod special_operations;
+pub mod stats;
+mod svd;
+mod trace;
+mod transforms;
+pub mod verify;
+mod view;
+#[cfg(feature = "wasm-bindgen")]
+mod wasm;
+
+pub use builder::MatrixBuilder;
+pub use chain::ChainProduct;
+pub use csv::{CsvError, CsvOptions, Delimiter};
+pub use eigen::SymmetricEigen;
+#[cfg(feature = "ffi")]
+pub use ffi::MatrixStatus;
+pub use format::{Border, FormatOptions, FormattedMatrix, Notation};
+pub use fourier::ComplexMatrix;
+pub use lu::{LuDecomposition, PivotStrategy};
+pub use markov::AbsorbingChain;
+pub use matlab::ParseMatlabError;
+pub use modular::ModularMatrix;
+pub use norms::Norm;
+pub use npy::NpyError;
+pub use packed::{SymmetricMatrix, Triangle, TriangularMatrix};
+pub use precision::{current_precision, with_precision, Precision};
+pub use properties::{FormattedReport, Property, PropertyReport, PropertyValue};
+#[cfg(feature = "python")]
+pub use python::PyMatrix;
+pub use random::Distribution;
+#[cfg(feature = "serde")]
+pub use serialization::MatrixRecord;
+pub use settings::{current_settings, with_settings, Settings};
+pub use slicing::PaddingMode;
+pub use solver::LinearSystemSolution;
+pub use sorting::SortOrder;
+pub use sparse::SparseMatrix;
+pub use special_operations::{Equilibration, PivotedCholesky};
+pub use stats::OperationStats;
+#[cfg(feature = "trace")]
+pub use trace::OperationRecord;
+pub use verify::ResidualReport;
+pub use view::MatrixView;
+#[cfg(feature = "wasm-bindgen")]
+pub use wasm::WasmMatrix;
+
+pub mod prelude {
+    pub use crate::{
+        AbsorbingChain, Border, ChainProduct, ComplexMatrix, CsvError, CsvOptions, Delimiter,
+        Distribution, Equilibration, FormatOptions, LinearSystemSolution, LuDecomposition, Matrix,
+        MatrixBuilder, MatrixError, MatrixView, ModularMatrix, Norm, Notation, NpyError,
+        OperationStats, PaddingMode, ParseMatlabError, PivotStrategy, PivotedCholesky, Precision,
+        Property, PropertyReport, PropertyValue, ResidualReport, Settings, SortOrder,
+        SymmetricEigen, SymmetricMatrix, Triangle, TriangularMatrix,
+    };
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Matrix {
+    rows: usize,
+    columns: usize,
+    data: Rows,
+}
+
+// The elements behind a shared pointer, so that cloning a matrix is O(1) and the elements are only
+// copied when a matrix that shares them is first modified
+#[derive(Clone)]
+struct Rows {
+    elements: Arc<Vec<Vec<f64>>>,
+    cache: Arc<Cache>,
+}
+
+// Results computed from the elements, shared by the clones that share the elements and dropped as
+// soon as the elements are modified through any method
+#[derive(Default)]
+struct Cache {
+    lu: OnceLock<Result<LuDecomposition, MatrixError>>,
+    determinant: OnceLock<Result<f64, MatrixError>>,
+    trace: OnceLock<Result<f64, MatrixError>>,
+    rank: OnceLock<usize>,
+}
+
+impl Rows {
+    fn new(elements: Vec<Vec<f64>>) -> Self {
+        Rows {
+            elements: Arc::new(elements),
+            cache: Arc::default(),
+        }
+    }
+
+    // A result from the cache, computed on first use. Results computed under other settings would be
+    // stale once they end, so those are computed afresh every time
+    fn cached<T: Clone>(
+        &self,
+        entry: impl FnOnce(&Cache) -> &OnceLock<T>,
+        compute: impl FnOnce() -> T,
+    ) -> Cow<'_, T> {
+        if current_settings() != Settings::default() {
+            return Cow::Owned(compute());
+        }
+
+        Cow::Borrowed(entry(&self.cache).get_or_init(compute))
+    }
+}
+
+impl Deref for Rows {
+    type Target = Vec<Vec<f64>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.elements
+    }
+}
+
+impl DerefMut for Rows {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Resetting a cache nothing else shares reuses its allocation, which matters for the loops
+        // that modify one element at a time
+        match Arc::get_mut(&mut self.cache) {
+            Some(cache) => *cache = Cache::default(),
+            None => self.cache = Arc::default(),
+        }
+
+        Arc::make_mut(&mut self.elements)
+    }
+}
+
+impl PartialEq for Rows {
+    fn eq(&self, other: &Self) -> bool {
+        self.elements == other.elements
+    }
+}
+
+impl fmt::Debug for Rows {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.elements.fmt(f)
+    }
+}
+
+impl From<Vec<Vec<f64>>> for Rows {
+    fn from(data: Vec<Vec<f64>>) -> Self {
+        Rows::new(data)
+    }
+}
+
+impl FromIterator<Vec<f64>> for Rows {
+    fn from_iter<I: IntoIterator<Item = Vec<f64>>>(iter: I) -> Self {
+        Rows::new(iter.into_iter().collect())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[non_exhaustive]
+pub enum MatrixError {
+    ZeroDimension,
+    InconsistentColumnSize,
+    // The (rows, columns) an operand should have had to fit, next to the ones it has
+    DimensionMismatch {
+        expected: (usize, usize),
+        actual: (usize, usize),
+    },
+    NonSquareMatrix,
+    NonSymmetricMatrix,
+    SingularMatrix,
+    // A row, column or position counted from 0, and the length it should be below
+    IndexOutOfBounds {
+        index: usize,
+        len: usize,
+    },
+    NotPositiveSemidefinite,
+    NotPositiveDefinite,
+    InvalidArgument,
+}
+
+fn count(num: usize, noun: &str) -> String {
+    match num {
+        1 => format!("1 {noun}"),
+        _ => format!("{num} {noun}s"),
+    }
+}
+
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            // Only the dimension that differs is mentioned, as in `expected 3 columns, got 2`
+            MatrixError::DimensionMismatch { expected, actual } => {
+                return if expected.0 == actual.0 {
+                    write!(
+                        f,
+                        "expected {}, got {}",
+                        count(expected.1, "column"),
+                        actual.1
+                    )
+                } else if expected.1 == actual.1 {
+                    write!(f, "expected {}, got {}", count(expected.0, "row"), actual.0)
+                } else {
+                    write!(
+                        f,
+                        "expected a {}×{} matrix, got {}×{}",
+                        expected.0, expected.1, actual.0, actual.1
+                    )
+                };
+            }
+            MatrixError::IndexOutOfBounds { index, len } => {
+                return write!(f, "index {index} is out of bounds for length {len}");
+            }
+            MatrixError::ZeroDimension => "matrix dimensions must be greater than zero",
+            MatrixError::InconsistentColumnSize => {
+                "every row must have the same number of elements"
+            }
+            MatrixError::NonSquareMatrix => "matrix is not square",
+            MatrixError::NonSymmetricMatrix => "matrix is not symmetric",
+            MatrixError::SingularMatrix => "matrix is singular",
+            MatrixError::NotPositiveSemidefinite => "matrix is not positive semidefinite",
+            MatrixError::NotPositiveDefinite => "matrix is not positive definite",
+            MatrixError::InvalidArgument => "invalid argument",
+        };
+
+        write!(f, "{message}")
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
+impl IntoIterator for Matrix {
+    type Item = Vec<f64>;
+    type IntoIter = std::vec::IntoIter<Vec<f64>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_rows()
+    }
+}
+
+impl Display for Matrix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.formatted(FormatOptions::default()))
+    }
+}
+
+impl Matrix {
+    pub fn new_zero_matrix(rows: usize, columns: usize) -> Result<Self, MatrixError> {
+        if rows == 0 || columns == 0 {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        Ok(Self {
+            rows,
+            columns,
+            data: vec![vec![0.0; columns]; rows].into(),
+        })
+    }
+
+    // Like every other constructor, rejects data without rows or columns instead of making a 0×0 or
+    // n×0 matrix
+    fn validate_data(data: &[Vec<f64>]) -> Result<(), MatrixError> {
+        let Some(first_row) = data.first() else {
+            return Err(MatrixError::ZeroDimension);
+        };
+
+        if data.iter().any(|row| first_row.len() != row.len()) {
+            return Err(MatrixError::InconsistentColumnSize);
+        };
+
+        if first_row.is_empty() {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        Ok(())
+    }
+
+    pub fn new_with_data(data: Vec<Vec<f64>>) -> Result<Self, MatrixError> {
+        Self::validate_data(&data)?;
+
+        Ok(Self {
+            rows: data.len(),
+            columns: data[0].len(),
+            data: data.into(),
+        })
+    }
+
+    pub fn new_diagonal_matrix(diagonal_elements: &[f64]) -> Result<Self, MatrixError> {
+        if diagonal_elements.is_empty() {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        let mut diagonal_matrix =
+            Matrix::new_zero_matrix(diagonal_elements.len(), diagonal_elements.len()).unwrap();
+
+        diagonal_elements
+            .iter()
+            .enumerate()
+            .for_each(|(i, num)| diagonal_matrix.data[i][i] = *num);
+
+        Ok(diagonal_matrix)
+    }
+
+    pub fn new_scalar_matrix(scalar: f64, size: usize) -> Result<Self, MatrixError> {
+        if let 0 = size {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        Ok(Self::new_diagonal_matrix(&vec![scalar; size]).unwrap())
+    }
+
+    pub fn nth_identity(n: usize) -> Result<Self, MatrixError> {
+        if let 0 = n {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        Ok(Self::new_scalar_matrix(1.0, n).unwrap())
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn get_data(&self) -> Vec<Vec<f64>> {
+        self.data.to_vec()
+    }
+
+    // Takes the elements without copying them unless another matrix still shares them
+    pub fn take_data(self) -> Vec<Vec<f64>> {
+        Arc::unwrap_or_clone(self.data.elements)
+    }
+
+    // The elements in row-major order
+    pub fn take_flat_data(self) -> Vec<f64> {
+        self.take_data().into_iter().flatten().collect()
+    }
+
+    pub fn into_rows(self) -> std::vec::IntoIter<Vec<f64>> {
+        self.take_data().into_iter()
+    }
+
+    pub(crate) fn shape(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
+
+    pub fn get_row(&self, row: usize) -> Result<Vec<f64>, MatrixError> {
+        check_index(row, self.rows)?;
+
+        Ok(self.data[row].clone())
+    }
+
+    pub fn get_column(&self, column: usize) -> Result<Vec<f64>, MatrixError> {
+        check_index(column, self.columns)?;
+
+        Ok(self.data.iter().map(|row| row[column]).collect())
+    }
+
+    pub fn get_element(&self, row: usize, column: usize) -> Result<f64, MatrixError> {
+        check_index(row, self.rows)?;
+        check_index(column, self.columns)?;
+
+        Ok(self.data[row][column])
+    }
+
+    pub fn set_data(&mut self, data: Vec<Vec<f64>>) -> Result<(), MatrixError> {
+        Self::validate_data(&data)?;
+
+        if data.len() != self.rows || data[0].len() != self.columns {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.shape(),
+                actual: (data.len(), data[0].len()),
+            });
+        }
+
+        self.data = data.into();
+        Ok(())
+    }
+
+    pub fn set_row(&mut self, row: usize, data: Vec<f64>) -> Result<(), MatrixError> {
+        self.replace_row(row, data)?;
+        Ok(())
+    }
+
+    // Like `set_row`, but hands back the row that was replaced instead of dropping it
+    pub fn replace_row(&mut self, row: usize, data: Vec<f64>) -> Result<Vec<f64>, MatrixError> {
+        check_index(row, self.rows)?;
+
+        if data.len() != self.columns {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (1, self.columns),
+                actual: (1, data.len()),
+            });
+        }
+
+        Ok(std::mem::replace(&mut self.data[row], data))
+    }
+
+    pub fn set_column(&mut self, column: usize, data: Vec<f64>) -> Result<(), MatrixError> {
+        check_index(column, self.columns)?;
+
+        if data.len() != self.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.rows, 1),
+                actual: (data.len(), 1),
+            });
+        }
+
+        data.iter()
+            .enumerate()
+            .for_each(|(row, num)| self.data[row][column] = *num);
+        Ok(())
+    }
+
+    pub fn set_element(&mut self, row: usize, column: usize, data: f64) -> Result<(), MatrixError> {
+        check_index(row, self.rows)?;
+        check_index(column, self.columns)?;
+
+        self.data[row][column] = data;
+        Ok(())
+    }
+
+    // Each row as a slice that can be updated in place, so it can't change length
+    pub fn iter_rows_mut(&mut self) -> impl Iterator<Item = &mut [f64]> {
+        self.data.iter_mut().map(Vec::as_mut_slice)
+    }
+
+    // The elements of a column from top to bottom, checking the index once up front
+    pub fn column_iter_mut(
+        &mut self,
+        column: usize,
+    ) -> Result<impl Iterator<Item = &mut f64>, MatrixError> {
+        check_index(column, self.columns)?;
+
+        Ok(self.data.iter_mut().map(move |row| &mut row[column]))
+    }
+
+    /// Reads an element without checking the indices, for inner loops that have already checked
+    /// them once against `rows()` and `columns()`.
+    ///
+    /// # Safety
+    ///
+    /// `row` must be less than `rows()` and `column` less than `columns()`. Debug builds assert this.
+    pub unsafe fn get_unchecked(&self, row: usize, column: usize) -> f64 {
+        debug_assert!(row < self.rows && column < self.columns);
+        *self.data.get_unchecked(row).get_unchecked(column)
+    }
+
+    /// Writes an element without checking the indices.
+    ///
+    /// # Safety
+    ///
+    /// `row` must be less than `rows()` and `column` less than `columns()`. Debug builds assert this.
+    pub unsafe fn set_unchecked(&mut self, row: usize, column: usize, data: f64) {
+        debug_assert!(row < self.rows && column < self.columns);
+        *self.data.get_unchecked_mut(row).get_unchecked_mut(column) = data;
+    }
+
+    // Applies `function` to every element, failing when it gives a value that is not a finite number
+    pub fn map<F>(&self, function: F) -> Result<Self, MatrixError>
+    where
+        F: Fn(f64) -> f64 + Sync,
+    {
+        let data = self.rows_from(|row| self.data[row].iter().map(|num| function(*num)).collect());
+
+        if data.iter().flatten().any(|num| !num.is_finite()) {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        Ok(Self::new_with_data(
+            data.into_iter()
+                .map(|row| row.into_iter().map(round_result).collect())
+                .collect(),
+        )
+        .unwrap())
+    }
+
+    // A mask has the shape of the matrix it selects from, with 1 where an element is selected and 0
+    // elsewhere
+    fn check_mask(&self, mask: &Matrix) -> Result<(), MatrixError> {
+        if mask.shape() != self.shape() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.shape(),
+                actual: mask.shape(),
+            });
+        }
+
+        match mask
+            .data
+            .iter()
+            .flatten()
+            .all(|num| *num == 0.0 || *num == 1.0)
+        {
+            true => Ok(()),
+            false => Err(MatrixError::InvalidArgument),
+        }
+    }
+
+    // Sets every element where `mask` is 1 to `value`, leaving the rest as they are
+    pub fn set_where(&mut self, mask: &Matrix, value: f64) -> Result<(), MatrixError> {
+        self.update_where(mask, |_| value)
+    }
+
+    // Applies `function` to the elements where `mask` is 1, like `map`. Nothing is changed when it
+    // gives a value that is not a finite number
+    pub fn update_where<F>(&mut self, mask: &Matrix, function: F) -> Result<(), MatrixError>
+    where
+        F: Fn(f64) -> f64 + Sync,
+    {
+        self.check_mask(mask)?;
+
+        let data = self.rows_from(|row| {
+            self.data[row]
+                .iter()
+                .zip(mask.data[row].iter())
+                .map(|(num, selected)| match *selected == 1.0 {
+                    true => round_result(function(*num)),
+                    false => *num,
+                })
+                .collect()
+        });
+
+        if data.iter().flatten().any(|num| !num.is_finite()) {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        self.data = data.into();
+        Ok(())
+    }
+
+    // A + λI in place, as the shift in ridge regression or in shifted eigenvalue iterations,
+    // without building the identity
+    pub fn add_scalar_to_diagonal(&mut self, lambda: f64) -> Result<(), MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        (0..self.rows).for_each(|i| self.data[i][i] = round_result(self.data[i][i] + lambda));
+        Ok(())
+    }
+
+    // Every element rounded to `decimals` places, for cleaning up values before showing them
+    pub fn rounded(&self, decimals: u32) -> Self {
+        Self::new_with_data(self.rows_from(|row| {
+            self.data[row]
+                .iter()
+                .map(|num| round_to(*num, decimals))
+                .collect()
+        }))
+        .unwrap()
+    }
+
+    // Replaces the elements within `epsilon` of zero with 0.0, such as the 1e-17 that cancellation
+    // leaves where the exact result is zero
+    pub fn snap_small_to_zero(&self, epsilon: f64) -> Self {
+        Self::new_with_data(self.rows_from(|row| {
+            self.data[row]
+                .iter()
+                .map(|num| if num.abs() <= epsilon { 0.0 } else { *num })
+                .collect()
+        }))
+        .unwrap()
+    }
+
+    fn element_wise_operation<F>(&self, rhs: &Self, operation: F) -> Result<Self, MatrixError>
+    where
+        F: Fn(f64, f64) -> f64 + Sync,
+    {
+        if self.shape() != rhs.shape() {
+            Err(MatrixError::DimensionMismatch {
+                expected: self.shape(),
+                actual: rhs.shape(),
+            })
+        } else {
+            Ok(Self::new_with_data(self.rows_from(|row| {
+                self.data[row]
+                    .iter()
+                    .zip(rhs.data[row].iter())
+                    .map(|(num1, num2)| round_result(operation(*num1, *num2)))
+                    .collect()
+            }))
+            .unwrap())
+        }
+    }
+
+    // Builds data of the same number of rows one row at a time
+    pub(crate) fn rows_from<F>(&self, row: F) -> Vec<Vec<f64>>
+    where
+        F: Fn(usize) -> Vec<f64> + Sync,
+    {
+        build_vectors(self.rows, self.rows * self.columns, row)
+    }
+}
+
+// Builds `count` vectors from their indices. With the `rayon` feature, work that touches at least
+// `parallel_threshold` elements in total, from the current settings, is spread over the rayon
+// thread pool
+#[cfg_attr(not(feature = "rayon"), allow(unused_variables))]
+fn build_vectors<F>(count: usize, elements: usize, build: F) -> Vec<Vec<f64>>
+where
+    F: Fn(usize) -> Vec<f64> + Sync,
+{
+    #[cfg(feature = "rayon")]
+    if elements >= current_settings().parallel_threshold {
+        use rayon::prelude::*;
+        // The settings live in a thread local, so the worker threads have to be given them
+        let settings = current_settings();
+        return (0..count)
+            .into_par_iter()
+            .map(|index| with_settings(settings, || build(index)))
+            .collect();
+    }
+
+    (0..count).map(build).collect()
+}
+
+// Updates every row in place, in parallel under the same conditions as `build_vectors`
+#[cfg_attr(not(feature = "rayon"), allow(unused_variables))]
+fn update_rows<F>(rows: &mut [Vec<f64>], elements: usize, update: F)
+where
+    F: Fn(&mut Vec<f64>) + Sync,
+{
+    #[cfg(feature = "rayon")]
+    if elements >= current_settings().parallel_threshold {
+        use rayon::prelude::*;
+        let settings = current_settings();
+        return rows
+            .par_iter_mut()
+            .for_each(|row| with_settings(settings, || update(row)));
+    }
+
+    rows.iter_mut().for_each(update)
+}
+
+#[allow(dead_code)]
+mod test_utils {
+    use super::*;
+
+    pub(crate) fn generic_examples() -> [Matrix; 3] {
+        [
+            Matrix::new_with_data(vec![vec![7.2, 13.8, 5.1], vec![9.3, 2.7, 6.4]]).unwrap(),
+            Matrix::new_with_data(vec![vec![1.5, 8.9, 3.2], vec![6.7, 11.3, 4.8]]).unwrap(),
+            Matrix::new_with_data(vec![
+                vec![5.6, 9.8],
+                vec![2.9, 7.4],
+                vec![11.2, 3.1],
+                vec![6.3, 8.7],
+            ])
+            .unwrap(),
+        ]
+    }
+
+    pub(crate) fn square_examples() -> [Matrix; 4] {
+        [
+            Matrix::new_with_data(vec![vec![2.5]]).unwrap(),
+            Matrix::new_with_data(vec![vec![4.5, 2.8], vec![1.3, 6.7]]).unwrap(),
+            Matrix::new_with_data(vec![
+                vec![2.1, 9.7, 3.5],
+                vec![8.4, 1.6, 7.2],
+                vec![5.9, 12.3, 0.8],
+            ])
+            .unwrap(),
+            Matrix::new_with_data(vec![
+                vec![0.0, 7.1, 0.5, 9.3, 2.8],
+                vec![6.4, 1.9, 8.7, 4.2, 5.6],
+                vec![0.3, 9.8, 2.1, 7.5, 3.9],
+                vec![5.7, 3.6, 8.2, 1.4, 6.0],
+                vec![9.1, 4.5, 2.6, 7.8, 0.7],
+            ])
+            .unwrap(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compensated_sum() {
+        assert_eq!(1.0, super::compensated_sum([1e16, 1.0, -1e16]));
+        assert_eq!(
+            1.0 + 1e-15,
+            super::compensated_sum(std::iter::once(1.0).chain(std::iter::repeat_n(1e-16, 10)))
+        );
+        assert_eq!(0.0, super::compensated_sum([]));
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            "matrix is not square",
+            MatrixError::NonSquareMatrix.to_string()
+        );
+        assert_eq!(
+            "expected 3 columns, got 2",
+            MatrixError::DimensionMismatch {
+                expected: (2, 3),
+                actual: (2, 2)
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "expected 1 row, got 4",
+            MatrixError::DimensionMismatch {
+                expected: (1, 2),
+                actual: (4, 2)
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "expected a 2×3 matrix, got 3×2",
+            MatrixError::DimensionMismatch {
+                expected: (2, 3),
+                actual: (3, 2)
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "index 3 is out of bounds for length 2",
+            MatrixError::IndexOutOfBounds { index: 3, len: 2 }.to_string()
+        );
+    }
+
+    #[test]
+    fn new_zero_matrix() {
+        let matrix1 = Matrix::new_zero_matrix(2, 3).unwrap();
+        assert_eq!(
+            Matrix {
+                rows: 2,
+                columns: 3,
+                data: vec![vec![0.0; 3]; 2].into()
+            },
+            matrix1
+        );
+
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_zero_matrix(0, 1).unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_zero_matrix(1, 0).unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_zero_matrix(0, 0).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn new_with_data() {
+        assert_eq!(
+            Matrix {
+                rows: 2,
+                columns: 3,
+                data: vec![vec![4.5, 54.6, 0.0], vec![2.4, 10.4, 1.8]].into()
+            },
+            Matrix::new_with_data(vec![vec![4.5, 54.6, 0.0], vec![2.4, 10.4, 1.8]]).unwrap()
+        );
+
+        assert_eq!(
+            MatrixError::InconsistentColumnSize,
+            Matrix::new_with_data(vec![vec![4.5, 54.6, 0.0], vec![2.4, 10.4]]).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::InconsistentColumnSize,
+            Matrix::new_with_data(vec![vec![], vec![2.4]]).unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_with_data(vec![]).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_with_data(vec![vec![]]).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_with_data(vec![vec![]; 3]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn get_data() {
+        let [example_matrix, _, _] = test_utils::generic_examples();
+
+        assert_eq!(
+            vec![vec![7.2, 13.8, 5.1], vec![9.3, 2.7, 6.4]],
+            example_matrix.get_data()
+        );
+
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 2, len: 2 },
+            example_matrix.get_row(2).unwrap_err()
+        );
+        assert_eq!(vec![9.3, 2.7, 6.4], example_matrix.get_row(1).unwrap());
+
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 3, len: 3 },
+            example_matrix.get_column(3).unwrap_err()
+        );
+        assert_eq!(vec![13.8, 2.7], example_matrix.get_column(1).unwrap());
+
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 2, len: 2 },
+            example_matrix.get_element(2, 1).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 3, len: 3 },
+            example_matrix.get_element(1, 3).unwrap_err()
+        );
+        assert_eq!(2.7, example_matrix.get_element(1, 1).unwrap());
+    }
+
+    #[test]
+    fn map() {
+        let [example_matrix, _, _] = test_utils::generic_examples();
+
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![51.84, 190.44, 26.01], vec![86.49, 7.29, 40.96]])
+                .unwrap(),
+            example_matrix.map(|num| num * num).unwrap()
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![0.33333, -2.0]]).unwrap(),
+            Matrix::new_with_data(vec![vec![3.0, -0.5]])
+                .unwrap()
+                .map(|num| 1.0 / num)
+                .unwrap()
+        );
+
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            Matrix::new_with_data(vec![vec![4.0, -1.0]])
+                .unwrap()
+                .map(f64::sqrt)
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn set_where() {
+        let [mut matrix_2x3, _, _] = test_utils::generic_examples();
+        let mask = Matrix::new_with_data(vec![vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 1.0]]).unwrap();
+
+        matrix_2x3.set_where(&mask, 0.0).unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![0.0, 13.8, 5.1], vec![9.3, 0.0, 0.0]]).unwrap(),
+            matrix_2x3
+        );
+
+        let not_binary = Matrix::new_with_data(vec![vec![1.0, 0.5, 0.0]; 2]).unwrap();
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            matrix_2x3.set_where(&not_binary, 1.0).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 3),
+                actual: (3, 2)
+            },
+            matrix_2x3.set_where(&mask.transpose(), 1.0).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn add_scalar_to_diagonal() {
+        let mut matrix = test_utils::square_examples()[1].clone();
+        let determinant = matrix.determinant().unwrap();
+        let shifted = (&matrix + &(0.1 * &Matrix::nth_identity(2).unwrap())).unwrap();
+
+        matrix.add_scalar_to_diagonal(0.1).unwrap();
+        assert_eq!(shifted, matrix);
+        // The cached determinant belongs to the matrix before the shift
+        assert_ne!(Ok(determinant), matrix.determinant());
+
+        let [mut matrix_2x3, _, _] = test_utils::generic_examples();
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            matrix_2x3.add_scalar_to_diagonal(1.0).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn update_where() {
+        let mut matrix = Matrix::new_with_data(vec![vec![-2.0, 3.0], vec![4.0, -5.0]]).unwrap();
+        let negatives = matrix.map(|num| (num < 0.0) as u8 as f64).unwrap();
+
+        matrix.update_where(&negatives, f64::abs).unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![2.0, 3.0], vec![4.0, 5.0]]).unwrap(),
+            matrix
+        );
+
+        let first_row = Matrix::new_with_data(vec![vec![1.0, 1.0], vec![0.0, 0.0]]).unwrap();
+        matrix.update_where(&first_row, |num| num / 3.0).unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![0.66667, 1.0], vec![4.0, 5.0]]).unwrap(),
+            matrix
+        );
+
+        // A value that isn't finite leaves the matrix unchanged
+        let unchanged = matrix.clone();
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            matrix
+                .update_where(&first_row, |num| num / 0.0)
+                .unwrap_err()
+        );
+        assert_eq!(unchanged, matrix);
+    }
+
+    #[test]
+    fn rounded() {
+        let matrix = Matrix::new_with_data(vec![vec![1.23456789, -0.5, 2.5e-12]]).unwrap();
+
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1.23, -0.5, 0.0]]).unwrap(),
+            matrix.rounded(2)
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1.0, -1.0, 0.0]]).unwrap(),
+            matrix.rounded(0)
+        );
+        assert_eq!(matrix, matrix.rounded(u32::MAX));
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1e300]]).unwrap(),
+            Matrix::new_with_data(vec![vec![1e300]])
+                .unwrap()
+                .rounded(10)
+        );
+    }
+
+    #[test]
+    fn snap_small_to_zero() {
+        let matrix = Matrix::new_with_data(vec![vec![1e-17, -3e-9], vec![1e-3, -1.0]]).unwrap();
+
+        let snapped = matrix.snap_small_to_zero(1e-8);
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![0.0, 0.0], vec![1e-3, -1.0]]).unwrap(),
+            snapped
+        );
+        assert!(snapped.data[0][1].is_sign_positive());
+        assert_eq!(matrix, matrix.snap_small_to_zero(0.0));
+    }
+
+    #[test]
+    fn cache() {
+        let mut matrix = test_utils::square_examples()[2].clone();
+        let determinant = matrix.determinant().unwrap();
+        let rank = matrix.rank();
+        assert!(matrix.data.cache.lu.get().is_some());
+        assert!(!matrix.is_singular());
+
+        // Clones share what was already computed until either side changes
+        let copy = matrix.clone();
+        assert!(Arc::ptr_eq(&matrix.data.cache, &copy.data.cache));
+        assert_eq!(Some(&Ok(determinant)), copy.data.cache.determinant.get());
+
+        matrix.set_row(1, vec![0.0; 3]).unwrap();
+        assert!(matrix.data.cache.determinant.get().is_none());
+        assert!(matrix.data.cache.rank.get().is_none());
+        assert_eq!(0.0, matrix.determinant().unwrap());
+        assert_eq!(rank - 1, matrix.rank());
+        assert!(matrix.is_singular());
+
+        assert_eq!(determinant, copy.determinant().unwrap());
+        assert_eq!(rank, copy.rank());
+    }
+
+    // Large enough to cross the parallel threshold when the `rayon` feature is on
+    #[test]
+    fn large_element_wise_operations() {
+        let matrix = Matrix::new_with_data(
+            (0..300)
+                .map(|i| (0..300).map(|j| (i * 300 + j) as f64).collect())
+                .collect(),
+        )
+        .unwrap();
+
+        let sum = (&matrix + &matrix).unwrap();
+        let squares = matrix.map(|num| num * num).unwrap();
+        let halves = 0.5 * &matrix;
+        for (i, j) in [(0, 0), (150, 7), (299, 299)] {
+            let num = matrix.data[i][j];
+            assert_eq!(2.0 * num, sum.data[i][j]);
+            assert_eq!(num * num, squares.data[i][j]);
+            assert_eq!(num / 2.0, halves.data[i][j]);
+        }
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            (-&matrix).map(f64::ln).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn copy_on_write() {
+        let original = test_utils::generic_examples()[0].clone();
+        let mut copy = original.clone();
+        assert!(Arc::ptr_eq(&original.data.elements, &copy.data.elements));
+
+        copy.set_element(0, 0, 100.0).unwrap();
+        assert!(!Arc::ptr_eq(&original.data.elements, &copy.data.elements));
+        assert_eq!(test_utils::generic_examples()[0], original);
+        assert_eq!(100.0, copy.get_element(0, 0).unwrap());
+
+        // Once nothing else shares them, the elements are modified in place
+        let shared = Arc::as_ptr(&copy.data.elements);
+        copy.set_element(0, 1, 200.0).unwrap();
+        assert_eq!(shared, Arc::as_ptr(&copy.data.elements));
+    }
+
+    #[test]
+    fn set_data() {
+        let [mut example_matrix, _, _] = test_utils::generic_examples();
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 3),
+                actual: (3, 1)
+            },
+            example_matrix
+                .set_data(vec![vec![1.0], vec![2.3], vec![5.1]])
+                .unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::InconsistentColumnSize,
+            example_matrix
+                .set_data(vec![vec![1.0, 4.3, 7.9], vec![2.6, 11.9]])
+                .unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            example_matrix.set_data(vec![]).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            example_matrix.set_data(vec![vec![], vec![]]).unwrap_err()
+        );
+
+        let data = vec![vec![2.6, 7.1, 0.0], vec![3.5, 7.1, 9.0]];
+        example_matrix.set_data(data.clone()).unwrap();
+        assert_eq!(Matrix::new_with_data(data).unwrap(), example_matrix);
+    }
+
+    #[test]
+    fn set_row() {
+        let [mut matrix_2x3, _, _] = test_utils::generic_examples();
+
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 3, len: 2 },
+            matrix_2x3.set_row(3, vec![1.0, 2.3, 5.6]).unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (1, 3),
+                actual: (1, 2)
+            },
+            matrix_2x3.set_row(1, vec![1.0, 2.3]).unwrap_err()
+        );
+
+        let data = vec![2.6, 7.1, 0.0];
+        matrix_2x3.set_row(1, data.clone()).unwrap();
+        assert_eq!(data, matrix_2x3.data[1]);
+    }
+
+    #[test]
+    fn take_data() {
+        let [matrix_2x3, _, _] = test_utils::generic_examples();
+        let data = matrix_2x3.get_data();
+
+        let shared = matrix_2x3.clone();
+        assert_eq!(data, shared.take_data());
+        assert_eq!(data.concat(), matrix_2x3.clone().take_flat_data());
+        assert_eq!(data, matrix_2x3.clone().into_rows().collect::<Vec<_>>());
+        assert_eq!(data, matrix_2x3.into_iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn replace_row() {
+        let [mut matrix_2x3, _, _] = test_utils::generic_examples();
+        let first = matrix_2x3.get_row(0).unwrap();
+        let second = matrix_2x3.get_row(1).unwrap();
+
+        let replaced = matrix_2x3.replace_row(0, second.clone()).unwrap();
+        assert_eq!(first, replaced);
+        assert_eq!(vec![second.clone(), second], matrix_2x3.get_data());
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 2, len: 2 },
+            matrix_2x3.replace_row(2, first).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn iter_rows_mut() {
+        let [mut matrix_2x3, _, _] = test_utils::generic_examples();
+        let expected = (2.0 * &matrix_2x3).get_data();
+
+        for row in matrix_2x3.iter_rows_mut() {
+            row.iter_mut().for_each(|num| *num *= 2.0);
+        }
+        assert_eq!(expected, matrix_2x3.get_data());
+
+        // Swapping rows through the iterator keeps them whole
+        {
+            let mut rows = matrix_2x3.iter_rows_mut();
+            let (first, second) = (rows.next().unwrap(), rows.next().unwrap());
+            first.swap_with_slice(second);
+        }
+        assert_eq!(expected[1], matrix_2x3.get_row(0).unwrap());
+    }
+
+    #[test]
+    fn column_iter_mut() {
+        let [mut matrix_2x3, _, _] = test_utils::generic_examples();
+        let column = matrix_2x3.get_column(1).unwrap();
+
+        for (i, num) in matrix_2x3.column_iter_mut(1).unwrap().enumerate() {
+            *num = i as f64;
+        }
+        assert_eq!(vec![0.0, 1.0], matrix_2x3.get_column(1).unwrap());
+        assert_ne!(column, matrix_2x3.get_column(1).unwrap());
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 3, len: 3 },
+            matrix_2x3.column_iter_mut(3).err().unwrap()
+        );
+    }
+
+    #[test]
+    fn set_column() {
+        let [mut matrix_2x3, _, _] = test_utils::generic_examples();
+
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 3, len: 3 },
+            matrix_2x3.set_column(3, vec![1.0, 2.3, 5.6]).unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 1),
+                actual: (4, 1)
+            },
+            matrix_2x3
+                .set_column(1, vec![1.0, 2.3, 34.8, 0.0])
+                .unwrap_err()
+        );
+
+        let data = vec![2.6, 7.1];
+        matrix_2x3.set_column(1, data.clone()).unwrap();
+        assert!(matrix_2x3
+            .data
+            .iter()
+            .enumerate()
+            .all(|(index, row)| row[1] == data[index]));
+    }
+
+    #[test]
+    fn set_element() {
+        let [mut matrix_2x3, _, _] = test_utils::generic_examples();
+
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 3, len: 2 },
+            matrix_2x3.set_element(3, 1, 11.1).unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 3, len: 3 },
+            matrix_2x3.set_element(1, 3, 11.1).unwrap_err()
+        );
+
+        matrix_2x3.set_element(1, 1, 11.1).unwrap();
+        assert_eq!(11.1, matrix_2x3.data[1][1]);
+    }
+
+    #[test]
+    fn unchecked_access() {
+        let [mut matrix_2x3, _, _] = test_utils::generic_examples();
+
+        let mut sum = 0.0;
+        for row in 0..matrix_2x3.rows() {
+            for column in 0..matrix_2x3.columns() {
+                unsafe {
+                    sum += matrix_2x3.get_unchecked(row, column);
+                    matrix_2x3.set_unchecked(row, column, 0.0);
+                }
+            }
+        }
+
+        assert_eq!(44.5, round_result(sum));
+        assert_eq!(Matrix::new_zero_matrix(2, 3).unwrap(), matrix_2x3);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn unchecked_access_asserts_in_debug_builds() {
+        let matrix_2x3 = &test_utils::generic_examples()[0];
+        unsafe { matrix_2x3.get_unchecked(2, 0) };
+    }
+
+    #[test]
+    fn diagonal_matrix() {
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_diagonal_matrix(&[]).unwrap_err()
+        );
+
+        assert_eq!(
+            Matrix {
+                rows: 4,
+                columns: 4,
+                data: vec![
+                    vec![5.5, 0.0, 0.0, 0.0],
+                    vec![0.0, 7.8, 0.0, 0.0],
+                    vec![0.0, 0.0, 3.2, 0.0],
+                    vec![0.0, 0.0, 0.0, 11.0],
+                ]
+                .into()
+            },
+            Matrix::new_diagonal_matrix(&[5.5, 7.8, 3.2, 11.0]).unwrap()
+        )
+    }
+
+    #[test]
+    fn scalar_matrix() {
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_scalar_matrix(6.5, 0).unwrap_err()
+        );
+
+        assert_eq!(
+            Matrix {
+                rows: 4,
+                columns: 4,
+                data: vec![
+                    vec![5.5, 0.0, 0.0, 0.0],
+                    vec![0.0, 5.5, 0.0, 0.0],
+                    vec![0.0, 0.0, 5.5, 0.0],
+                    vec![0.0, 0.0, 0.0, 5.5],
+                ]
+                .into()
+            },
+            Matrix::new_scalar_matrix(5.5, 4).unwrap()
+        )
+    }
+
+    #[test]
+    fn nth_identity() {
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::nth_identity(0).unwrap_err()
+        );
+
+        assert_eq!(
+            Matrix {
+                rows: 3,
+                columns: 3,
+                data: vec![
+                    vec![1.0, 0.0, 0.0],
+                    vec![0.0, 1.0, 0.0],
+                    vec![0.0, 0.0, 1.0]
+                ]
+                .into()
+            },
+            Matrix::nth_identity(3).unwrap()
+        )
+    }
+}
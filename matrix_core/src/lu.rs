@@ -0,0 +1,607 @@
+use crate::{
+    trace::{self, Span},
+    Matrix, MatrixError, Norm,
+};
+use std::borrow::Cow;
+
+// Columns factored together before the trailing submatrix is updated
+const PANEL_WIDTH: usize = 64;
+
+// How elimination chooses the pivot of each column
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum PivotStrategy {
+    // The largest magnitude in the column
+    #[default]
+    Partial,
+    // The largest magnitude relative to the largest element of its row in the original matrix, so
+    // rows multiplied by a large constant do not win every pivot
+    ScaledPartial,
+    // The largest magnitude anywhere in the remaining submatrix, swapping columns as well as rows
+    Full,
+}
+
+// PAQ = LU with L unit lower triangular and U upper triangular. Q is the identity unless the
+// decomposition used full pivoting
+#[derive(Debug, PartialEq, Clone)]
+pub struct LuDecomposition {
+    lower: Matrix,
+    upper: Matrix,
+    row_permutation: Vec<usize>,
+    column_permutation: Vec<usize>,
+    // L below the diagonal and U on and above it, unrounded for solving
+    factors: Vec<Vec<f64>>,
+    swap_count: usize,
+}
+
+impl LuDecomposition {
+    pub fn lower(&self) -> &Matrix {
+        &self.lower
+    }
+
+    pub fn upper(&self) -> &Matrix {
+        &self.upper
+    }
+
+    // Row i of PAQ is row row_permutation[i] of A
+    pub fn row_permutation(&self) -> &[usize] {
+        &self.row_permutation
+    }
+
+    // Column j of PAQ is column column_permutation[j] of A
+    pub fn column_permutation(&self) -> &[usize] {
+        &self.column_permutation
+    }
+
+    // P as a matrix, for checking the factors or forming PA directly
+    pub fn row_permutation_matrix(&self) -> Matrix {
+        Matrix::new_permutation_matrix(&self.row_permutation).unwrap()
+    }
+
+    // Q as a matrix. Moving columns around is the transpose of moving rows the same way
+    pub fn column_permutation_matrix(&self) -> Matrix {
+        Matrix::new_permutation_matrix(&self.column_permutation)
+            .unwrap()
+            .transpose()
+    }
+
+    pub fn determinant(&self) -> f64 {
+        crate::round_result(self.unrounded_determinant())
+    }
+
+    pub(crate) fn unrounded_determinant(&self) -> f64 {
+        let product: f64 = (0..self.factors.len())
+            .map(|i| self.factors[i][i])
+            .product();
+
+        if self.swap_count.is_multiple_of(2) {
+            product
+        } else {
+            -product
+        }
+    }
+
+    // The sign and natural logarithm of the absolute value of the determinant, which stay finite
+    // where the determinant itself overflows or underflows. A singular matrix gives (0, -∞)
+    pub fn logdet(&self) -> (f64, f64) {
+        let pivots = (0..self.factors.len()).map(|i| self.factors[i][i]);
+        if pivots.clone().any(|pivot| pivot == 0.0) {
+            return (0.0, f64::NEG_INFINITY);
+        }
+
+        let negatives = pivots.clone().filter(|pivot| *pivot < 0.0).count();
+        let sign = match (self.swap_count + negatives).is_multiple_of(2) {
+            true => 1.0,
+            false => -1.0,
+        };
+
+        (
+            sign,
+            crate::round_result(crate::compensated_sum(pivots.map(|pivot| pivot.abs().ln()))),
+        )
+    }
+
+    fn smallest_pivot(&self) -> f64 {
+        (0..self.factors.len())
+            .map(|i| self.factors[i][i].abs())
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    // Solves Ax = b for every column of b, failing when a pivot is zero. `solve_general` tells apart
+    // the systems with no solution and with infinitely many instead
+    pub fn solve(&self, b: &Matrix) -> Result<Matrix, MatrixError> {
+        let n = self.factors.len();
+        let _span = Span::new(
+            "solve",
+            &[(n, n), b.shape()],
+            2 * (n * n * b.columns) as u64,
+        );
+        if b.rows != n {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (n, b.columns),
+                actual: b.shape(),
+            });
+        }
+
+        if (0..n).any(|i| self.factors[i][i] == 0.0) {
+            return Err(MatrixError::SingularMatrix);
+        }
+
+        // Each column is solved on its own, in parallel when there are enough of them
+        let columns = crate::build_vectors(b.columns, n * b.columns, |column| {
+            self.solve_vector(&b.data.iter().map(|row| row[column]).collect::<Vec<_>>())
+        });
+
+        let mut solution = Matrix::new_zero_matrix(n, b.columns).unwrap();
+        for (column, x) in columns.into_iter().enumerate() {
+            for (i, num) in x.into_iter().enumerate() {
+                solution.data[i][column] = crate::round_result(num);
+            }
+        }
+
+        Ok(solution)
+    }
+
+    // Ax = b for a single unrounded b, with every pivot known to be nonzero. With y = Q⁻¹x the
+    // system becomes LUy = Pb
+    pub(crate) fn solve_vector(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.factors.len();
+        let mut y: Vec<f64> = self.row_permutation.iter().map(|row| b[*row]).collect();
+
+        for i in 0..n {
+            for j in 0..i {
+                y[i] -= self.factors[i][j] * y[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                y[i] -= self.factors[i][j] * y[j];
+            }
+            y[i] /= self.factors[i][i];
+        }
+
+        let mut x = vec![0.0; n];
+        for (i, num) in y.into_iter().enumerate() {
+            x[self.column_permutation[i]] = num;
+        }
+        x
+    }
+
+    // Aᵀx = b in the same way, which becomes UᵀLᵀz = Qᵀb with z = Px
+    pub(crate) fn solve_transpose_vector(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.factors.len();
+        let mut z: Vec<f64> = self
+            .column_permutation
+            .iter()
+            .map(|column| b[*column])
+            .collect();
+
+        for i in 0..n {
+            for j in 0..i {
+                z[i] -= self.factors[j][i] * z[j];
+            }
+            z[i] /= self.factors[i][i];
+        }
+        for i in (0..n).rev() {
+            for j in (i + 1)..n {
+                z[i] -= self.factors[j][i] * z[j];
+            }
+        }
+
+        let mut x = vec![0.0; n];
+        for (i, num) in z.into_iter().enumerate() {
+            x[self.row_permutation[i]] = num;
+        }
+        x
+    }
+}
+
+// Subtracts the multiples of the factored panel rows given by the row's entries in the panel columns,
+// from the row's entries right of the panel
+fn eliminate(row: &mut [f64], panel: &[Vec<f64>], panel_start: usize, panel_end: usize) {
+    let (factors, rest) = row.split_at_mut(panel_end);
+    for (panel_row, factor) in panel.iter().zip(&factors[panel_start..]) {
+        for (num, panel_num) in rest.iter_mut().zip(&panel_row[panel_end..]) {
+            *num -= factor * panel_num;
+        }
+    }
+}
+
+// The first position of the largest value, so ties keep the earliest row or column
+fn first_max(candidates: impl Iterator<Item = ((usize, usize), f64)>) -> (usize, usize) {
+    candidates
+        .fold(
+            None,
+            |best: Option<((usize, usize), f64)>, candidate| match best {
+                Some((_, max)) if candidate.1 <= max => best,
+                _ => Some(candidate),
+            },
+        )
+        .unwrap()
+        .0
+}
+
+impl Matrix {
+    pub fn lu_decomposition(
+        &self,
+        strategy: PivotStrategy,
+    ) -> Result<LuDecomposition, MatrixError> {
+        let _span = Span::new(
+            "lu_decomposition",
+            &[self.shape()],
+            2 * trace::cube(self.rows) / 3,
+        );
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        let n = self.rows;
+        let mut factors = self.data.to_vec();
+        let mut row_permutation: Vec<usize> = (0..n).collect();
+        let mut column_permutation: Vec<usize> = (0..n).collect();
+        let mut swap_count = 0;
+        let mut scales: Vec<f64> = factors
+            .iter()
+            .map(|row| row.iter().fold(0.0, |max: f64, num| max.max(num.abs())))
+            .collect();
+
+        // Full pivoting searches the whole trailing submatrix for every pivot, so it has to be updated
+        // after each column, a panel of width one
+        let width = match strategy {
+            PivotStrategy::Full => 1,
+            _ => PANEL_WIDTH,
+        };
+        for panel_start in (0..n).step_by(width) {
+            let panel_end = (panel_start + width).min(n);
+            for k in panel_start..panel_end {
+                let (pivot_row, pivot_column) = match strategy {
+                    PivotStrategy::Partial => {
+                        first_max((k..n).map(|i| ((i, k), factors[i][k].abs())))
+                    }
+                    PivotStrategy::ScaledPartial => first_max((k..n).map(|i| {
+                        let ratio = match scales[i] {
+                            0.0 => 0.0,
+                            scale => factors[i][k].abs() / scale,
+                        };
+                        ((i, k), ratio)
+                    })),
+                    PivotStrategy::Full => first_max(
+                        (k..n)
+                            .flat_map(|i| (k..n).map(move |j| (i, j)))
+                            .map(|(i, j)| ((i, j), factors[i][j].abs())),
+                    ),
+                };
+
+                if pivot_row != k {
+                    factors.swap(k, pivot_row);
+                    row_permutation.swap(k, pivot_row);
+                    scales.swap(k, pivot_row);
+                    swap_count += 1;
+                }
+                if pivot_column != k {
+                    factors.iter_mut().for_each(|row| row.swap(k, pivot_column));
+                    column_permutation.swap(k, pivot_column);
+                    swap_count += 1;
+                }
+
+                // A zero pivot means the rest of the column is zero too, so there is nothing to eliminate
+                let pivot = factors[k][k];
+                if pivot == 0.0 {
+                    continue;
+                }
+
+                // Only the panel's columns are eliminated here, the rest waits for the trailing update
+                let (above, below) = factors.split_at_mut(k + 1);
+                let pivot_values = &above[k][k + 1..panel_end];
+                for row in below {
+                    let factor = row[k] / pivot;
+                    row[k] = factor;
+                    for (num, pivot_num) in row[k + 1..panel_end].iter_mut().zip(pivot_values) {
+                        *num -= factor * pivot_num;
+                    }
+                }
+            }
+
+            // The panel's rows right of it become U12, by forward substitution with the unit lower
+            // triangular L11
+            let (panel, trailing) = factors.split_at_mut(panel_end);
+            let panel = &mut panel[panel_start..];
+            for i in 1..panel.len() {
+                let (solved, rest) = panel.split_at_mut(i);
+                eliminate(&mut rest[0], solved, panel_start, panel_end);
+            }
+
+            // A22 -= L21·U12, where nearly all the work is. The rows are independent, so large trailing
+            // blocks are updated in parallel, with one fork and join per panel
+            let panel = &*panel;
+            let remaining = n - panel_end;
+            crate::update_rows(trailing, remaining * remaining * panel.len(), |row| {
+                eliminate(row, panel, panel_start, panel_end)
+            });
+        }
+
+        let mut lower = Matrix::nth_identity(n).unwrap();
+        let mut upper = Matrix::new_zero_matrix(n, n).unwrap();
+        for (i, row) in factors.iter().enumerate() {
+            for (j, num) in row.iter().enumerate() {
+                let num = crate::round_result(*num);
+                match j < i {
+                    true => lower.data[i][j] = num,
+                    false => upper.data[i][j] = num,
+                }
+            }
+        }
+
+        Ok(LuDecomposition {
+            lower,
+            upper,
+            row_permutation,
+            column_permutation,
+            factors,
+            swap_count,
+        })
+    }
+
+    // Fails with SingularMatrix when elimination meets a pivot no larger than `tolerance` times the
+    // ∞-norm
+    pub(crate) fn nonsingular_lu(
+        &self,
+        tolerance: f64,
+    ) -> Result<Cow<'_, LuDecomposition>, MatrixError> {
+        let lu = self.settings_lu()?;
+        if lu.smallest_pivot() <= tolerance * self.unrounded_norm(Norm::Infinity) {
+            return Err(MatrixError::SingularMatrix);
+        }
+
+        Ok(lu)
+    }
+
+    // The decomposition with the pivot strategy of the current settings, computed once and kept until
+    // the matrix is modified, since the determinant, the inverse and the singularity test all start
+    // from it
+    pub(crate) fn settings_lu(&self) -> Result<Cow<'_, LuDecomposition>, MatrixError> {
+        match self.data.cached(
+            |cache| &cache.lu,
+            || self.lu_decomposition(crate::current_settings().pivot_strategy),
+        ) {
+            Cow::Borrowed(lu) => lu.as_ref().map(Cow::Borrowed).map_err(|err| *err),
+            Cow::Owned(lu) => lu.map(Cow::Owned),
+        }
+    }
+
+    pub fn determinant_with(&self, strategy: PivotStrategy) -> Result<f64, MatrixError> {
+        if strategy == crate::current_settings().pivot_strategy {
+            return Ok(self.settings_lu()?.determinant());
+        }
+
+        Ok(self.lu_decomposition(strategy)?.determinant())
+    }
+
+    pub fn logdet(&self) -> Result<(f64, f64), MatrixError> {
+        Ok(self.settings_lu()?.logdet())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    const STRATEGIES: [PivotStrategy; 3] = [
+        PivotStrategy::Partial,
+        PivotStrategy::ScaledPartial,
+        PivotStrategy::Full,
+    ];
+
+    #[test]
+    fn factors_reproduce_the_matrix() {
+        for matrix in test_utils::square_examples() {
+            for strategy in STRATEGIES {
+                let lu = matrix.lu_decomposition(strategy).unwrap();
+                let product = (lu.lower() * lu.upper()).unwrap();
+
+                for (i, row) in lu.row_permutation().iter().enumerate() {
+                    for (j, column) in lu.column_permutation().iter().enumerate() {
+                        let expected = matrix.get_element(*row, *column).unwrap();
+                        assert!((expected - product.get_element(i, j).unwrap()).abs() < 1e-3);
+                    }
+                }
+                assert_eq!(
+                    matrix.determinant().unwrap(),
+                    matrix.determinant_with(strategy).unwrap()
+                );
+
+                let permuted = (&(&lu.row_permutation_matrix() * &matrix).unwrap()
+                    * &lu.column_permutation_matrix())
+                    .unwrap();
+                assert!(permuted.distance(&product, Norm::Infinity).unwrap() < 1e-3);
+            }
+        }
+
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[0]
+                .lu_decomposition(PivotStrategy::Full)
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn pivot_choice() {
+        // The first row is scaled up, so it has the largest element of the first column but the
+        // smallest relative to its own row
+        let matrix = Matrix::new_with_data(vec![vec![30.0, 591400.0], vec![5.291, -6.13]]).unwrap();
+
+        assert_eq!(
+            [0, 1],
+            matrix
+                .lu_decomposition(PivotStrategy::Partial)
+                .unwrap()
+                .row_permutation()
+        );
+        assert_eq!(
+            [1, 0],
+            matrix
+                .lu_decomposition(PivotStrategy::ScaledPartial)
+                .unwrap()
+                .row_permutation()
+        );
+
+        let full = matrix.lu_decomposition(PivotStrategy::Full).unwrap();
+        assert_eq!([0, 1], full.row_permutation());
+        assert_eq!([1, 0], full.column_permutation());
+        assert_eq!(-3129281.3, full.determinant());
+    }
+
+    #[test]
+    fn logdet() {
+        for matrix in test_utils::square_examples() {
+            let determinant = matrix.determinant().unwrap();
+            let (sign, log) = matrix.logdet().unwrap();
+            assert_eq!(determinant.signum(), sign);
+            assert!((determinant.abs().ln() - log).abs() < 1e-5);
+        }
+
+        // The determinant is 10^-400, below the smallest positive f64
+        let mut tiny = 0.01 * &Matrix::nth_identity(200).unwrap();
+        tiny.set_element(0, 0, -0.01).unwrap();
+        assert_eq!(0.0, tiny.determinant().unwrap());
+        assert_eq!((-1.0, -921.03404), tiny.logdet().unwrap());
+
+        let singular = Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert_eq!((0.0, f64::NEG_INFINITY), singular.logdet().unwrap());
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[0].logdet().unwrap_err()
+        );
+    }
+
+    // Spans several panels, so pivots are also chosen after trailing updates and row swaps reach
+    // rows already factored
+    #[test]
+    fn factors_across_panels() {
+        let n = 2 * PANEL_WIDTH + 20;
+        let matrix = Matrix::new_with_data(
+            (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| ((i * i * 31 + i * j * 7 + j * 17) % 1009) as f64 / 10.0 - 50.0)
+                        .collect()
+                })
+                .collect(),
+        )
+        .unwrap();
+
+        for strategy in STRATEGIES {
+            let lu = matrix.lu_decomposition(strategy).unwrap();
+            assert!(lu.smallest_pivot() > 1e-6);
+            for (i, row) in lu.row_permutation().iter().enumerate() {
+                for (j, column) in lu.column_permutation().iter().enumerate() {
+                    let product: f64 = (0..=i.min(j))
+                        .map(|k| match k == i {
+                            true => lu.factors[k][j],
+                            false => lu.factors[i][k] * lu.factors[k][j],
+                        })
+                        .sum();
+                    let expected = matrix.get_element(*row, *column).unwrap();
+                    assert!((expected - product).abs() < 1e-9);
+                }
+            }
+        }
+    }
+
+    // Large enough for the parallel path when the `rayon` feature is on
+    #[test]
+    fn large_system() {
+        let n = 260;
+        let matrix = Matrix::new_with_data(
+            (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| {
+                            if i == j {
+                                n as f64
+                            } else {
+                                ((i * 7 + j * 3) % 11) as f64 / 10.0
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+        .unwrap();
+        let x =
+            Matrix::new_with_data((0..n).map(|i| vec![(i % 5) as f64 - 2.0]).collect()).unwrap();
+        let b = (&matrix * &x).unwrap();
+
+        let lu = matrix.lu_decomposition(PivotStrategy::Partial).unwrap();
+        assert_eq!(x, lu.solve(&b).unwrap());
+
+        // The product of the unit lower bidiagonal L with ones below the diagonal and the unit upper
+        // bidiagonal U with minus ones above it. Its inverse U⁻¹L⁻¹ has elements 0 and ±1, which
+        // rounding cannot disturb
+        let product = Matrix::new_with_data(
+            (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| match (i, j) {
+                            (0, 0) => 1.0,
+                            _ if j == i + 1 => -1.0,
+                            _ if i == j + 1 => 1.0,
+                            _ => 0.0,
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+        .unwrap();
+        let inverse = Matrix::new_with_data(
+            (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| {
+                            let first = i.max(j);
+                            match ((n - first) % 2, (first - j) % 2) {
+                                (0, _) => 0.0,
+                                (_, 0) => 1.0,
+                                _ => -1.0,
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+        .unwrap();
+        assert_eq!(inverse, product.inverse().unwrap());
+    }
+
+    #[test]
+    fn solve() {
+        let a = Matrix::new_with_data(vec![vec![30.0, 591400.0], vec![5.291, -6.13]]).unwrap();
+        let b = Matrix::new_with_data(vec![vec![591700.0], vec![46.78]]).unwrap();
+
+        for strategy in STRATEGIES {
+            assert_eq!(
+                Matrix::new_with_data(vec![vec![10.0], vec![1.0]]).unwrap(),
+                a.lu_decomposition(strategy).unwrap().solve(&b).unwrap()
+            );
+        }
+
+        let singular = Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        for strategy in STRATEGIES {
+            let lu = singular.lu_decomposition(strategy).unwrap();
+            assert_eq!(0.0, lu.determinant());
+            assert_eq!(MatrixError::SingularMatrix, lu.solve(&b).unwrap_err());
+        }
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 2),
+                actual: (4, 2)
+            },
+            a.lu_decomposition(PivotStrategy::Partial)
+                .unwrap()
+                .solve(&test_utils::generic_examples()[2])
+                .unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{
+    functions::{self, Data},
+    LinearSystemSolution, Matrix, MatrixError,
+};
+
+// Elements are rounded to five decimal places, so rows such as 1/3 1/3 1/3 only sum to 1 approximately
+const ROW_SUM_TOLERANCE: f64 = 1e-4;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct AbsorbingChain {
+    transient_states: Vec<usize>,
+    absorbing_states: Vec<usize>,
+    expected_steps: Vec<f64>,
+    absorption_probabilities: Matrix,
+}
+
+impl AbsorbingChain {
+    pub fn transient_states(&self) -> &[usize] {
+        &self.transient_states
+    }
+
+    pub fn absorbing_states(&self) -> &[usize] {
+        &self.absorbing_states
+    }
+
+    // Expected number of steps before absorption, starting from each transient state
+    pub fn expected_steps(&self) -> &[f64] {
+        &self.expected_steps
+    }
+
+    // Row i, column j is the probability of ending in absorbing state j when starting from transient state i
+    pub fn absorption_probabilities(&self) -> &Matrix {
+        &self.absorption_probabilities
+    }
+}
+
+impl Matrix {
+    pub fn is_row_stochastic(&self) -> bool {
+        self.is_square()
+            && self.data.iter().all(|row| {
+                row.iter().all(|num| *num >= 0.0)
+                    && (row.iter().sum::<f64>() - 1.0).abs() <= ROW_SUM_TOLERANCE
+            })
+    }
+
+    // States the chain never leaves once it enters them
+    pub fn absorbing_states(&self) -> Vec<usize> {
+        (0..self.rows.min(self.columns))
+            .filter(|state| self.data[*state][*state] == 1.0)
+            .collect()
+    }
+
+    // A row vector π with πP = π, or None when the chain has more than one, which happens when it
+    // has several closed classes of states
+    pub fn stationary_distribution(&self) -> Result<Option<Matrix>, MatrixError> {
+        if !self.is_row_stochastic() {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        // (Pᵀ - I)πᵀ = 0 always has a redundant equation, so the last one is replaced by the
+        // requirement that the probabilities sum to 1
+        let n = self.rows;
+        let mut system = (&self.transpose() - &Matrix::nth_identity(n).unwrap()).unwrap();
+        system.data[n - 1] = vec![1.0; n];
+        let mut constants = Matrix::new_zero_matrix(n, 1).unwrap();
+        constants.data[n - 1][0] = 1.0;
+
+        Ok(match system.solve_general(&constants)? {
+            LinearSystemSolution::Unique(solution) => Some(solution.transpose()),
+            _ => None,
+        })
+    }
+
+    pub fn absorbing_chain(&self) -> Result<AbsorbingChain, MatrixError> {
+        if !self.is_row_stochastic() {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        let absorbing_states = self.absorbing_states();
+        let transient_states: Vec<usize> = (0..self.rows)
+            .filter(|state| !absorbing_states.contains(state))
+            .collect();
+        if absorbing_states.is_empty() || transient_states.is_empty() {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        let block = |rows: &[usize], columns: &[usize]| -> Data {
+            rows.iter()
+                .map(|row| {
+                    columns
+                        .iter()
+                        .map(|column| self.data[*row][*column])
+                        .collect()
+                })
+                .collect()
+        };
+        let absorbing = block(&transient_states, &absorbing_states);
+        let identity_minus_transient: Data = block(&transient_states, &transient_states)
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .map(|(j, num)| if i == j { 1.0 - num } else { -num })
+                    .collect()
+            })
+            .collect();
+
+        // The fundamental matrix N = (I - Q)⁻¹ counts the expected visits to each transient state.
+        // It does not exist when some transient states can never reach an absorbing one
+        let fundamental =
+            functions::inverse(&identity_minus_transient).ok_or(MatrixError::SingularMatrix)?;
+
+        Ok(AbsorbingChain {
+            expected_steps: fundamental
+                .iter()
+                .map(|row| crate::round_result(crate::compensated_sum(row.iter().copied())))
+                .collect(),
+            absorption_probabilities: functions::rounded(functions::product(
+                &fundamental,
+                &absorbing,
+            )),
+            transient_states,
+            absorbing_states,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn row_stochastic() {
+        assert!(
+            Matrix::new_with_data(vec![vec![0.5, 0.5], vec![0.25, 0.75]])
+                .unwrap()
+                .is_row_stochastic()
+        );
+        assert!(
+            Matrix::new_with_data(vec![vec![0.33333, 0.33333, 0.33333]; 3])
+                .unwrap()
+                .is_row_stochastic()
+        );
+
+        assert!(!Matrix::new_with_data(vec![vec![0.5, 0.6], vec![0.5, 0.5]])
+            .unwrap()
+            .is_row_stochastic());
+        assert!(
+            !Matrix::new_with_data(vec![vec![1.5, -0.5], vec![0.5, 0.5]])
+                .unwrap()
+                .is_row_stochastic()
+        );
+        assert!(!test_utils::generic_examples()[0].is_row_stochastic());
+    }
+
+    #[test]
+    fn stationary_distribution() {
+        let chain = Matrix::new_with_data(vec![vec![0.9, 0.1], vec![0.5, 0.5]]).unwrap();
+        assert_eq!(
+            Ok(Some(
+                Matrix::new_with_data(vec![vec![0.83333, 0.16667]]).unwrap()
+            )),
+            chain.stationary_distribution()
+        );
+
+        // Two absorbing states are two closed classes, each with its own stationary distribution
+        let reducible = Matrix::new_with_data(vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.5, 0.0, 0.5],
+            vec![0.0, 0.0, 1.0],
+        ])
+        .unwrap();
+        assert_eq!(Ok(None), reducible.stationary_distribution());
+
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            test_utils::generic_examples()[0]
+                .stationary_distribution()
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn absorbing_chain() {
+        // A random walk on 0..=3 that stops at either end
+        let walk = Matrix::new_with_data(vec![
+            vec![1.0, 0.0, 0.0, 0.0],
+            vec![0.5, 0.0, 0.5, 0.0],
+            vec![0.0, 0.5, 0.0, 0.5],
+            vec![0.0, 0.0, 0.0, 1.0],
+        ])
+        .unwrap();
+        let chain = walk.absorbing_chain().unwrap();
+
+        assert_eq!(vec![0, 3], walk.absorbing_states());
+        assert_eq!(&[1, 2], chain.transient_states());
+        assert_eq!(&[0, 3], chain.absorbing_states());
+        assert_eq!(&[2.0, 2.0], chain.expected_steps());
+        assert_eq!(
+            &Matrix::new_with_data(vec![vec![0.66667, 0.33333], vec![0.33333, 0.66667]]).unwrap(),
+            chain.absorption_probabilities()
+        );
+
+        let trapped = Matrix::new_with_data(vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+            vec![0.0, 1.0, 0.0],
+        ])
+        .unwrap();
+        assert_eq!(
+            MatrixError::SingularMatrix,
+            trapped.absorbing_chain().unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            Matrix::new_with_data(vec![vec![0.5, 0.5], vec![0.5, 0.5]])
+                .unwrap()
+                .absorbing_chain()
+                .unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{Matrix, MatrixError};
+use std::{
+    error::Error,
+    f64::consts::PI,
+    fmt::{self, Display, Formatter},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[non_exhaustive]
+pub enum ParseMatlabError {
+    MissingBrackets,
+    InvalidNumber(String),
+    Matrix(MatrixError),
+}
+
+impl Display for ParseMatlabError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseMatlabError::MissingBrackets => {
+                write!(f, "the matrix must be enclosed in square brackets")
+            }
+            ParseMatlabError::InvalidNumber(element) => {
+                write!(f, "'{element}' is not a real number")
+            }
+            ParseMatlabError::Matrix(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for ParseMatlabError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseMatlabError::Matrix(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<MatrixError> for ParseMatlabError {
+    fn from(err: MatrixError) -> Self {
+        ParseMatlabError::Matrix(err)
+    }
+}
+
+fn parse_element(element: &str) -> Result<f64, ParseMatlabError> {
+    let (sign, magnitude) = match element.strip_prefix('-') {
+        Some(magnitude) => (-1.0, magnitude),
+        None => (1.0, element.strip_prefix('+').unwrap_or(element)),
+    };
+
+    // Rust also reads inf and nan, in any case, which covers MATLAB's Inf and NaN
+    match magnitude {
+        "pi" => Ok(sign * PI),
+        _ => magnitude
+            .parse::<f64>()
+            .map(|num| sign * num)
+            .map_err(|_| ParseMatlabError::InvalidNumber(element.to_string())),
+    }
+}
+
+impl Matrix {
+    // Reads a matrix literal such as `[1 2; 3 4]` or `[1, 2\n 3, 4]`, optionally written as a whole
+    // statement like `A = [1 2; 3 4];` with `%` comments and `...` line continuations
+    pub fn parse_matlab(text: &str) -> Result<Self, ParseMatlabError> {
+        let text = text
+            .lines()
+            .map(|line| line.split('%').next().unwrap())
+            // Anything after a continuation is a comment too
+            .map(|line| match line.split_once("...") {
+                Some((continued, _)) => format!("{continued} "),
+                None => format!("{line}\n"),
+            })
+            .collect::<String>();
+
+        let mut literal = text.trim();
+        if let Some((name, value)) = literal.split_once('=') {
+            let name = name.trim();
+            if !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                literal = value.trim();
+            }
+        }
+        let literal = literal.strip_suffix(';').unwrap_or(literal).trim_end();
+        let inner = literal
+            .strip_prefix('[')
+            .and_then(|literal| literal.strip_suffix(']'))
+            .ok_or(ParseMatlabError::MissingBrackets)?;
+
+        let data = inner
+            .split([';', '\n'])
+            .map(|row| {
+                row.split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|element| !element.is_empty())
+                    .map(parse_element)
+                    .collect::<Result<Vec<f64>, _>>()
+            })
+            .filter(|row| !row.as_ref().is_ok_and(Vec::is_empty))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Matrix::new_with_data(data)?)
+    }
+
+    pub fn to_matlab_string(&self) -> String {
+        let rows = self
+            .data
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|num| match num {
+                        num if num.is_nan() => "NaN".to_string(),
+                        num if num.is_infinite() && num.is_sign_negative() => "-Inf".to_string(),
+                        num if num.is_infinite() => "Inf".to_string(),
+                        num => (num + 0.0).to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        format!("[{rows}]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn parse_matlab() {
+        let expected = Matrix::new_with_data(vec![vec![1.0, -2.5], vec![3e2, 4.0]]).unwrap();
+
+        assert_eq!(expected, Matrix::parse_matlab("[1 -2.5; 3e2 4]").unwrap());
+        assert_eq!(
+            expected,
+            Matrix::parse_matlab("[1, -2.5\n 300, +4]").unwrap()
+        );
+        assert_eq!(
+            expected,
+            Matrix::parse_matlab("A = [1 -2.5 ... first row\n ; 300 4]; % from the docs").unwrap()
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![f64::INFINITY, -PI]]).unwrap(),
+            Matrix::parse_matlab("[Inf -pi]").unwrap()
+        );
+
+        assert_eq!(
+            ParseMatlabError::MissingBrackets,
+            Matrix::parse_matlab("1 2; 3 4").unwrap_err()
+        );
+        assert_eq!(
+            ParseMatlabError::InvalidNumber("2i".to_string()),
+            Matrix::parse_matlab("[1 2i]").unwrap_err()
+        );
+        assert_eq!(
+            ParseMatlabError::Matrix(MatrixError::InconsistentColumnSize),
+            Matrix::parse_matlab("[1 2; 3]").unwrap_err()
+        );
+        assert_eq!(
+            ParseMatlabError::Matrix(MatrixError::ZeroDimension),
+            Matrix::parse_matlab("[]").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn to_matlab_string() {
+        let matrix = Matrix::new_with_data(vec![
+            vec![1.0, -0.0, 0.5],
+            vec![f64::NEG_INFINITY, 2.0, 3.0],
+        ])
+        .unwrap();
+        assert_eq!("[1 0 0.5; -Inf 2 3]", matrix.to_matlab_string());
+
+        for matrix in test_utils::generic_examples() {
+            assert_eq!(
+                matrix,
+                Matrix::parse_matlab(&matrix.to_matlab_string()).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,485 @@
+use crate::{Matrix, MatrixError};
+use std::ops::{Add, Mul, Sub};
+
+// A matrix over the integers modulo a prime p, where every nonzero element has an inverse so
+// elimination works exactly as over the reals, without any rounding
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ModularMatrix {
+    modulus: u64,
+    rows: usize,
+    columns: usize,
+    data: Vec<Vec<u64>>,
+}
+
+// Elements are always below the modulus, so these can't overflow even for moduli near u64::MAX
+fn add(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 + b as u128) % modulus as u128) as u64
+}
+
+fn subtract(a: u64, b: u64, modulus: u64) -> u64 {
+    if a >= b {
+        a - b
+    } else {
+        a + (modulus - b)
+    }
+}
+
+fn multiply(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+fn power(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if !exponent.is_multiple_of(2) {
+            result = multiply(result, base, modulus);
+        }
+        base = multiply(base, base, modulus);
+        exponent /= 2;
+    }
+
+    result
+}
+
+// By Fermat's little theorem, for a nonzero a
+fn reciprocal(a: u64, modulus: u64) -> u64 {
+    power(a, modulus - 2, modulus)
+}
+
+// Miller-Rabin with the first twelve primes as witnesses, which is exact for every u64
+fn is_prime(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    if let Some(witness) = WITNESSES.iter().find(|witness| n.is_multiple_of(**witness)) {
+        return n == *witness;
+    }
+
+    let (mut odd, mut twos) = (n - 1, 0);
+    while odd.is_multiple_of(2) {
+        odd /= 2;
+        twos += 1;
+    }
+
+    WITNESSES.iter().all(|witness| {
+        let mut x = power(*witness, odd, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        (1..twos).any(|_| {
+            x = multiply(x, x, n);
+            x == n - 1
+        })
+    })
+}
+
+impl ModularMatrix {
+    // Negative elements are taken to their representative in 0..p. The modulus has to be prime
+    pub fn new(data: Vec<Vec<i64>>, modulus: u64) -> Result<Self, MatrixError> {
+        if !is_prime(modulus) {
+            return Err(MatrixError::InvalidArgument);
+        }
+        let Some(first_row) = data.first() else {
+            return Err(MatrixError::ZeroDimension);
+        };
+        if data.iter().any(|row| first_row.len() != row.len()) {
+            return Err(MatrixError::InconsistentColumnSize);
+        }
+        if first_row.is_empty() {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        Ok(Self {
+            modulus,
+            rows: data.len(),
+            columns: data[0].len(),
+            data: data
+                .into_iter()
+                .map(|row| {
+                    row.into_iter()
+                        .map(|num| (num as i128).rem_euclid(modulus as i128) as u64)
+                        .collect()
+                })
+                .collect(),
+        })
+    }
+
+    pub fn from_matrix(matrix: &Matrix, modulus: u64) -> Result<Self, MatrixError> {
+        if matrix
+            .data
+            .iter()
+            .flatten()
+            .any(|num| num.fract() != 0.0 || num.abs() > i64::MAX as f64)
+        {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        Self::new(
+            matrix
+                .data
+                .iter()
+                .map(|row| row.iter().map(|num| *num as i64).collect())
+                .collect(),
+            modulus,
+        )
+    }
+
+    // The elements as their representatives in 0..p
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::new_with_data(
+            self.data
+                .iter()
+                .map(|row| row.iter().map(|num| *num as f64).collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn get_data(&self) -> Vec<Vec<u64>> {
+        self.data.clone()
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+
+    // Gauss-Jordan elimination. Any nonzero pivot is as good as another, so the first one is taken
+    pub fn row_reduce(&self) -> (Self, Vec<usize>) {
+        let p = self.modulus;
+        let mut reduced = self.clone();
+        let mut pivot_columns = vec![];
+        let mut pivot_row = 0;
+
+        for column in 0..reduced.columns {
+            if pivot_row == reduced.rows {
+                break;
+            }
+
+            let Some(nonzero_row) =
+                (pivot_row..reduced.rows).find(|row| reduced.data[*row][column] != 0)
+            else {
+                continue;
+            };
+
+            reduced.data.swap(pivot_row, nonzero_row);
+            let pivot_reciprocal = reciprocal(reduced.data[pivot_row][column], p);
+            reduced.data[pivot_row]
+                .iter_mut()
+                .for_each(|num| *num = multiply(*num, pivot_reciprocal, p));
+
+            let pivot_values = reduced.data[pivot_row].clone();
+            reduced
+                .data
+                .iter_mut()
+                .enumerate()
+                .filter(|(row, _)| *row != pivot_row)
+                .for_each(|(_, row)| {
+                    let factor = row[column];
+                    row.iter_mut()
+                        .zip(pivot_values.iter())
+                        .for_each(|(num, pivot_num)| {
+                            *num = subtract(*num, multiply(factor, *pivot_num, p), p)
+                        })
+                });
+
+            pivot_columns.push(column);
+            pivot_row += 1;
+        }
+
+        (reduced, pivot_columns)
+    }
+
+    pub fn rank(&self) -> usize {
+        self.row_reduce().1.len()
+    }
+
+    // The product of the pivots of an elimination to upper triangular form, negated for every swap
+    pub fn determinant(&self) -> Result<u64, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        let p = self.modulus;
+        let mut data = self.data.clone();
+        let mut determinant = 1;
+        for column in 0..self.columns {
+            let Some(nonzero_row) = (column..self.rows).find(|row| data[*row][column] != 0) else {
+                return Ok(0);
+            };
+            if nonzero_row != column {
+                data.swap(column, nonzero_row);
+                determinant = subtract(0, determinant, p);
+            }
+
+            let pivot = data[column][column];
+            determinant = multiply(determinant, pivot, p);
+            let pivot_reciprocal = reciprocal(pivot, p);
+
+            let (above, below) = data.split_at_mut(column + 1);
+            let pivot_values = &above[column];
+            for row in below {
+                let factor = multiply(row[column], pivot_reciprocal, p);
+                row.iter_mut()
+                    .zip(pivot_values)
+                    .for_each(|(num, pivot_num)| {
+                        *num = subtract(*num, multiply(factor, *pivot_num, p), p)
+                    });
+            }
+        }
+
+        Ok(determinant)
+    }
+
+    // Row reduces [A | I] to [I | A⁻¹]
+    pub fn inverse(&self) -> Result<Self, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        let n = self.rows;
+        let augmented = Self {
+            columns: 2 * n,
+            data: self
+                .data
+                .iter()
+                .enumerate()
+                .map(|(i, row)| {
+                    let mut row = row.clone();
+                    row.extend((0..n).map(|j| u64::from(i == j)));
+                    row
+                })
+                .collect(),
+            ..self.clone()
+        };
+
+        let (reduced, pivot_columns) = augmented.row_reduce();
+        if pivot_columns.len() < n || pivot_columns[n - 1] != n - 1 {
+            return Err(MatrixError::SingularMatrix);
+        }
+
+        Ok(Self {
+            data: reduced
+                .data
+                .into_iter()
+                .map(|row| row[n..].to_vec())
+                .collect(),
+            ..self.clone()
+        })
+    }
+
+    fn check_modulus(&self, rhs: &Self) -> Result<(), MatrixError> {
+        if self.modulus != rhs.modulus {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        Ok(())
+    }
+
+    fn element_wise_operation<F>(&self, rhs: &Self, operation: F) -> Result<Self, MatrixError>
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        self.check_modulus(rhs)?;
+        if self.rows != rhs.rows || self.columns != rhs.columns {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.rows, self.columns),
+                actual: (rhs.rows, rhs.columns),
+            });
+        }
+
+        Ok(Self {
+            data: self
+                .data
+                .iter()
+                .zip(&rhs.data)
+                .map(|(row, rhs_row)| {
+                    row.iter()
+                        .zip(rhs_row)
+                        .map(|(a, b)| operation(*a, *b))
+                        .collect()
+                })
+                .collect(),
+            ..self.clone()
+        })
+    }
+}
+
+impl Add for &ModularMatrix {
+    type Output = Result<ModularMatrix, MatrixError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.element_wise_operation(rhs, |a, b| add(a, b, self.modulus))
+    }
+}
+
+impl Sub for &ModularMatrix {
+    type Output = Result<ModularMatrix, MatrixError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.element_wise_operation(rhs, |a, b| subtract(a, b, self.modulus))
+    }
+}
+
+impl Mul for &ModularMatrix {
+    type Output = Result<ModularMatrix, MatrixError>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.check_modulus(rhs)?;
+        if self.columns != rhs.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.columns, rhs.columns),
+                actual: (rhs.rows, rhs.columns),
+            });
+        }
+
+        let p = self.modulus;
+        Ok(ModularMatrix {
+            modulus: self.modulus,
+            rows: self.rows,
+            columns: rhs.columns,
+            data: self
+                .data
+                .iter()
+                .map(|row| {
+                    (0..rhs.columns)
+                        .map(|j| {
+                            row.iter().zip(&rhs.data).fold(0, |sum, (a, rhs_row)| {
+                                add(sum, multiply(*a, rhs_row[j], p), p)
+                            })
+                        })
+                        .collect()
+                })
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn is_prime() {
+        let primes: Vec<u64> = (0..30).filter(|n| super::is_prime(*n)).collect();
+        assert_eq!(vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29], primes);
+
+        assert!(super::is_prime(18_446_744_073_709_551_557));
+        // Strong pseudoprime to bases 2, 3, 5 and 7
+        assert!(!super::is_prime(3_215_031_751));
+        assert!(!super::is_prime(u64::MAX));
+    }
+
+    #[test]
+    fn new() {
+        let matrix = ModularMatrix::new(vec![vec![-1, 7], vec![12, 0]], 5).unwrap();
+        assert_eq!(vec![vec![4, 2], vec![2, 0]], matrix.get_data());
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![4.0, 2.0], vec![2.0, 0.0]]).unwrap(),
+            matrix.to_matrix()
+        );
+
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            ModularMatrix::new(vec![vec![1]], 6).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::InconsistentColumnSize,
+            ModularMatrix::new(vec![vec![1], vec![1, 2]], 7).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            ModularMatrix::from_matrix(&test_utils::generic_examples()[0], 7).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn determinant() {
+        // 2·4 - 3·1 = 5, so 0 modulo 5 and 5 modulo 7
+        let matrix = Matrix::new_with_data(vec![vec![2.0, 3.0], vec![1.0, 4.0]]).unwrap();
+        assert_eq!(
+            0,
+            ModularMatrix::from_matrix(&matrix, 5)
+                .unwrap()
+                .determinant()
+                .unwrap()
+        );
+        assert_eq!(
+            5,
+            ModularMatrix::from_matrix(&matrix, 7)
+                .unwrap()
+                .determinant()
+                .unwrap()
+        );
+
+        // Needs a row swap, which flips the sign: -(1·1·1) = 10 modulo 11
+        let swapped = ModularMatrix::new(vec![vec![0, 1, 0], vec![1, 0, 0], vec![0, 0, 1]], 11);
+        assert_eq!(10, swapped.unwrap().determinant().unwrap());
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix =
+            ModularMatrix::new(vec![vec![1, 2, 3], vec![0, 1, 4], vec![5, 6, 0]], 13).unwrap();
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(
+            ModularMatrix::new(vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 1]], 13).unwrap(),
+            (&matrix * &inverse).unwrap()
+        );
+
+        let singular = ModularMatrix::new(vec![vec![2, 3], vec![1, 4]], 5).unwrap();
+        assert_eq!(MatrixError::SingularMatrix, singular.inverse().unwrap_err());
+        assert_eq!(1, singular.rank());
+    }
+
+    #[test]
+    fn row_reduce() {
+        let matrix = ModularMatrix::new(vec![vec![2, 4, 1], vec![1, 2, 4]], 5).unwrap();
+        let (reduced, pivot_columns) = matrix.row_reduce();
+        assert_eq!(vec![vec![1, 2, 0], vec![0, 0, 1]], reduced.get_data());
+        assert_eq!(vec![0, 2], pivot_columns);
+    }
+
+    #[test]
+    fn operations() {
+        let a = ModularMatrix::new(vec![vec![1, 2], vec![3, 4]], 5).unwrap();
+        let b = ModularMatrix::new(vec![vec![4, 4], vec![4, 4]], 5).unwrap();
+
+        assert_eq!(vec![vec![0, 1], vec![2, 3]], (&a + &b).unwrap().get_data());
+        assert_eq!(vec![vec![2, 3], vec![4, 0]], (&a - &b).unwrap().get_data());
+        assert_eq!(vec![vec![2, 2], vec![3, 3]], (&a * &b).unwrap().get_data());
+
+        // -1 · -1 + -1 · -1 = 2 with elements next to u64::MAX
+        let large = ModularMatrix::new(vec![vec![-1, -1]], 18_446_744_073_709_551_557).unwrap();
+        let column = ModularMatrix::new(vec![vec![-1], vec![-1]], large.modulus()).unwrap();
+        assert_eq!(vec![vec![2]], (&large * &column).unwrap().get_data());
+        assert_eq!(vec![vec![0, 0]], (&large - &large).unwrap().get_data());
+
+        let other_modulus = ModularMatrix::new(vec![vec![1, 2], vec![3, 4]], 7).unwrap();
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            (&a + &other_modulus).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 1),
+                actual: (1, 1)
+            },
+            (&a * &ModularMatrix::new(vec![vec![1]], 5).unwrap()).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{Matrix, MatrixError};
+use nalgebra::DMatrix;
+
+// nalgebra allows matrices with no rows or columns, which are rejected like everywhere else
+impl TryFrom<&DMatrix<f64>> for Matrix {
+    type Error = MatrixError;
+
+    fn try_from(matrix: &DMatrix<f64>) -> Result<Self, Self::Error> {
+        if matrix.is_empty() {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        Ok(Matrix {
+            rows: matrix.nrows(),
+            columns: matrix.ncols(),
+            data: matrix
+                .row_iter()
+                .map(|row| row.iter().copied().collect())
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<DMatrix<f64>> for Matrix {
+    type Error = MatrixError;
+
+    fn try_from(matrix: DMatrix<f64>) -> Result<Self, Self::Error> {
+        Matrix::try_from(&matrix)
+    }
+}
+
+impl From<&Matrix> for DMatrix<f64> {
+    fn from(matrix: &Matrix) -> Self {
+        DMatrix::from_fn(matrix.rows, matrix.columns, |row, column| {
+            matrix.data[row][column]
+        })
+    }
+}
+
+impl From<Matrix> for DMatrix<f64> {
+    fn from(matrix: Matrix) -> Self {
+        DMatrix::from(&matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn round_trip() {
+        for matrix in test_utils::generic_examples() {
+            let converted = DMatrix::from(&matrix);
+            assert_eq!(
+                (matrix.rows(), matrix.columns()),
+                (converted.nrows(), converted.ncols())
+            );
+            assert_eq!(matrix.get_element(1, 1).unwrap(), converted[(1, 1)]);
+            assert_eq!(matrix, Matrix::try_from(converted).unwrap());
+        }
+
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::try_from(DMatrix::<f64>::zeros(0, 3)).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn products_agree() {
+        let [first, _, tall] = test_utils::generic_examples();
+        let product: DMatrix<f64> = DMatrix::from(&tall) * DMatrix::from(&first);
+
+        assert_eq!(
+            (&tall * &first).unwrap(),
+            Matrix::try_from(product.map(crate::round_result)).unwrap()
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{Matrix, MatrixError};
+use ndarray::{Array2, ArrayView2};
+
+impl Matrix {
+    // Rows are stored separately, so both directions copy the elements
+    pub fn from_array2(array: ArrayView2<f64>) -> Result<Self, MatrixError> {
+        let (rows, columns) = array.dim();
+        if rows == 0 || columns == 0 {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        Ok(Matrix {
+            rows,
+            columns,
+            data: array.rows().into_iter().map(|row| row.to_vec()).collect(),
+        })
+    }
+
+    pub fn to_array2(&self) -> Array2<f64> {
+        Array2::from_shape_fn((self.rows, self.columns), |(row, column)| {
+            self.data[row][column]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use ndarray::array;
+
+    #[test]
+    fn round_trip() {
+        for matrix in test_utils::generic_examples() {
+            let array = matrix.to_array2();
+            assert_eq!((matrix.rows(), matrix.columns()), array.dim());
+            assert_eq!(matrix, Matrix::from_array2(array.view()).unwrap());
+        }
+
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::from_array2(Array2::zeros((2, 0)).view()).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn views() {
+        let array = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+        // Transposed and sliced views are not contiguous in memory
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]).unwrap(),
+            Matrix::from_array2(array.t()).unwrap()
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![3.0], vec![6.0]]).unwrap(),
+            Matrix::from_array2(array.slice(ndarray::s![.., 2..])).unwrap()
+        );
+    }
+}
@@ -0,0 +1,376 @@
+use crate::{Matrix, MatrixError};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Norm {
+    One,
+    Infinity,
+    Frobenius,
+    Two,
+}
+
+impl Matrix {
+    const CONDEST_ITERATIONS: usize = 5;
+
+    pub fn norm(&self, norm: Norm) -> f64 {
+        crate::round_result(self.unrounded_norm(norm))
+    }
+
+    pub(crate) fn unrounded_norm(&self, norm: Norm) -> f64 {
+        match norm {
+            Norm::One => (0..self.columns)
+                .map(|column| crate::compensated_sum(self.data.iter().map(|row| row[column].abs())))
+                .fold(0.0, f64::max),
+            Norm::Infinity => self
+                .data
+                .iter()
+                .map(|row| crate::compensated_sum(row.iter().map(|num| num.abs())))
+                .fold(0.0, f64::max),
+            Norm::Frobenius => {
+                crate::compensated_sum(self.data.iter().flatten().map(|num| num * num)).sqrt()
+            }
+            Norm::Two => self.singular_value_extremes().0,
+        }
+    }
+
+    // ‖A - B‖, with the difference and the norm left unrounded so that matrices agreeing to more
+    // than five decimal places still have a nonzero distance
+    pub fn distance(&self, other: &Matrix, norm: Norm) -> Result<f64, MatrixError> {
+        if self.shape() != other.shape() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.shape(),
+                actual: other.shape(),
+            });
+        }
+
+        let difference = Matrix::new_with_data(
+            self.data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(row, other_row)| row.iter().zip(other_row).map(|(a, b)| a - b).collect())
+                .collect(),
+        )
+        .unwrap();
+
+        Ok(difference.unrounded_norm(norm))
+    }
+
+    // ‖A - reference‖ / ‖reference‖ in the Frobenius norm. A zero reference gives InvalidArgument,
+    // since no error is small relative to it
+    pub fn relative_error(&self, reference: &Matrix) -> Result<f64, MatrixError> {
+        let distance = self.distance(reference, Norm::Frobenius)?;
+        match reference.unrounded_norm(Norm::Frobenius) {
+            0.0 => Err(MatrixError::InvalidArgument),
+            scale => Ok(distance / scale),
+        }
+    }
+
+    // Infinite for singular matrices, since their inverse is unbounded
+    pub fn condition_number(&self, norm: Norm) -> Result<f64, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        // Infinite exactly when `is_singular` holds
+        let Ok(inverse) = self.unrounded_inverse() else {
+            return Ok(f64::INFINITY);
+        };
+
+        let condition_number = match norm {
+            // The one-sided Jacobi SVD keeps the smallest singular value accurate, unlike the
+            // eigenvalues of AᵀA, which square the condition number
+            Norm::Two => {
+                let (singular_values, _, _) = self.singular_value_decomposition();
+                singular_values[0] / singular_values[singular_values.len() - 1]
+            }
+            // The inverse is left unrounded, since rounding would zero it for large elements
+            _ => {
+                self.unrounded_norm(norm)
+                    * Matrix::new_with_data(inverse).unwrap().unrounded_norm(norm)
+            }
+        };
+
+        Ok(crate::round_result(condition_number))
+    }
+
+    // The inverse along with its 1-norm condition number ‖A‖₁‖A⁻¹‖₁. The inverse of a matrix that
+    // only just passes the singularity test can be dominated by rounding error, and about
+    // log10 of the condition number of its significant digits are lost
+    pub fn inverse_checked(&self) -> Result<(Self, f64), MatrixError> {
+        let inverse = self.inverse()?;
+        // Rounding would zero the inverse of a matrix with large elements, so its norm comes from the
+        // unrounded solves
+        let unrounded_inverse = Matrix::new_with_data(self.unrounded_inverse()?).unwrap();
+        let condition_number =
+            self.unrounded_norm(Norm::One) * unrounded_inverse.unrounded_norm(Norm::One);
+
+        Ok((inverse, crate::round_result(condition_number)))
+    }
+
+    // An estimate of the 1-norm condition number ‖A‖₁‖A⁻¹‖₁ that never forms the inverse. Hager's
+    // method climbs towards the column of A⁻¹ with the largest 1-norm using solves with A and Aᵀ
+    // from the cached factorization, so it costs O(n²) per step instead of the O(n³) of an inverse.
+    // Higham's extra test vector guards against the rare matrices that stall the climb. The
+    // estimate never exceeds the exact value and is usually equal to it
+    pub fn condest(&self) -> Result<f64, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+        let Ok(lu) = self.nonsingular_lu(crate::current_settings().singularity_tolerance) else {
+            return Ok(f64::INFINITY);
+        };
+
+        let n = self.rows;
+        let norm = |x: &[f64]| crate::compensated_sum(x.iter().map(|num| num.abs()));
+        let mut x = vec![1.0 / n as f64; n];
+        let mut estimate = 0.0;
+        for iteration in 0..Self::CONDEST_ITERATIONS {
+            let y = lu.solve_vector(&x);
+            let y_norm = norm(&y);
+            if iteration > 0 && y_norm <= estimate {
+                break;
+            }
+            estimate = y_norm;
+
+            let signs: Vec<f64> = y
+                .iter()
+                .map(|num| if *num < 0.0 { -1.0 } else { 1.0 })
+                .collect();
+            let z = lu.solve_transpose_vector(&signs);
+            let (largest, _) =
+                z.iter()
+                    .enumerate()
+                    .fold((0, 0.0), |(j, max), (i, num)| match num.abs() > max {
+                        true => (i, num.abs()),
+                        false => (j, max),
+                    });
+            // Stop at a local maximum, where no unit vector improves on x
+            let gradient: f64 = crate::compensated_sum(z.iter().zip(&x).map(|(a, b)| a * b));
+            if iteration > 0 && z[largest].abs() <= gradient {
+                break;
+            }
+            x = vec![0.0; n];
+            x[largest] = 1.0;
+        }
+
+        if n > 1 {
+            let alternating: Vec<f64> = (0..n)
+                .map(|i| {
+                    let magnitude = 1.0 + i as f64 / (n - 1) as f64;
+                    if i % 2 == 0 {
+                        magnitude
+                    } else {
+                        -magnitude
+                    }
+                })
+                .collect();
+            let alternative = 2.0 * norm(&lu.solve_vector(&alternating)) / (3.0 * n as f64);
+            estimate = estimate.max(alternative);
+        }
+
+        Ok(crate::round_result(
+            self.unrounded_norm(Norm::One) * estimate,
+        ))
+    }
+
+    // The singular values are the square roots of the eigenvalues of AᵀA, which is formed without
+    // rounding so that the smallest ones are not lost
+    fn singular_value_extremes(&self) -> (f64, f64) {
+        // Those of a symmetric matrix are the absolute values of its eigenvalues, without squaring
+        if self.is_symmetric() {
+            let magnitudes: Vec<f64> = self
+                .symmetric_eigenvalues()
+                .iter()
+                .map(|eigenvalue| eigenvalue.abs())
+                .collect();
+            return (
+                magnitudes.iter().copied().fold(0.0, f64::max),
+                magnitudes.iter().copied().fold(f64::INFINITY, f64::min),
+            );
+        }
+
+        let vectors = if self.rows >= self.columns {
+            self.transpose().data
+        } else {
+            self.data.clone()
+        };
+        let gram = Matrix {
+            rows: vectors.len(),
+            columns: vectors.len(),
+            data: vectors
+                .iter()
+                .map(|u| {
+                    vectors
+                        .iter()
+                        .map(|v| u.iter().zip(v).map(|(x, y)| x * y).sum())
+                        .collect()
+                })
+                .collect(),
+        };
+        let eigenvalues = gram.symmetric_eigenvalues();
+
+        (
+            eigenvalues[0].max(0.0).sqrt(),
+            eigenvalues[eigenvalues.len() - 1].max(0.0).sqrt(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn norms() {
+        let matrix = Matrix::new_with_data(vec![vec![1.0, -2.0], vec![3.0, 4.0]]).unwrap();
+
+        assert_eq!(6.0, matrix.norm(Norm::One));
+        assert_eq!(7.0, matrix.norm(Norm::Infinity));
+        assert_eq!(5.47723, matrix.norm(Norm::Frobenius));
+        assert_eq!(5.11667, matrix.norm(Norm::Two));
+
+        let matrix_4x2 = &test_utils::generic_examples()[2];
+        assert_eq!(
+            matrix_4x2.norm(Norm::Two),
+            matrix_4x2.transpose().norm(Norm::Two)
+        );
+
+        // The largest absolute eigenvalue of a symmetric matrix, even a negative one
+        let symmetric = Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, -3.0]]).unwrap();
+        assert_eq!(3.82843, symmetric.norm(Norm::Two));
+    }
+
+    #[test]
+    fn condition_number() {
+        let matrix = Matrix::new_with_data(vec![vec![1.0, -2.0], vec![3.0, 4.0]]).unwrap();
+
+        assert_eq!(Ok(4.2), matrix.condition_number(Norm::One));
+        assert_eq!(Ok(2.61803), matrix.condition_number(Norm::Two));
+        assert_eq!(
+            Ok(3.0),
+            Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 1.0]])
+                .unwrap()
+                .condition_number(Norm::Two)
+        );
+        assert_eq!(
+            Ok(1.0),
+            Matrix::nth_identity(3).unwrap().condition_number(Norm::Two)
+        );
+
+        let singular = Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert_eq!(Ok(f64::INFINITY), singular.condition_number(Norm::Infinity));
+        assert_eq!(Ok(f64::INFINITY), singular.condition_number(Norm::Two));
+
+        let nearly_singular =
+            Matrix::new_with_data(vec![vec![100.0, 100.0], vec![100.0, 100.00001]]).unwrap();
+        assert!(nearly_singular
+            .condition_number(Norm::Two)
+            .is_ok_and(|number| number.is_finite() && number > 1e6));
+
+        // The inverse of 1e6·I rounds to zero, which mustn't make the condition number zero
+        let scaled_identity = Matrix::new_diagonal_matrix(&[1e6; 3]).unwrap();
+        for norm in [Norm::One, Norm::Infinity, Norm::Two] {
+            assert_eq!(Ok(1.0), scaled_identity.condition_number(norm));
+        }
+
+        // Invertible however badly it is scaled, so never infinite
+        let badly_scaled = Matrix::new_diagonal_matrix(&[1.0, 1e-9]).unwrap();
+        assert!(badly_scaled.is_invertible());
+        assert_eq!(Ok(1e9), badly_scaled.condition_number(Norm::One));
+        assert_eq!(Ok(1e9), badly_scaled.condition_number(Norm::Two));
+        assert_eq!(Ok(1e9), badly_scaled.condest());
+
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[2]
+                .condition_number(Norm::One)
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn inverse_checked() {
+        let matrix = Matrix::new_with_data(vec![vec![1.0, -2.0], vec![3.0, 4.0]]).unwrap();
+        assert_eq!(
+            (matrix.inverse().unwrap(), 4.2),
+            matrix.inverse_checked().unwrap()
+        );
+
+        let (_, condition_number) =
+            Matrix::new_with_data(vec![vec![100.0, 100.0], vec![100.0, 100.00001]])
+                .unwrap()
+                .inverse_checked()
+                .unwrap();
+        assert!(condition_number > 1e6);
+
+        // The inverse rounds to zero, but the condition number is still that of the identity
+        let scaled_identity = Matrix::new_diagonal_matrix(&[1e6; 3]).unwrap();
+        let (inverse, condition_number) = scaled_identity.inverse_checked().unwrap();
+        assert_eq!(Matrix::new_zero_matrix(3, 3).unwrap(), inverse);
+        assert_eq!(1.0, condition_number);
+
+        let singular = Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert_eq!(
+            MatrixError::SingularMatrix,
+            singular.inverse_checked().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn condest() {
+        for matrix in test_utils::square_examples() {
+            let exact = matrix.condition_number(Norm::One).unwrap();
+            let estimate = matrix.condest().unwrap();
+            assert!(estimate <= exact * (1.0 + 1e-4) && estimate >= exact / 3.0);
+        }
+
+        let matrix = Matrix::new_with_data(vec![vec![1.0, -2.0], vec![3.0, 4.0]]).unwrap();
+        assert_eq!(Ok(4.2), matrix.condest());
+
+        let singular = Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert_eq!(Ok(f64::INFINITY), singular.condest());
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[2].condest().unwrap_err()
+        );
+    }
+
+    #[test]
+    fn distance() {
+        let [matrix_2x3, another_matrix_2x3, matrix_4x2] = test_utils::generic_examples();
+        let difference = (&matrix_2x3 - &another_matrix_2x3).unwrap();
+
+        for norm in [Norm::One, Norm::Infinity, Norm::Frobenius] {
+            let distance = matrix_2x3.distance(&another_matrix_2x3, norm).unwrap();
+            assert!((difference.norm(norm) - distance).abs() < 1e-4);
+            assert_eq!(0.0, matrix_2x3.distance(&matrix_2x3, norm).unwrap());
+        }
+
+        let close = Matrix::new_with_data(vec![vec![1.0 + 1e-9]]).unwrap();
+        let one = Matrix::nth_identity(1).unwrap();
+        assert!(close.distance(&one, Norm::Two).unwrap() > 0.0);
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 3),
+                actual: (4, 2)
+            },
+            matrix_2x3.distance(&matrix_4x2, Norm::One).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn relative_error() {
+        let reference = Matrix::new_with_data(vec![vec![3.0, 0.0], vec![0.0, 4.0]]).unwrap();
+        let approximation = Matrix::new_with_data(vec![vec![3.0, 0.05], vec![0.0, 4.0]]).unwrap();
+
+        assert!((approximation.relative_error(&reference).unwrap() - 0.01).abs() < 1e-15);
+        assert_eq!(0.0, reference.relative_error(&reference).unwrap());
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            reference
+                .relative_error(&Matrix::new_zero_matrix(2, 2).unwrap())
+                .unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,430 @@
+use crate::{Matrix, MatrixError};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+// Zip signatures, for the uncompressed archives written by `numpy.savez`
+const LOCAL_HEADER: u32 = 0x0403_4b50;
+const CENTRAL_HEADER: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+// 1980-01-01, the earliest date a zip entry can have
+const DOS_DATE: u16 = (1 << 5) | 1;
+
+#[derive(Debug, PartialEq, Clone)]
+#[non_exhaustive]
+pub enum NpyError {
+    // The bytes are not a valid .npy file or .npz archive
+    InvalidFormat(String),
+    // Valid, but holding something a matrix cannot, such as complex numbers or a 3D array
+    Unsupported(String),
+    Matrix(MatrixError),
+}
+
+impl Display for NpyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            NpyError::InvalidFormat(reason) => write!(f, "invalid file: {reason}"),
+            NpyError::Unsupported(reason) => write!(f, "unsupported array: {reason}"),
+            NpyError::Matrix(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl Error for NpyError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            NpyError::Matrix(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<MatrixError> for NpyError {
+    fn from(err: MatrixError) -> Self {
+        NpyError::Matrix(err)
+    }
+}
+
+fn invalid(reason: &str) -> NpyError {
+    NpyError::InvalidFormat(reason.to_string())
+}
+
+// Reads little-endian integers of N bytes, failing when the input is too short
+fn read<const N: usize>(bytes: &[u8], offset: usize) -> Result<[u8; N], NpyError> {
+    bytes
+        .get(offset..offset + N)
+        .and_then(|slice| slice.try_into().ok())
+        .ok_or_else(|| invalid("unexpected end of data"))
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Result<usize, NpyError> {
+    Ok(u16::from_le_bytes(read(bytes, offset)?) as usize)
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Result<usize, NpyError> {
+    Ok(u32::from_le_bytes(read(bytes, offset)?) as usize)
+}
+
+// The value of `key` in the header, a Python dict literal such as
+// {'descr': '<f8', 'fortran_order': False, 'shape': (2, 3), }
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let start = header
+        .find(&format!("'{key}'"))
+        .ok_or_else(|| invalid(&format!("the header has no {key}")))?;
+    let rest = header[start + key.len() + 2..]
+        .trim_start()
+        .strip_prefix(':')
+        .ok_or_else(|| invalid("malformed header"))?
+        .trim_start();
+
+    let end = if rest.starts_with('(') {
+        rest.find(')').map(|end| end + 1)
+    } else if let Some(quoted) = rest.strip_prefix('\'') {
+        quoted.find('\'').map(|end| end + 2)
+    } else {
+        rest.find([',', '}'])
+    };
+    end.map(|end| rest[..end].trim())
+        .ok_or_else(|| invalid("malformed header"))
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, byte| {
+        (0..8).fold(crc ^ *byte as u32, |crc, _| {
+            if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            }
+        })
+    })
+}
+
+impl Matrix {
+    // Accepts 1D arrays as row vectors and 2D arrays of floating-point or integer elements, in
+    // either byte order and in C or Fortran order
+    pub fn from_npy(bytes: &[u8]) -> Result<Self, NpyError> {
+        if !bytes.starts_with(MAGIC) {
+            return Err(invalid("not a .npy file"));
+        }
+
+        let (header_start, header_length) = match read::<1>(bytes, 6)?[0] {
+            1 => (10, read_u16(bytes, 8)?),
+            2 | 3 => (12, read_u32(bytes, 8)?),
+            version => return Err(NpyError::Unsupported(format!("format version {version}"))),
+        };
+        let header = bytes
+            .get(header_start..header_start + header_length)
+            .and_then(|header| std::str::from_utf8(header).ok())
+            .ok_or_else(|| invalid("malformed header"))?;
+
+        let descr = header_value(header, "descr")?.trim_matches('\'');
+        let fortran_order = match header_value(header, "fortran_order")? {
+            "False" => false,
+            "True" => true,
+            _ => return Err(invalid("malformed header")),
+        };
+        let shape = header_value(header, "shape")?
+            .trim_matches(['(', ')'])
+            .split(',')
+            .map(str::trim)
+            .filter(|dimension| !dimension.is_empty())
+            .map(|dimension| {
+                dimension
+                    .parse::<usize>()
+                    .map_err(|_| invalid("malformed shape"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let (rows, columns) = match shape[..] {
+            [length] => (1, length),
+            [rows, columns] => (rows, columns),
+            _ => {
+                return Err(NpyError::Unsupported(format!(
+                    "{} dimensions, but a matrix has 2",
+                    shape.len()
+                )))
+            }
+        };
+        if rows == 0 || columns == 0 {
+            return Err(MatrixError::ZeroDimension.into());
+        }
+
+        let (order, kind, size) = (
+            &descr[..1.min(descr.len())],
+            descr.get(1..2).unwrap_or_default(),
+            descr.get(2..).and_then(|size| size.parse::<usize>().ok()),
+        );
+        let big_endian = match order {
+            "<" | "|" | "=" => false,
+            ">" => true,
+            _ => return Err(invalid("malformed data type")),
+        };
+        let decode: fn(&[u8]) -> f64 = match (kind, size) {
+            ("f", Some(8)) => |b| f64::from_le_bytes(b.try_into().unwrap()),
+            ("f", Some(4)) => |b| f32::from_le_bytes(b.try_into().unwrap()) as f64,
+            ("i", Some(8)) => |b| i64::from_le_bytes(b.try_into().unwrap()) as f64,
+            ("i", Some(4)) => |b| i32::from_le_bytes(b.try_into().unwrap()) as f64,
+            ("i", Some(2)) => |b| i16::from_le_bytes(b.try_into().unwrap()) as f64,
+            ("i", Some(1)) => |b| b[0] as i8 as f64,
+            ("u", Some(8)) => |b| u64::from_le_bytes(b.try_into().unwrap()) as f64,
+            ("u", Some(4)) => |b| u32::from_le_bytes(b.try_into().unwrap()) as f64,
+            ("u", Some(2)) => |b| u16::from_le_bytes(b.try_into().unwrap()) as f64,
+            ("u", Some(1)) => |b| b[0] as f64,
+            _ => return Err(NpyError::Unsupported(format!("elements of type {descr}"))),
+        };
+        let size = size.unwrap();
+
+        // The shape comes from the file, so a crafted one mustn't overflow the length
+        let length = rows
+            .checked_mul(columns)
+            .and_then(|elements| elements.checked_mul(size))
+            .ok_or_else(|| invalid("shape too large"))?;
+        let elements = bytes
+            .get(header_start + header_length..)
+            .and_then(|data| data.get(..length))
+            .ok_or_else(|| invalid("unexpected end of data"))?;
+        let values: Vec<f64> = elements
+            .chunks_exact(size)
+            .map(|chunk| {
+                let mut chunk = chunk.to_vec();
+                if big_endian {
+                    chunk.reverse();
+                }
+                decode(&chunk)
+            })
+            .collect();
+
+        Matrix::new_with_data(
+            (0..rows)
+                .map(|row| {
+                    (0..columns)
+                        .map(|column| {
+                            if fortran_order {
+                                values[column * rows + row]
+                            } else {
+                                values[row * columns + column]
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+        .map_err(NpyError::from)
+    }
+
+    // Little-endian 64-bit floats in C order, as written by `numpy.save`
+    pub fn to_npy(&self) -> Vec<u8> {
+        let mut header = format!(
+            "{{'descr': '<f8', 'fortran_order': False, 'shape': ({}, {}), }}",
+            self.rows, self.columns
+        );
+        // The header ends in a newline, padded so that the data starts at a multiple of 64 bytes
+        let padding = 63 - (MAGIC.len() + 4 + header.len()) % 64;
+        header.push_str(&" ".repeat(padding));
+        header.push('\n');
+
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend([1, 0]);
+        bytes.extend((header.len() as u16).to_le_bytes());
+        bytes.extend(header.as_bytes());
+        for num in self.data.iter().flatten() {
+            bytes.extend(num.to_le_bytes());
+        }
+        bytes
+    }
+
+    // The arrays of an archive written by `numpy.savez`, named without the .npy extension.
+    // Archives from `numpy.savez_compressed` are not supported
+    pub fn from_npz(bytes: &[u8]) -> Result<Vec<(String, Self)>, NpyError> {
+        let end = (0..bytes.len().saturating_sub(21))
+            .rev()
+            .find(|offset| {
+                read_u32(bytes, *offset).is_ok_and(|sig| sig == END_OF_CENTRAL_DIRECTORY as usize)
+            })
+            .ok_or_else(|| invalid("not a .npz archive"))?;
+        let count = read_u16(bytes, end + 10)?;
+        let mut offset = read_u32(bytes, end + 16)?;
+
+        let mut matrices = Vec::with_capacity(count);
+        for _ in 0..count {
+            if read_u32(bytes, offset)? != CENTRAL_HEADER as usize {
+                return Err(invalid("malformed archive"));
+            }
+            let method = read_u16(bytes, offset + 10)?;
+            let compressed_size = read_u32(bytes, offset + 20)?;
+            let name_length = read_u16(bytes, offset + 28)?;
+            let extra_length = read_u16(bytes, offset + 30)?;
+            let comment_length = read_u16(bytes, offset + 32)?;
+            let local_offset = read_u32(bytes, offset + 42)?;
+            let name = bytes
+                .get(offset + 46..offset + 46 + name_length)
+                .map(String::from_utf8_lossy)
+                .ok_or_else(|| invalid("unexpected end of data"))?;
+            offset += 46 + name_length + extra_length + comment_length;
+
+            if method != 0 {
+                return Err(NpyError::Unsupported(format!(
+                    "{name} is compressed, save the arrays with numpy.savez instead"
+                )));
+            }
+            if read_u32(bytes, local_offset)? != LOCAL_HEADER as usize {
+                return Err(invalid("malformed archive"));
+            }
+            let data_start = local_offset
+                + 30
+                + read_u16(bytes, local_offset + 26)?
+                + read_u16(bytes, local_offset + 28)?;
+            let data = bytes
+                .get(data_start..data_start + compressed_size)
+                .ok_or_else(|| invalid("unexpected end of data"))?;
+
+            let name = name.strip_suffix(".npy").unwrap_or(&name).to_string();
+            matrices.push((name, Matrix::from_npy(data)?));
+        }
+
+        Ok(matrices)
+    }
+
+    // An uncompressed archive that `numpy.load` reads as a dict of arrays under the given names
+    pub fn to_npz(matrices: &[(&str, &Matrix)]) -> Vec<u8> {
+        let mut bytes = vec![];
+        let mut central_directory = vec![];
+
+        for (name, matrix) in matrices {
+            let name = format!("{name}.npy");
+            let data = matrix.to_npy();
+            let crc = crc32(&data);
+            let local_offset = bytes.len() as u32;
+
+            // Fields shared by the local and central headers, from the version needed to the extra field length
+            let mut common = vec![];
+            common.extend(20u16.to_le_bytes());
+            common.extend(0u16.to_le_bytes());
+            common.extend(0u16.to_le_bytes());
+            common.extend(0u16.to_le_bytes());
+            common.extend(DOS_DATE.to_le_bytes());
+            common.extend(crc.to_le_bytes());
+            common.extend((data.len() as u32).to_le_bytes());
+            common.extend((data.len() as u32).to_le_bytes());
+            common.extend((name.len() as u16).to_le_bytes());
+            common.extend(0u16.to_le_bytes());
+
+            bytes.extend(LOCAL_HEADER.to_le_bytes());
+            bytes.extend(&common);
+            bytes.extend(name.as_bytes());
+            bytes.extend(&data);
+
+            central_directory.extend(CENTRAL_HEADER.to_le_bytes());
+            central_directory.extend(20u16.to_le_bytes());
+            central_directory.extend(&common);
+            // Comment length, disk number, internal and external attributes
+            central_directory.extend([0; 10]);
+            central_directory.extend(local_offset.to_le_bytes());
+            central_directory.extend(name.as_bytes());
+        }
+
+        let central_offset = bytes.len() as u32;
+        bytes.extend(&central_directory);
+        bytes.extend(END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+        bytes.extend([0; 4]);
+        bytes.extend((matrices.len() as u16).to_le_bytes());
+        bytes.extend((matrices.len() as u16).to_le_bytes());
+        bytes.extend((central_directory.len() as u32).to_le_bytes());
+        bytes.extend(central_offset.to_le_bytes());
+        bytes.extend(0u16.to_le_bytes());
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn npy_round_trip() {
+        for matrix in test_utils::generic_examples() {
+            let bytes = matrix.to_npy();
+            assert_eq!(0, (bytes.len() - matrix.rows * matrix.columns * 8) % 64);
+            assert_eq!(matrix, Matrix::from_npy(&bytes).unwrap());
+        }
+    }
+
+    #[test]
+    fn npy_element_types() {
+        let header = |descr: &str, fortran_order: &str, shape: &str| {
+            let header = format!(
+                "{{'descr': '{descr}', 'fortran_order': {fortran_order}, 'shape': {shape}, }}\n"
+            );
+            let mut bytes = MAGIC.to_vec();
+            bytes.extend([1, 0]);
+            bytes.extend((header.len() as u16).to_le_bytes());
+            bytes.extend(header.as_bytes());
+            bytes
+        };
+
+        let mut fortran_ints = header("<i4", "True", "(2, 2)");
+        for num in [1i32, 3, 2, -4] {
+            fortran_ints.extend(num.to_le_bytes());
+        }
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1.0, 2.0], vec![3.0, -4.0]]).unwrap(),
+            Matrix::from_npy(&fortran_ints).unwrap()
+        );
+
+        let mut big_endian_vector = header(">f4", "False", "(3,)");
+        for num in [0.5f32, 1.5, 2.5] {
+            big_endian_vector.extend(num.to_be_bytes());
+        }
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![0.5, 1.5, 2.5]]).unwrap(),
+            Matrix::from_npy(&big_endian_vector).unwrap()
+        );
+
+        assert!(matches!(
+            Matrix::from_npy(&header("<c16", "False", "(1, 1)")),
+            Err(NpyError::Unsupported(_))
+        ));
+        assert!(matches!(
+            Matrix::from_npy(&header("<f8", "False", "(2, 2, 2)")),
+            Err(NpyError::Unsupported(_))
+        ));
+        assert!(matches!(
+            Matrix::from_npy(&header("<f8", "False", "(2, 2)")),
+            Err(NpyError::InvalidFormat(_))
+        ));
+        for shape in ["(4294967296, 4294967296)", "(2305843009213693952,)"] {
+            assert!(matches!(
+                Matrix::from_npy(&header("<f8", "False", shape)),
+                Err(NpyError::InvalidFormat(_))
+            ));
+        }
+        assert!(matches!(
+            Matrix::from_npy(b"1,2\n3,4"),
+            Err(NpyError::InvalidFormat(_))
+        ));
+    }
+
+    #[test]
+    fn npz_round_trip() {
+        let [first, second, third] = test_utils::generic_examples();
+        let bytes = Matrix::to_npz(&[("a", &first), ("b", &second), ("c", &third)]);
+
+        assert_eq!(
+            vec![
+                ("a".to_string(), first),
+                ("b".to_string(), second),
+                ("c".to_string(), third)
+            ],
+            Matrix::from_npz(&bytes).unwrap()
+        );
+        assert_eq!(0xCBF4_3926, crc32(b"123456789"));
+        assert!(matches!(
+            Matrix::from_npz(&[0; 30]),
+            Err(NpyError::InvalidFormat(_))
+        ));
+    }
+}
@@ -1,10 +1,14 @@
-use crate::{Matrix, MatrixError};
+use crate::{
+    trace::{self, Span},
+    Matrix, MatrixError,
+};
 use std::ops::{Add, Mul, Neg, Sub};
 
 impl Add for &Matrix {
     type Output = Result<Matrix, MatrixError>;
 
     fn add(self, rhs: Self) -> Self::Output {
+        let _span = Span::new("add", &[self.shape(), rhs.shape()], trace::elements(self));
         self.element_wise_operation(rhs, |a, b| a + b)
     }
 }
@@ -13,6 +17,11 @@ impl Sub for &Matrix {
     type Output = Result<Matrix, MatrixError>;
 
     fn sub(self, rhs: Self) -> Self::Output {
+        let _span = Span::new(
+            "subtract",
+            &[self.shape(), rhs.shape()],
+            trace::elements(self),
+        );
         self.element_wise_operation(rhs, |a, b| a - b)
     }
 }
@@ -21,18 +30,25 @@ impl Mul for &Matrix {
     type Output = Result<Matrix, MatrixError>;
 
     fn mul(self, rhs: Self) -> Self::Output {
+        let _span = Span::new(
+            "multiply",
+            &[self.shape(), rhs.shape()],
+            2 * trace::elements(self) * rhs.columns as u64,
+        );
         if self.columns != rhs.rows {
-            return Err(MatrixError::DimensionMismatch);
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.columns, rhs.columns),
+                actual: rhs.shape(),
+            });
         }
 
         let mut result_matrix = Matrix::new_zero_matrix(self.rows, rhs.columns).unwrap();
         for i in 0..result_matrix.rows {
             for j in 0..result_matrix.columns {
-                let mut sum = 0.0;
-                for k in 0..self.columns {
-                    sum += self.data[i][k] * rhs.data[k][j]
-                }
-                result_matrix.data[i][j] = crate::round_to_five(sum);
+                let sum = crate::compensated_sum(
+                    (0..self.columns).map(|k| self.data[i][k] * rhs.data[k][j]),
+                );
+                result_matrix.data[i][j] = crate::round_result(sum);
             }
         }
 
@@ -52,16 +68,12 @@ impl Mul<&Matrix> for f64 {
     type Output = Matrix;
 
     fn mul(self, rhs: &Matrix) -> Self::Output {
-        Matrix::new_with_data(
-            rhs.data
+        Matrix::new_with_data(rhs.rows_from(|row| {
+            rhs.data[row]
                 .iter()
-                .map(|row| {
-                    row.iter()
-                        .map(|num| crate::round_to_five(self * num))
-                        .collect()
-                })
-                .collect(),
-        )
+                .map(|num| crate::round_result(self * num))
+                .collect()
+        }))
         .unwrap()
     }
 }
@@ -80,7 +92,10 @@ mod tests {
             (&matrix_2x3 + &another_matrix_2x3).unwrap()
         );
         assert_eq!(
-            MatrixError::DimensionMismatch,
+            MatrixError::DimensionMismatch {
+                expected: (2, 3),
+                actual: (4, 2)
+            },
             (&matrix_2x3 + &matrix_4x2).unwrap_err()
         );
     }
@@ -94,7 +109,10 @@ mod tests {
             (&matrix_2x3 - &another_matrix_2x3).unwrap()
         );
         assert_eq!(
-            MatrixError::DimensionMismatch,
+            MatrixError::DimensionMismatch {
+                expected: (2, 3),
+                actual: (4, 2)
+            },
             (&matrix_2x3 - &matrix_4x2).unwrap_err()
         );
     }
@@ -130,7 +148,10 @@ mod tests {
         );
 
         assert_eq!(
-            MatrixError::DimensionMismatch,
+            MatrixError::DimensionMismatch {
+                expected: (3, 2),
+                actual: (4, 2)
+            },
             (&matrix_2x3 * &matrix_4x2).unwrap_err()
         );
     }
@@ -0,0 +1,366 @@
+use crate::{Matrix, MatrixError};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Triangle {
+    Lower,
+    Upper,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct TriangularMatrix {
+    size: usize,
+    triangle: Triangle,
+    data: Vec<f64>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SymmetricMatrix {
+    size: usize,
+    data: Vec<f64>,
+}
+
+// Row-major packing of the lower triangle, so row i starts after 1 + 2 + ... + i elements
+fn lower_index(row: usize, column: usize) -> usize {
+    row * (row + 1) / 2 + column
+}
+
+fn upper_index(size: usize, row: usize, column: usize) -> usize {
+    row * size - row * (row.saturating_sub(1)) / 2 + (column - row)
+}
+
+impl TriangularMatrix {
+    pub fn from_matrix(matrix: &Matrix, triangle: Triangle) -> Result<Self, MatrixError> {
+        if !matrix.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        let size = matrix.rows;
+        let data = (0..size)
+            .flat_map(|row| {
+                let columns = match triangle {
+                    Triangle::Lower => 0..(row + 1),
+                    Triangle::Upper => row..size,
+                };
+                columns.map(move |column| matrix.data[row][column])
+            })
+            .collect();
+
+        Ok(Self {
+            size,
+            triangle,
+            data,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn triangle(&self) -> Triangle {
+        self.triangle
+    }
+
+    pub fn get_element(&self, row: usize, column: usize) -> Result<f64, MatrixError> {
+        crate::check_index(row, self.size)?;
+        crate::check_index(column, self.size)?;
+
+        Ok(self.element(row, column))
+    }
+
+    fn element(&self, row: usize, column: usize) -> f64 {
+        match self.triangle {
+            Triangle::Lower if column <= row => self.data[lower_index(row, column)],
+            Triangle::Upper if column >= row => self.data[upper_index(self.size, row, column)],
+            _ => 0.0,
+        }
+    }
+
+    fn stored_columns(&self, row: usize) -> std::ops::Range<usize> {
+        match self.triangle {
+            Triangle::Lower => 0..(row + 1),
+            Triangle::Upper => row..self.size,
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix {
+        let mut matrix = Matrix::new_zero_matrix(self.size, self.size).unwrap();
+        for row in 0..self.size {
+            for column in self.stored_columns(row) {
+                matrix.data[row][column] = self.element(row, column);
+            }
+        }
+
+        matrix
+    }
+
+    pub fn multiply(&self, rhs: &Matrix) -> Result<Matrix, MatrixError> {
+        if self.size != rhs.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.size, rhs.columns),
+                actual: rhs.shape(),
+            });
+        }
+
+        let mut product = Matrix::new_zero_matrix(self.size, rhs.columns).unwrap();
+        for row in 0..self.size {
+            for column in 0..rhs.columns {
+                let sum = crate::compensated_sum(
+                    self.stored_columns(row)
+                        .map(|k| self.element(row, k) * rhs.data[k][column]),
+                );
+                product.data[row][column] = crate::round_result(sum);
+            }
+        }
+
+        Ok(product)
+    }
+
+    pub fn solve(&self, b: &Matrix) -> Result<Matrix, MatrixError> {
+        if self.size != b.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.size, b.columns),
+                actual: b.shape(),
+            });
+        }
+
+        if (0..self.size).any(|i| self.element(i, i) == 0.0) {
+            return Err(MatrixError::SingularMatrix);
+        }
+
+        let order: Vec<usize> = match self.triangle {
+            Triangle::Lower => (0..self.size).collect(),
+            Triangle::Upper => (0..self.size).rev().collect(),
+        };
+
+        let mut solution = Matrix::new_zero_matrix(self.size, b.columns).unwrap();
+        for column in 0..b.columns {
+            for &row in &order {
+                let known = crate::compensated_sum(
+                    self.stored_columns(row)
+                        .filter(|k| *k != row)
+                        .map(|k| self.element(row, k) * solution.data[k][column]),
+                );
+                solution.data[row][column] = (b.data[row][column] - known) / self.element(row, row);
+            }
+        }
+
+        solution.data.iter_mut().for_each(|row| {
+            row.iter_mut()
+                .for_each(|num| *num = crate::round_result(*num))
+        });
+        Ok(solution)
+    }
+}
+
+impl SymmetricMatrix {
+    pub fn from_matrix(matrix: &Matrix) -> Result<Self, MatrixError> {
+        if !matrix.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        if !matrix.is_symmetric() {
+            return Err(MatrixError::NonSymmetricMatrix);
+        }
+
+        Ok(Self {
+            size: matrix.rows,
+            data: (0..matrix.rows)
+                .flat_map(|row| (0..(row + 1)).map(move |column| matrix.data[row][column]))
+                .collect(),
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn get_element(&self, row: usize, column: usize) -> Result<f64, MatrixError> {
+        crate::check_index(row, self.size)?;
+        crate::check_index(column, self.size)?;
+
+        Ok(self.element(row, column))
+    }
+
+    fn element(&self, row: usize, column: usize) -> f64 {
+        if column <= row {
+            self.data[lower_index(row, column)]
+        } else {
+            self.data[lower_index(column, row)]
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix {
+        let mut matrix = Matrix::new_zero_matrix(self.size, self.size).unwrap();
+        for row in 0..self.size {
+            for column in 0..self.size {
+                matrix.data[row][column] = self.element(row, column);
+            }
+        }
+
+        matrix
+    }
+
+    pub fn multiply(&self, rhs: &Matrix) -> Result<Matrix, MatrixError> {
+        if self.size != rhs.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.size, rhs.columns),
+                actual: rhs.shape(),
+            });
+        }
+
+        let mut product = Matrix::new_zero_matrix(self.size, rhs.columns).unwrap();
+        for row in 0..self.size {
+            for column in 0..rhs.columns {
+                let sum = crate::compensated_sum(
+                    (0..self.size).map(|k| self.element(row, k) * rhs.data[k][column]),
+                );
+                product.data[row][column] = crate::round_result(sum);
+            }
+        }
+
+        Ok(product)
+    }
+}
+
+impl From<&TriangularMatrix> for Matrix {
+    fn from(triangular: &TriangularMatrix) -> Self {
+        triangular.to_matrix()
+    }
+}
+
+impl From<&SymmetricMatrix> for Matrix {
+    fn from(symmetric: &SymmetricMatrix) -> Self {
+        symmetric.to_matrix()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn triangular_conversion() {
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            TriangularMatrix::from_matrix(&test_utils::generic_examples()[0], Triangle::Lower)
+                .unwrap_err()
+        );
+
+        let matrix_3x3 = &test_utils::square_examples()[2];
+        let lower = TriangularMatrix::from_matrix(matrix_3x3, Triangle::Lower).unwrap();
+        assert_eq!(vec![2.1, 8.4, 1.6, 5.9, 12.3, 0.8], lower.data);
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                vec![2.1, 0.0, 0.0],
+                vec![8.4, 1.6, 0.0],
+                vec![5.9, 12.3, 0.8],
+            ])
+            .unwrap(),
+            lower.to_matrix()
+        );
+
+        let upper = TriangularMatrix::from_matrix(matrix_3x3, Triangle::Upper).unwrap();
+        assert_eq!(vec![2.1, 9.7, 3.5, 1.6, 7.2, 0.8], upper.data);
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                vec![2.1, 9.7, 3.5],
+                vec![0.0, 1.6, 7.2],
+                vec![0.0, 0.0, 0.8],
+            ])
+            .unwrap(),
+            Matrix::from(&upper)
+        );
+
+        assert_eq!(7.2, upper.get_element(1, 2).unwrap());
+        assert_eq!(0.0, upper.get_element(2, 1).unwrap());
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 3, len: 3 },
+            upper.get_element(3, 1).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn triangular_multiply() {
+        let matrix_3x3 = &test_utils::square_examples()[2];
+        let [_, _, matrix_4x2] = test_utils::generic_examples();
+
+        for triangle in [Triangle::Lower, Triangle::Upper] {
+            let triangular = TriangularMatrix::from_matrix(matrix_3x3, triangle).unwrap();
+            assert_eq!(
+                (&triangular.to_matrix() * matrix_3x3).unwrap(),
+                triangular.multiply(matrix_3x3).unwrap()
+            );
+            assert_eq!(
+                MatrixError::DimensionMismatch {
+                    expected: (3, 2),
+                    actual: (4, 2)
+                },
+                triangular.multiply(&matrix_4x2).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn triangular_solve() {
+        let lower = TriangularMatrix::from_matrix(
+            &Matrix::new_with_data(vec![vec![2.0, 0.0], vec![1.0, 4.0]]).unwrap(),
+            Triangle::Lower,
+        )
+        .unwrap();
+        let b = Matrix::new_with_data(vec![vec![4.0, 2.0], vec![10.0, 5.0]]).unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![2.0, 1.0], vec![2.0, 1.0]]).unwrap(),
+            lower.solve(&b).unwrap()
+        );
+
+        let upper = TriangularMatrix::from_matrix(
+            &Matrix::new_with_data(vec![vec![2.0, 1.0], vec![0.0, 4.0]]).unwrap(),
+            Triangle::Upper,
+        )
+        .unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![0.75, 0.375], vec![2.5, 1.25]]).unwrap(),
+            upper.solve(&b).unwrap()
+        );
+
+        let singular = TriangularMatrix::from_matrix(
+            &Matrix::new_with_data(vec![vec![0.0, 1.0], vec![0.0, 4.0]]).unwrap(),
+            Triangle::Upper,
+        )
+        .unwrap();
+        assert_eq!(MatrixError::SingularMatrix, singular.solve(&b).unwrap_err());
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 2),
+                actual: (4, 2)
+            },
+            upper.solve(&test_utils::generic_examples()[2]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn symmetric() {
+        assert_eq!(
+            MatrixError::NonSymmetricMatrix,
+            SymmetricMatrix::from_matrix(&test_utils::square_examples()[2]).unwrap_err()
+        );
+
+        let matrix = Matrix::new_with_data(vec![
+            vec![9.5, 2.3, 3.5],
+            vec![2.3, -1.0, -8.5],
+            vec![3.5, -8.5, 0.0],
+        ])
+        .unwrap();
+        let symmetric = SymmetricMatrix::from_matrix(&matrix).unwrap();
+        assert_eq!(6, symmetric.data.len());
+        assert_eq!(matrix, Matrix::from(&symmetric));
+        assert_eq!(-8.5, symmetric.get_element(1, 2).unwrap());
+
+        let matrix_3x3 = &test_utils::square_examples()[2];
+        assert_eq!(
+            (&matrix * matrix_3x3).unwrap(),
+            symmetric.multiply(matrix_3x3).unwrap()
+        );
+    }
+}
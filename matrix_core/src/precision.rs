@@ -0,0 +1,97 @@
+use crate::{current_settings, with_settings, Settings};
+
+// How the result of every operation is rounded before it is returned
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Precision {
+    // A fixed number of decimal places, five by default
+    Decimals(u32),
+    // A number of significant digits, which simulates a machine with a shorter mantissa so that
+    // the growth of rounding error through a computation becomes visible. At least one digit is
+    // always kept
+    SignificantDigits(u32),
+}
+
+impl Default for Precision {
+    fn default() -> Self {
+        Precision::Decimals(5)
+    }
+}
+
+// Runs `computation` with every result on this thread rounded to `precision`, such as
+// `with_precision(Precision::SignificantDigits(3), || a.inverse())`, keeping the other settings
+pub fn with_precision<T>(precision: Precision, computation: impl FnOnce() -> T) -> T {
+    let settings = Settings {
+        precision,
+        ..current_settings()
+    };
+    with_settings(settings, computation)
+}
+
+pub fn current_precision() -> Precision {
+    current_settings().precision
+}
+
+fn round_to_significant(num: f64, digits: u32) -> f64 {
+    if num == 0.0 || !num.is_finite() {
+        return num;
+    }
+
+    let decimals = digits.max(1) as i32 - 1 - num.abs().log10().floor() as i32;
+    if decimals >= 0 {
+        crate::round_to(num, decimals as u32)
+    } else {
+        let scale = 10f64.powi(-decimals);
+        (num / scale).round() * scale
+    }
+}
+
+pub(crate) fn round(num: f64) -> f64 {
+    match current_precision() {
+        Precision::Decimals(decimals) => crate::round_to(num, decimals),
+        Precision::SignificantDigits(digits) => round_to_significant(num, digits),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils, Matrix};
+
+    #[test]
+    fn round_to_significant() {
+        assert_eq!(123.0, super::round_to_significant(123.456, 3));
+        assert_eq!(0.00123, super::round_to_significant(0.0012345, 3));
+        assert_eq!(-1200.0, super::round_to_significant(-1234.5, 2));
+        assert_eq!(100.0, super::round_to_significant(99.96, 3));
+        assert_eq!(2.0, super::round_to_significant(1.5, 0));
+        assert_eq!(0.0, super::round_to_significant(0.0, 3));
+        assert!(super::round_to_significant(f64::NAN, 3).is_nan());
+    }
+
+    #[test]
+    fn with_precision() {
+        let matrix = &test_utils::square_examples()[1];
+        let inverse = matrix.inverse().unwrap();
+        assert_eq!(Ok(0.25273), inverse.get_element(0, 0));
+
+        let coarse = super::with_precision(Precision::SignificantDigits(2), || {
+            assert_eq!(Precision::SignificantDigits(2), current_precision());
+            matrix.inverse().unwrap()
+        });
+        assert_eq!(Ok(0.25), coarse.get_element(0, 0));
+        assert_eq!(Precision::default(), current_precision());
+
+        // Results already cached at the default precision are not reused
+        let determinant = matrix.determinant().unwrap();
+        assert_eq!(
+            Ok(27.0),
+            super::with_precision(Precision::SignificantDigits(2), || matrix.determinant())
+        );
+        assert_eq!(Ok(determinant), matrix.determinant());
+
+        let product = super::with_precision(Precision::Decimals(1), || {
+            (&Matrix::nth_identity(2).unwrap() * &inverse).unwrap()
+        });
+        assert_eq!(Ok(0.3), product.get_element(0, 0));
+    }
+}
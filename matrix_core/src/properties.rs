@@ -0,0 +1,417 @@
+use crate::{FormatOptions, Matrix};
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Property {
+    Square,
+    Symmetric,
+    SkewSymmetric,
+    Diagonal,
+    Scalar,
+    Identity,
+    Zero,
+    Singular,
+}
+
+impl Property {
+    // The order every report lists them in
+    pub const ALL: [Property; 8] = [
+        Property::Square,
+        Property::Symmetric,
+        Property::SkewSymmetric,
+        Property::Diagonal,
+        Property::Scalar,
+        Property::Identity,
+        Property::Zero,
+        Property::Singular,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Property::Square => "Is Square",
+            Property::Symmetric => "Is Symmetric",
+            Property::SkewSymmetric => "Is Skew Symmetric",
+            Property::Diagonal => "Is Diagonal",
+            Property::Scalar => "Is Scalar",
+            Property::Identity => "Is Identity",
+            Property::Zero => "Is Zero",
+            Property::Singular => "Is Singular",
+        }
+    }
+
+    pub fn holds_for(self, matrix: &Matrix) -> bool {
+        match self {
+            Property::Square => matrix.is_square(),
+            Property::Symmetric => matrix.is_symmetric(),
+            Property::SkewSymmetric => matrix.is_skew_symmetric(),
+            Property::Diagonal => matrix.is_diagonal(),
+            Property::Scalar => matrix.is_scalar(),
+            Property::Identity => matrix.is_identity(),
+            Property::Zero => matrix.is_zero(),
+            Property::Singular => matrix.is_singular(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PropertyValue {
+    Flag(bool),
+    Count(usize),
+    Number(f64),
+}
+
+// Named results in a fixed order, one per line when displayed
+#[derive(Debug, PartialEq, Clone)]
+pub struct PropertyReport {
+    entries: Vec<(&'static str, PropertyValue)>,
+}
+
+impl PropertyReport {
+    pub fn entries(&self) -> &[(&'static str, PropertyValue)] {
+        &self.entries
+    }
+
+    pub fn get(&self, name: &str) -> Option<PropertyValue> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| *entry == name)
+            .map(|(_, value)| *value)
+    }
+
+    pub fn formatted(&self, options: FormatOptions) -> FormattedReport<'_> {
+        FormattedReport {
+            report: self,
+            options,
+        }
+    }
+}
+
+impl Display for PropertyReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.formatted(FormatOptions::default()))
+    }
+}
+
+pub struct FormattedReport<'a> {
+    report: &'a PropertyReport,
+    options: FormatOptions,
+}
+
+impl Display for FormattedReport<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (name, value) in &self.report.entries {
+            let value = match value {
+                PropertyValue::Flag(true) => "✅".to_string(),
+                PropertyValue::Flag(false) => "❌".to_string(),
+                PropertyValue::Count(count) => count.to_string(),
+                PropertyValue::Number(num) => self.options.format_number(*num),
+            };
+            writeln!(f, "{name}: {value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Matrix {
+    // The relative pivot size below which `is_singular` and `inverse` treat a matrix as singular,
+    // unless the settings give another
+    pub const SINGULARITY_TOLERANCE: f64 = 1e-10;
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+
+    pub fn is_symmetric(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+        self == &self.transpose()
+    }
+
+    pub fn is_skew_symmetric(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+        *self == -&self.transpose()
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        self.data.iter().enumerate().all(|(row_index, row)| {
+            row.iter()
+                .enumerate()
+                .all(|(column_index, num)| *num == 0.0 || row_index == column_index)
+        })
+    }
+
+    pub fn is_scalar(&self) -> bool {
+        if !self.is_square() || self.data.is_empty() {
+            return false;
+        }
+
+        let first_element = self.data[0][0];
+        self.data.iter().enumerate().all(|(row_index, row)| {
+            row.iter().enumerate().all(|(column_index, num)| {
+                *num == if row_index == column_index {
+                    first_element
+                } else {
+                    0.0
+                }
+            })
+        })
+    }
+
+    pub fn is_identity(&self) -> bool {
+        if self.is_scalar() && !self.data.is_empty() {
+            self.data[0][0] == 1.0
+        } else {
+            false
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.data
+            .iter()
+            .all(|row| row.iter().all(|num| *num == 0.0))
+    }
+
+    // Only the given properties, in the order of Property::ALL
+    pub fn check_properties(&self, properties: &[Property]) -> PropertyReport {
+        PropertyReport {
+            entries: Property::ALL
+                .into_iter()
+                .filter(|property| properties.contains(property))
+                .map(|property| {
+                    (
+                        property.label(),
+                        PropertyValue::Flag(property.holds_for(self)),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    // Every property followed by the rank, and the determinant and trace of a square matrix
+    pub fn property_report(&self) -> PropertyReport {
+        let mut report = self.check_properties(&Property::ALL);
+        report
+            .entries
+            .push(("Rank", PropertyValue::Count(self.rank())));
+        if let Ok(determinant) = self.determinant() {
+            report
+                .entries
+                .push(("Determinant", PropertyValue::Number(determinant)));
+        }
+        if let Ok(trace) = self.trace() {
+            report.entries.push(("Trace", PropertyValue::Number(trace)));
+        }
+        report
+    }
+
+    pub fn is_singular(&self) -> bool {
+        self.is_singular_within(crate::current_settings().singularity_tolerance)
+    }
+
+    // The determinant is a poor test, since it scales with the n-th power of the elements
+    pub fn is_singular_within(&self, tolerance: f64) -> bool {
+        self.is_square() && self.nonsingular_lu(tolerance).is_err()
+    }
+
+    // Not the same as !is_singular(), since a matrix that isn't square is neither
+    pub fn is_invertible(&self) -> bool {
+        self.is_invertible_within(crate::current_settings().singularity_tolerance)
+    }
+
+    // Both tests read the same cached elimination, so asking both costs one decomposition
+    pub fn is_invertible_within(&self, tolerance: f64) -> bool {
+        self.is_square() && self.nonsingular_lu(tolerance).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn square() {
+        assert!(!test_utils::generic_examples()[0].is_square());
+        assert!(test_utils::square_examples()
+            .iter()
+            .all(|matrix| matrix.is_square()));
+    }
+
+    #[test]
+    fn symmetric() {
+        assert!(!test_utils::square_examples()[2].is_symmetric());
+
+        let symmetric_matrix = Matrix::new_with_data(vec![
+            vec![9.5, 2.3, 3.5],
+            vec![2.3, -1.0, -8.5],
+            vec![3.5, -8.5, 0.0],
+        ])
+        .unwrap();
+        assert!(symmetric_matrix.is_symmetric());
+    }
+
+    #[test]
+    fn skew_symmetric() {
+        assert!(!test_utils::square_examples()[2].is_skew_symmetric());
+
+        let skew_symmetric_matrix = Matrix::new_with_data(vec![
+            vec![0.0, 2.3, 3.5],
+            vec![-2.3, 0.0, -8.5],
+            vec![-3.5, 8.5, 0.0],
+        ])
+        .unwrap();
+        assert!(skew_symmetric_matrix.is_skew_symmetric());
+    }
+
+    #[test]
+    fn diagonal() {
+        assert!(!test_utils::square_examples()[2].is_diagonal());
+
+        let diagonal_matrix = Matrix::new_with_data(vec![
+            vec![1.5, 0.0, 0.0, 0.0],
+            vec![0.0, 3.2, 0.0, 0.0],
+            vec![0.0, 0.0, 6.7, 0.0],
+            vec![0.0, 0.0, 0.0, 9.1],
+        ])
+        .unwrap();
+        assert!(diagonal_matrix.is_diagonal());
+    }
+
+    #[test]
+    fn scalar() {
+        let diagonal_matrix = Matrix::new_with_data(vec![
+            vec![1.5, 0.0, 0.0, 0.0],
+            vec![0.0, 3.2, 0.0, 0.0],
+            vec![0.0, 0.0, 6.7, 0.0],
+            vec![0.0, 0.0, 0.0, 9.1],
+        ])
+        .unwrap();
+        assert!(!diagonal_matrix.is_scalar());
+
+        let scalar_matrix = Matrix::new_with_data(vec![
+            vec![2.0, 0.0, 0.0],
+            vec![0.0, 2.0, 0.0],
+            vec![0.0, 0.0, 2.0],
+        ])
+        .unwrap();
+        assert!(scalar_matrix.is_scalar());
+    }
+
+    #[test]
+    fn identity() {
+        let scalar_matrix = Matrix::new_with_data(vec![
+            vec![2.0, 0.0, 0.0],
+            vec![0.0, 2.0, 0.0],
+            vec![0.0, 0.0, 2.0],
+        ])
+        .unwrap();
+        assert!(!scalar_matrix.is_identity());
+
+        let identity_matrix = Matrix::new_with_data(vec![
+            vec![1.0, 0.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0, 0.0],
+            vec![0.0, 0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 0.0, 1.0],
+        ])
+        .unwrap();
+        assert!(identity_matrix.is_identity());
+    }
+
+    #[test]
+    fn zero() {
+        assert!(!test_utils::generic_examples()[0].is_zero());
+        assert!(Matrix::new_with_data(vec![vec![0.0, 0.0]])
+            .unwrap()
+            .is_zero());
+    }
+
+    #[test]
+    fn singular() {
+        assert!(!test_utils::generic_examples()[0].is_singular());
+        assert!(Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]])
+            .unwrap()
+            .is_singular());
+
+        // Rounding leaves a determinant far from zero here
+        let singular = Matrix::new_with_data(vec![
+            vec![0.1e7, 0.2e7, 0.3e7],
+            vec![0.4e7, 0.5e7, 0.6e7],
+            vec![0.7e7, 0.8e7, 0.9e7],
+        ])
+        .unwrap();
+        assert_ne!(0.0, singular.determinant().unwrap());
+        assert!(singular.is_singular());
+
+        // and rounds it to zero here
+        let tiny = Matrix::new_with_data(vec![vec![1e-6, 0.0], vec![0.0, 1e-6]]).unwrap();
+        assert_eq!(0.0, tiny.determinant().unwrap());
+        assert!(!tiny.is_singular());
+        assert!(tiny.is_singular_within(2.0));
+        assert!(tiny.is_invertible());
+        assert!(!tiny.is_invertible_within(2.0));
+    }
+
+    #[test]
+    fn invertible() {
+        let [matrix_2x3, _, _] = test_utils::generic_examples();
+        assert!(!matrix_2x3.is_invertible());
+        assert!(!matrix_2x3.is_singular());
+
+        for matrix in test_utils::square_examples() {
+            assert_eq!(matrix.inverse().is_ok(), matrix.is_invertible());
+            assert_ne!(matrix.is_singular(), matrix.is_invertible());
+        }
+        assert!(!Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]])
+            .unwrap()
+            .is_invertible());
+    }
+
+    #[test]
+    fn property_report() {
+        let identity = Matrix::nth_identity(2).unwrap();
+        let report = identity.property_report();
+
+        assert_eq!(
+            Property::ALL
+                .iter()
+                .map(|property| property.label())
+                .chain(["Rank", "Determinant", "Trace"])
+                .collect::<Vec<_>>(),
+            report
+                .entries()
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Some(PropertyValue::Flag(true)), report.get("Is Identity"));
+        assert_eq!(Some(PropertyValue::Count(2)), report.get("Rank"));
+        assert_eq!(Some(PropertyValue::Number(2.0)), report.get("Trace"));
+
+        let chosen = identity.check_properties(&[Property::Singular, Property::Square]);
+        assert_eq!("Is Square: ✅\nIs Singular: ❌\n", chosen.to_string());
+
+        let options = FormatOptions {
+            precision: Some(2),
+            ..Default::default()
+        };
+        let report = test_utils::generic_examples()[0].property_report();
+        assert_eq!(None, report.get("Determinant"));
+        assert!(report
+            .formatted(options)
+            .to_string()
+            .ends_with("Is Singular: ❌\nRank: 2\n"));
+        assert!(Matrix::nth_identity(1)
+            .unwrap()
+            .property_report()
+            .formatted(options)
+            .to_string()
+            .ends_with("Determinant: 1.00\nTrace: 1.00\n"));
+    }
+}
@@ -0,0 +1,231 @@
+use crate::{LinearSystemSolution, Matrix, MatrixError};
+use pyo3::{
+    exceptions::{PyIndexError, PyValueError},
+    prelude::*,
+};
+
+impl From<MatrixError> for PyErr {
+    fn from(err: MatrixError) -> Self {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+// Exposed to Python as `matrix_core.Matrix`. Anything that iterates as rows of floats can construct
+// one, which covers nested lists as well as two-dimensional NumPy arrays
+#[pyclass(name = "Matrix", module = "matrix_core", from_py_object)]
+#[derive(Clone)]
+pub struct PyMatrix(Matrix);
+
+#[pymethods]
+impl PyMatrix {
+    #[new]
+    fn new(data: Vec<Vec<f64>>) -> PyResult<Self> {
+        Ok(PyMatrix(Matrix::new_with_data(data)?))
+    }
+
+    #[staticmethod]
+    fn zeros(rows: usize, columns: usize) -> PyResult<Self> {
+        Ok(PyMatrix(Matrix::new_zero_matrix(rows, columns)?))
+    }
+
+    #[staticmethod]
+    fn identity(size: usize) -> PyResult<Self> {
+        Ok(PyMatrix(Matrix::nth_identity(size)?))
+    }
+
+    #[getter]
+    fn rows(&self) -> usize {
+        self.0.rows
+    }
+
+    #[getter]
+    fn columns(&self) -> usize {
+        self.0.columns
+    }
+
+    #[getter]
+    fn shape(&self) -> (usize, usize) {
+        (self.0.rows, self.0.columns)
+    }
+
+    fn tolist(&self) -> Vec<Vec<f64>> {
+        self.0.get_data()
+    }
+
+    // NumPy is imported on demand, so the module itself works without it
+    fn to_numpy<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        py.import("numpy")?
+            .getattr("array")?
+            .call1((self.0.get_data(),))
+    }
+
+    // Lets numpy.asarray and friends accept a Matrix directly. A new array is made every time, so
+    // NumPy 2's `copy` argument has nothing to change
+    #[pyo3(signature = (dtype = None, copy = None))]
+    fn __array__<'py>(
+        &self,
+        py: Python<'py>,
+        dtype: Option<Bound<'py, PyAny>>,
+        copy: Option<bool>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let _ = copy;
+        let array = self.to_numpy(py)?;
+        match dtype {
+            Some(dtype) => array.call_method1("astype", (dtype,)),
+            None => Ok(array),
+        }
+    }
+
+    fn __getitem__(&self, index: (usize, usize)) -> PyResult<f64> {
+        let (row, column) = index;
+        self.0
+            .get_element(row, column)
+            .map_err(|err| PyIndexError::new_err(err.to_string()))
+    }
+
+    fn __setitem__(&mut self, index: (usize, usize), value: f64) -> PyResult<()> {
+        let (row, column) = index;
+        self.0
+            .set_element(row, column, value)
+            .map_err(|err| PyIndexError::new_err(err.to_string()))
+    }
+
+    fn __add__(&self, other: &PyMatrix) -> PyResult<Self> {
+        Ok(PyMatrix((&self.0 + &other.0)?))
+    }
+
+    fn __sub__(&self, other: &PyMatrix) -> PyResult<Self> {
+        Ok(PyMatrix((&self.0 - &other.0)?))
+    }
+
+    fn __matmul__(&self, other: &PyMatrix) -> PyResult<Self> {
+        Ok(PyMatrix((&self.0 * &other.0)?))
+    }
+
+    // `*` scales by a number, matrix products use `@` like NumPy
+    fn __mul__(&self, scalar: f64) -> Self {
+        PyMatrix(scalar * &self.0)
+    }
+
+    fn __rmul__(&self, scalar: f64) -> Self {
+        PyMatrix(scalar * &self.0)
+    }
+
+    fn __neg__(&self) -> Self {
+        PyMatrix(-&self.0)
+    }
+
+    fn __eq__(&self, other: &PyMatrix) -> bool {
+        self.0 == other.0
+    }
+
+    fn transpose(&self) -> Self {
+        PyMatrix(self.0.transpose())
+    }
+
+    #[getter(T)]
+    fn transposed(&self) -> Self {
+        self.transpose()
+    }
+
+    fn determinant(&self) -> PyResult<f64> {
+        Ok(self.0.determinant()?)
+    }
+
+    fn inverse(&self) -> PyResult<Self> {
+        Ok(PyMatrix(self.0.inverse()?))
+    }
+
+    // Raises ValueError unless the system has exactly one solution
+    fn solve(&self, b: &PyMatrix) -> PyResult<Self> {
+        match self.0.solve_general(&b.0)? {
+            LinearSystemSolution::Unique(solution) => Ok(PyMatrix(solution)),
+            LinearSystemSolution::NoSolution => {
+                Err(PyValueError::new_err("the system has no solution"))
+            }
+            LinearSystemSolution::Infinite { .. } => Err(PyValueError::new_err(
+                "the system has infinitely many solutions",
+            )),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Matrix({:?})", self.0.data)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl From<Matrix> for PyMatrix {
+    fn from(matrix: Matrix) -> Self {
+        PyMatrix(matrix)
+    }
+}
+
+impl From<PyMatrix> for Matrix {
+    fn from(matrix: PyMatrix) -> Self {
+        matrix.0
+    }
+}
+
+#[pymodule]
+fn matrix_core(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyMatrix>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use pyo3::types::PyDict;
+
+    fn run(code: &str) -> PyResult<()> {
+        Python::initialize();
+        Python::attach(|py| {
+            let globals = PyDict::new(py);
+            globals.set_item("Matrix", py.get_type::<PyMatrix>())?;
+            py.run(&std::ffi::CString::new(code).unwrap(), Some(&globals), None)
+        })
+    }
+
+    #[test]
+    fn conversions() {
+        for matrix in test_utils::generic_examples() {
+            assert_eq!(matrix, Matrix::from(PyMatrix::from(matrix.clone())));
+        }
+
+        run("m = Matrix([[7.2, 13.8, 5.1], [9.3, 2.7, 6.4]])
+assert m.shape == (2, 3) and m.T.shape == (3, 2)
+assert m[0, 1] == 13.8
+m[1, 2] = 1.0
+assert m.tolist() == [[7.2, 13.8, 5.1], [9.3, 2.7, 1.0]]
+assert eval(repr(m)) == m")
+        .unwrap();
+    }
+
+    #[test]
+    fn operators() {
+        run("a = Matrix([[2, 1], [1, 3]])
+assert a.determinant() == 5
+assert a.solve(Matrix([[3], [5]])).tolist() == [[0.8], [1.4]]
+assert a @ a.inverse() == Matrix.identity(2)
+assert a + a == 2 * a == a * 2 and a - a == Matrix.zeros(2, 2)
+assert -a == a * -1")
+        .unwrap();
+
+        run("
+try:
+    Matrix([[1, 2], [3]])
+    raise AssertionError
+except ValueError:
+    pass
+try:
+    Matrix.identity(2)[2, 0]
+    raise AssertionError
+except IndexError:
+    pass")
+        .unwrap();
+    }
+}
@@ -0,0 +1,176 @@
+use crate::{Matrix, MatrixError};
+use std::f64::consts::PI;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Distribution {
+    Uniform { low: f64, high: f64 },
+    Normal { mean: f64, standard_deviation: f64 },
+}
+
+// SplitMix64, chosen over an external crate so that a seed produces the same matrix on every platform and version
+#[derive(Debug, Clone)]
+pub(crate) struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in [0, 1)
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn sample(&mut self, distribution: Distribution) -> f64 {
+        match distribution {
+            Distribution::Uniform { low, high } => low + (high - low) * self.next_f64(),
+            Distribution::Normal {
+                mean,
+                standard_deviation,
+            } => {
+                // Box-Muller transform, with 1 - u keeping the logarithm's argument in (0, 1]
+                let radius = (-2.0 * (1.0 - self.next_f64()).ln()).sqrt();
+                let angle = 2.0 * PI * self.next_f64();
+                mean + standard_deviation * radius * angle.cos()
+            }
+        }
+    }
+}
+
+impl Matrix {
+    pub fn new_random(
+        rows: usize,
+        columns: usize,
+        distribution: Distribution,
+        seed: u64,
+    ) -> Result<Self, MatrixError> {
+        let valid = match distribution {
+            Distribution::Uniform { low, high } => {
+                low.is_finite() && high.is_finite() && low <= high
+            }
+            Distribution::Normal {
+                mean,
+                standard_deviation,
+            } => mean.is_finite() && standard_deviation.is_finite() && standard_deviation >= 0.0,
+        };
+
+        if !valid {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        let mut random_matrix = Matrix::new_zero_matrix(rows, columns)?;
+        let mut rng = Rng::new(seed);
+        random_matrix.data.iter_mut().for_each(|row| {
+            row.iter_mut()
+                .for_each(|num| *num = crate::round_result(rng.sample(distribution)))
+        });
+
+        Ok(random_matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uniform() {
+        let distribution = Distribution::Uniform {
+            low: -2.0,
+            high: 3.0,
+        };
+        let random_matrix = Matrix::new_random(20, 30, distribution, 42).unwrap();
+
+        assert_eq!((20, 30), (random_matrix.rows, random_matrix.columns));
+        assert!(random_matrix
+            .data
+            .iter()
+            .flatten()
+            .all(|num| (-2.0..=3.0).contains(num)));
+        assert_eq!(
+            random_matrix,
+            Matrix::new_random(20, 30, distribution, 42).unwrap()
+        );
+        assert_ne!(
+            random_matrix,
+            Matrix::new_random(20, 30, distribution, 43).unwrap()
+        );
+    }
+
+    #[test]
+    fn normal() {
+        let random_matrix = Matrix::new_random(
+            100,
+            100,
+            Distribution::Normal {
+                mean: 5.0,
+                standard_deviation: 2.0,
+            },
+            7,
+        )
+        .unwrap();
+
+        let values: Vec<f64> = random_matrix.take_data().into_iter().flatten().collect();
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let variance =
+            values.iter().map(|num| (num - mean).powi(2)).sum::<f64>() / values.len() as f64;
+
+        assert!((mean - 5.0).abs() < 0.1);
+        assert!((variance.sqrt() - 2.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            Matrix::new_random(
+                2,
+                2,
+                Distribution::Uniform {
+                    low: 1.0,
+                    high: 0.0
+                },
+                0
+            )
+            .unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            Matrix::new_random(
+                2,
+                2,
+                Distribution::Normal {
+                    mean: 0.0,
+                    standard_deviation: -1.0
+                },
+                0
+            )
+            .unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_random(
+                0,
+                2,
+                Distribution::Uniform {
+                    low: 0.0,
+                    high: 1.0
+                },
+                0
+            )
+            .unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use crate::{Matrix, MatrixError};
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeSet;
+
+// A matrix together with the metadata stored next to it in JSON, as described by
+// schema/matrix.schema.json. A serialized Matrix is a record without metadata, and the data of a
+// record can be read back as a plain Matrix
+#[derive(Debug, PartialEq, Clone)]
+pub struct MatrixRecord {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub tags: BTreeSet<String>,
+    pub matrix: Matrix,
+}
+
+impl From<Matrix> for MatrixRecord {
+    fn from(matrix: Matrix) -> Self {
+        MatrixRecord {
+            name: None,
+            description: None,
+            tags: BTreeSet::new(),
+            matrix,
+        }
+    }
+}
+
+// The data comes last, so the metadata stays at the top of large matrices
+#[derive(Serialize)]
+struct MatrixRef<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+    rows: usize,
+    columns: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    data: &'a Vec<Vec<f64>>,
+}
+
+#[derive(Deserialize)]
+struct MatrixOwned {
+    #[serde(default)]
+    name: Option<String>,
+    rows: usize,
+    columns: usize,
+    #[serde(default)]
+    tags: BTreeSet<String>,
+    #[serde(default)]
+    description: Option<String>,
+    data: Vec<Vec<f64>>,
+}
+
+impl MatrixOwned {
+    fn into_record(self) -> Result<MatrixRecord, MatrixError> {
+        let matrix = Matrix::new_with_data(self.data)?;
+
+        if matrix.shape() != (self.rows, self.columns) {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.rows, self.columns),
+                actual: matrix.shape(),
+            });
+        }
+
+        Ok(MatrixRecord {
+            name: self.name,
+            description: self.description,
+            tags: self.tags,
+            matrix,
+        })
+    }
+}
+
+impl Serialize for Matrix {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MatrixRef {
+            name: None,
+            rows: self.rows,
+            columns: self.columns,
+            tags: vec![],
+            description: None,
+            data: &self.data,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Matrix {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let record = MatrixOwned::deserialize(deserializer)?
+            .into_record()
+            .map_err(D::Error::custom)?;
+        Ok(record.matrix)
+    }
+}
+
+impl Serialize for MatrixRecord {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MatrixRef {
+            name: self.name.as_deref(),
+            rows: self.matrix.rows,
+            columns: self.matrix.columns,
+            tags: self.tags.iter().map(String::as_str).collect(),
+            description: self.description.as_deref(),
+            data: &self.matrix.data,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MatrixRecord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        MatrixOwned::deserialize(deserializer)?
+            .into_record()
+            .map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn round_trip() {
+        let matrix_2x3 = &test_utils::generic_examples()[0];
+        let json = serde_json::to_string(matrix_2x3).unwrap();
+
+        assert_eq!(
+            r#"{"rows":2,"columns":3,"data":[[7.2,13.8,5.1],[9.3,2.7,6.4]]}"#,
+            json
+        );
+        assert_eq!(matrix_2x3, &serde_json::from_str::<Matrix>(&json).unwrap());
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert!(serde_json::from_str::<Matrix>(
+            r#"{"rows":2,"columns":2,"data":[[1.0,2.0],[3.0]]}"#
+        )
+        .is_err());
+
+        assert!(serde_json::from_str::<Matrix>(
+            r#"{"rows":3,"columns":2,"data":[[1.0,2.0],[3.0,4.0]]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn record() {
+        let mut record = MatrixRecord::from(test_utils::generic_examples()[0].clone());
+        let plain = r#"{"rows":2,"columns":3,"data":[[7.2,13.8,5.1],[9.3,2.7,6.4]]}"#;
+        assert_eq!(plain, serde_json::to_string(&record).unwrap());
+        assert_eq!(record, serde_json::from_str::<MatrixRecord>(plain).unwrap());
+
+        record.name = Some("A".to_string());
+        record.tags = BTreeSet::from(["temp".to_string(), "homework3".to_string()]);
+        record.description = Some("Prices by quarter".to_string());
+        let json = serde_json::to_string(&record).unwrap();
+
+        assert_eq!(
+            r#"{"name":"A","rows":2,"columns":3,"tags":["homework3","temp"],"description":"Prices by quarter","data":[[7.2,13.8,5.1],[9.3,2.7,6.4]]}"#,
+            json
+        );
+        assert_eq!(record, serde_json::from_str::<MatrixRecord>(&json).unwrap());
+        assert_eq!(
+            record.matrix,
+            serde_json::from_str::<Matrix>(&json).unwrap()
+        );
+
+        assert!(serde_json::from_str::<MatrixRecord>(
+            r#"{"name":"A","rows":1,"columns":2,"data":[[1.0,2.0,3.0]]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn schema_covers_every_field() {
+        let schema: serde_json::Value =
+            serde_json::from_str(include_str!("../schema/matrix.schema.json")).unwrap();
+        let record = MatrixRecord {
+            name: Some("A".to_string()),
+            description: Some("Identity".to_string()),
+            tags: BTreeSet::from(["temp".to_string()]),
+            matrix: Matrix::nth_identity(2).unwrap(),
+        };
+
+        let serde_json::Value::Object(fields) = serde_json::to_value(&record).unwrap() else {
+            panic!("a record should serialize as an object");
+        };
+        for field in fields.keys() {
+            assert!(schema["properties"].get(field).is_some(), "{field}");
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{Matrix, PivotStrategy, Precision};
+use std::cell::Cell;
+
+// The choices that operations otherwise make on their own, gathered in one place. They apply to the
+// current thread through `with_settings`, and to any parallel work started from it
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Settings {
+    // How every result is rounded
+    pub precision: Precision,
+    // The pivots of the decomposition behind `determinant`, `inverse`, `is_singular` and `logdet`
+    pub pivot_strategy: PivotStrategy,
+    // The relative pivot size below which `is_singular`, `inverse` and the operations built on
+    // them treat a matrix as singular
+    pub singularity_tolerance: f64,
+    // The elements from which work is spread over the rayon thread pool. Without the `rayon`
+    // feature, everything runs on the calling thread whatever it is
+    pub parallel_threshold: usize,
+}
+
+const DEFAULT: Settings = Settings {
+    precision: Precision::Decimals(5),
+    pivot_strategy: PivotStrategy::Partial,
+    singularity_tolerance: Matrix::SINGULARITY_TOLERANCE,
+    // Below this many elements, handing rows to other threads costs more than it saves
+    parallel_threshold: 1 << 16,
+};
+
+impl Default for Settings {
+    fn default() -> Self {
+        DEFAULT
+    }
+}
+
+thread_local! {
+    static SETTINGS: Cell<Settings> = const { Cell::new(DEFAULT) };
+}
+
+// Puts back the settings that were in effect before, even when the computation panics
+struct Restore(Settings);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        SETTINGS.with(|settings| settings.set(self.0));
+    }
+}
+
+// Runs `computation` under `settings`, as in
+// `with_settings(Settings { pivot_strategy: PivotStrategy::Full, ..Settings::default() }, || a.determinant())`
+pub fn with_settings<T>(settings: Settings, computation: impl FnOnce() -> T) -> T {
+    let _restore = Restore(SETTINGS.with(|current| current.replace(settings)));
+    computation()
+}
+
+// The settings in effect on this thread
+pub fn current_settings() -> Settings {
+    SETTINGS.with(Cell::get)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils, MatrixError};
+
+    #[test]
+    fn with_settings() {
+        let nearly_singular =
+            Matrix::new_with_data(vec![vec![1.0, 1.0], vec![1.0, 1.000001]]).unwrap();
+        assert!(!nearly_singular.is_singular());
+
+        let strict = Settings {
+            singularity_tolerance: 1e-4,
+            ..Settings::default()
+        };
+        super::with_settings(strict, || {
+            assert_eq!(strict, current_settings());
+            assert!(nearly_singular.is_singular());
+            assert_eq!(
+                MatrixError::SingularMatrix,
+                nearly_singular.inverse().unwrap_err()
+            );
+        });
+        assert_eq!(Settings::default(), current_settings());
+        assert!(nearly_singular.inverse().is_ok());
+
+        // Every strategy finds the same determinant, up to rounding
+        let matrix = &test_utils::square_examples()[3];
+        let determinant = matrix.determinant().unwrap();
+        for pivot_strategy in [PivotStrategy::ScaledPartial, PivotStrategy::Full] {
+            let settings = Settings {
+                pivot_strategy,
+                ..Settings::default()
+            };
+            let other = super::with_settings(settings, || matrix.determinant().unwrap());
+            assert!((determinant - other).abs() < 1e-4);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_threshold() {
+        // Large enough for the parallel path once the threshold is lowered
+        let matrix = &test_utils::square_examples()[3];
+        let settings = Settings {
+            precision: Precision::Decimals(1),
+            parallel_threshold: 1,
+            ..Settings::default()
+        };
+        let sum = super::with_settings(settings, || (matrix + matrix).unwrap());
+        assert_eq!(Ok(14.2), sum.get_element(0, 1));
+    }
+}
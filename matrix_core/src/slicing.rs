@@ -0,0 +1,477 @@
+use crate::{Matrix, MatrixError};
+use std::ops::Range;
+
+// What fills the border that `pad` adds around a matrix
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum PaddingMode {
+    Zero,
+    Constant(f64),
+    // The nearest edge element, repeated outwards
+    Replicate,
+    // The elements mirrored about the edge, which itself isn't repeated, so [1 2 3] padded by two
+    // on each side becomes [3 2 1 2 3 2 1]. Borders wider than the matrix keep bouncing between its
+    // edges
+    Reflect,
+}
+
+impl PaddingMode {
+    // The index inside 0..len that `index`, counted from the start of the unpadded data, takes its
+    // value from, or None for the constant modes
+    fn source(self, index: isize, len: usize) -> Option<usize> {
+        let last = len as isize - 1;
+        match self {
+            PaddingMode::Zero | PaddingMode::Constant(_) => None,
+            PaddingMode::Replicate => Some(index.clamp(0, last) as usize),
+            PaddingMode::Reflect if len == 1 => Some(0),
+            PaddingMode::Reflect => {
+                let period = 2 * last;
+                let index = index.rem_euclid(period);
+                Some(if index > last { period - index } else { index } as usize)
+            }
+        }
+    }
+}
+
+impl Matrix {
+    pub fn submatrix(
+        &self,
+        rows: Range<usize>,
+        columns: Range<usize>,
+    ) -> Result<Self, MatrixError> {
+        if rows.is_empty() || columns.is_empty() {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        crate::check_index(rows.end - 1, self.rows)?;
+        crate::check_index(columns.end - 1, self.columns)?;
+
+        Matrix::new_with_data(
+            self.data[rows]
+                .iter()
+                .map(|row| row[columns.clone()].to_vec())
+                .collect(),
+        )
+    }
+
+    // A 1×n matrix holding the given row
+    pub fn row_vector(&self, row: usize) -> Result<Self, MatrixError> {
+        self.submatrix(row..row + 1, 0..self.columns)
+    }
+
+    // An n×1 matrix holding the given column
+    pub fn column_vector(&self, column: usize) -> Result<Self, MatrixError> {
+        self.submatrix(0..self.rows, column..column + 1)
+    }
+
+    // The matrix with `top` rows above it, `bottom` below, `left` columns to its left and `right` to
+    // its right, filled as the mode says
+    pub fn pad(
+        &self,
+        top: usize,
+        bottom: usize,
+        left: usize,
+        right: usize,
+        mode: PaddingMode,
+    ) -> Self {
+        let fill = match mode {
+            PaddingMode::Constant(value) => value,
+            _ => 0.0,
+        };
+        let rows = top + self.rows + bottom;
+        let columns = left + self.columns + right;
+
+        let data = (0..rows)
+            .map(|i| {
+                let row = mode.source(i as isize - top as isize, self.rows);
+                (0..columns)
+                    .map(|j| {
+                        let inside_row = (top..top + self.rows).contains(&i);
+                        let inside_column = (left..left + self.columns).contains(&j);
+                        if inside_row && inside_column {
+                            return self.data[i - top][j - left];
+                        }
+                        let column = mode.source(j as isize - left as isize, self.columns);
+                        match (row, column) {
+                            (Some(row), Some(column)) => self.data[row][column],
+                            _ => fill,
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Matrix {
+            rows,
+            columns,
+            data,
+        }
+    }
+
+    // [A | B], placing the columns of `other` to the right
+    pub fn augment(&self, other: &Matrix) -> Result<Self, MatrixError> {
+        if self.rows != other.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.rows, other.columns),
+                actual: other.shape(),
+            });
+        }
+
+        Matrix::new_with_data(
+            self.data
+                .iter()
+                .zip(other.data.iter())
+                .map(|(row, other_row)| [row.as_slice(), other_row.as_slice()].concat())
+                .collect(),
+        )
+    }
+
+    // Places the rows of `other` below
+    pub fn stack(&self, other: &Matrix) -> Result<Self, MatrixError> {
+        if self.columns != other.columns {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (other.rows, self.columns),
+                actual: other.shape(),
+            });
+        }
+
+        Matrix::new_with_data([self.data.as_slice(), other.data.as_slice()].concat())
+    }
+
+    // The rows before `row` and the rows from it on, neither of which may be empty
+    pub fn split_at_row(&self, row: usize) -> Result<(Self, Self), MatrixError> {
+        if row == 0 {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        Ok((
+            self.submatrix(0..row, 0..self.columns)?,
+            self.submatrix(row..self.rows, 0..self.columns)?,
+        ))
+    }
+
+    pub fn split_at_column(&self, column: usize) -> Result<(Self, Self), MatrixError> {
+        if column == 0 {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        Ok((
+            self.submatrix(0..self.rows, 0..column)?,
+            self.submatrix(0..self.rows, column..self.columns)?,
+        ))
+    }
+
+    // A grid of blocks cut before each of the given rows and columns, which must be increasing so
+    // that no block is empty. `blocks(&[], &[])` is a 1×1 grid holding the whole matrix
+    pub fn blocks(
+        &self,
+        row_splits: &[usize],
+        column_splits: &[usize],
+    ) -> Result<Vec<Vec<Self>>, MatrixError> {
+        let row_bounds = [&[0], row_splits, &[self.rows]].concat();
+        let column_bounds = [&[0], column_splits, &[self.columns]].concat();
+
+        row_bounds
+            .windows(2)
+            .map(|rows| {
+                column_bounds
+                    .windows(2)
+                    .map(|columns| self.submatrix(rows[0]..rows[1], columns[0]..columns[1]))
+                    .collect()
+            })
+            .collect()
+    }
+
+    // Joins a grid of blocks back into one matrix. The blocks of a grid row must have the same
+    // number of rows, and the grid rows the same number of columns
+    pub fn from_blocks(blocks: &[Vec<Matrix>]) -> Result<Self, MatrixError> {
+        let grid_rows = blocks
+            .iter()
+            .map(|grid_row| {
+                let (first, rest) = grid_row.split_first().ok_or(MatrixError::ZeroDimension)?;
+                rest.iter()
+                    .try_fold(first.clone(), |joined, block| joined.augment(block))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (first, rest) = grid_rows.split_first().ok_or(MatrixError::ZeroDimension)?;
+        rest.iter()
+            .try_fold(first.clone(), |joined, grid_row| joined.stack(grid_row))
+    }
+
+    // The product of two block grids, with block (i, j) the sum over k of a[i][k] * b[k][j]. The
+    // column cuts of `a` must line up with the row cuts of `b`
+    pub fn block_multiply(
+        a: &[Vec<Matrix>],
+        b: &[Vec<Matrix>],
+    ) -> Result<Vec<Vec<Self>>, MatrixError> {
+        if a.is_empty() || b.is_empty() || a[0].is_empty() || b[0].is_empty() {
+            return Err(MatrixError::ZeroDimension);
+        }
+        // Every row of a grid needs as many blocks as the first, as with the elements of a matrix
+        for grid in [a, b] {
+            if grid.iter().any(|row| row.len() != grid[0].len()) {
+                return Err(MatrixError::InconsistentColumnSize);
+            }
+        }
+
+        // Compared as grids of blocks rather than as matrices
+        if a[0].len() != b.len() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (a[0].len(), b[0].len()),
+                actual: (b.len(), b[0].len()),
+            });
+        }
+
+        a.iter()
+            .map(|a_row| {
+                (0..b[0].len())
+                    .map(|j| {
+                        let (first, rest) = a_row.split_first().unwrap();
+                        rest.iter()
+                            .zip(b.iter().skip(1))
+                            .try_fold((first * &b[0][j])?, |sum, (a_block, b_row)| {
+                                &sum + &(a_block * &b_row[j])?
+                            })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    // The main diagonal as a column vector, which need not be square
+    pub fn diagonal(&self) -> Self {
+        Matrix::new_with_data(
+            (0..self.rows.min(self.columns))
+                .map(|i| vec![self.data[i][i]])
+                .collect(),
+        )
+        .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn submatrix() {
+        let matrix = Matrix::new_with_data(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ])
+        .unwrap();
+
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![5.0, 6.0], vec![8.0, 9.0]]).unwrap(),
+            matrix.submatrix(1..3, 1..3).unwrap()
+        );
+        assert_eq!(matrix, matrix.submatrix(0..3, 0..3).unwrap());
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            matrix.submatrix(1..1, 0..3).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 3, len: 3 },
+            matrix.submatrix(0..2, 1..4).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn vectors() {
+        let matrix = &test_utils::generic_examples()[2];
+
+        assert_eq!(
+            Matrix::new_with_data(vec![matrix.get_row(1).unwrap()]).unwrap(),
+            matrix.row_vector(1).unwrap()
+        );
+        assert_eq!(
+            matrix.get_column(0).unwrap(),
+            matrix.column_vector(0).unwrap().get_column(0).unwrap()
+        );
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 4, len: 4 },
+            matrix.row_vector(matrix.rows()).unwrap_err()
+        );
+
+        let diagonal = matrix.diagonal();
+        assert_eq!(
+            (matrix.rows().min(matrix.columns()), 1),
+            (diagonal.rows(), diagonal.columns())
+        );
+        assert_eq!(
+            (0..diagonal.rows())
+                .map(|i| matrix.get_element(i, i).unwrap())
+                .collect::<Vec<_>>(),
+            diagonal.get_column(0).unwrap()
+        );
+    }
+
+    #[test]
+    fn augment_and_stack() {
+        let [first, second, tall] = test_utils::generic_examples();
+
+        let augmented = first.augment(&second).unwrap();
+        assert_eq!((2, 6), (augmented.rows(), augmented.columns()));
+        assert_eq!(first, augmented.submatrix(0..2, 0..3).unwrap());
+        assert_eq!(second, augmented.submatrix(0..2, 3..6).unwrap());
+
+        let stacked = first.stack(&second).unwrap();
+        assert_eq!((4, 3), (stacked.rows(), stacked.columns()));
+        assert_eq!(second, stacked.submatrix(2..4, 0..3).unwrap());
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 2),
+                actual: (4, 2)
+            },
+            first.augment(&tall).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (4, 3),
+                actual: (4, 2)
+            },
+            first.stack(&tall).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn pad() {
+        let row = Matrix::new_with_data(vec![vec![1.0, 2.0, 3.0]]).unwrap();
+        let padded_row = |mode| row.pad(0, 0, 2, 2, mode).get_data().concat();
+        assert_eq!(
+            vec![0.0, 0.0, 1.0, 2.0, 3.0, 0.0, 0.0],
+            padded_row(PaddingMode::Zero)
+        );
+        assert_eq!(
+            vec![9.0, 9.0, 1.0, 2.0, 3.0, 9.0, 9.0],
+            padded_row(PaddingMode::Constant(9.0))
+        );
+        assert_eq!(
+            vec![1.0, 1.0, 1.0, 2.0, 3.0, 3.0, 3.0],
+            padded_row(PaddingMode::Replicate)
+        );
+        assert_eq!(
+            vec![3.0, 2.0, 1.0, 2.0, 3.0, 2.0, 1.0],
+            padded_row(PaddingMode::Reflect)
+        );
+        // Wider than the row itself
+        assert_eq!(
+            vec![1.0, 2.0, 3.0, 2.0, 1.0, 2.0, 3.0, 2.0],
+            row.pad(0, 0, 0, 5, PaddingMode::Reflect)
+                .get_data()
+                .concat()
+        );
+
+        let matrix = Matrix::new_with_data(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                vec![1.0, 1.0, 2.0],
+                vec![1.0, 1.0, 2.0],
+                vec![3.0, 3.0, 4.0],
+                vec![3.0, 3.0, 4.0],
+            ])
+            .unwrap(),
+            matrix.pad(1, 1, 1, 0, PaddingMode::Replicate)
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![4.0, 3.0, 4.0], vec![2.0, 1.0, 2.0]]).unwrap(),
+            matrix
+                .pad(1, 0, 1, 0, PaddingMode::Reflect)
+                .submatrix(0..2, 0..3)
+                .unwrap()
+        );
+        assert_eq!(matrix, matrix.pad(0, 0, 0, 0, PaddingMode::Zero));
+
+        // A single element reflects onto itself
+        let single = Matrix::new_with_data(vec![vec![5.0]]).unwrap();
+        assert_eq!(
+            vec![vec![5.0; 3]; 2],
+            single.pad(1, 0, 1, 1, PaddingMode::Reflect).get_data()
+        );
+    }
+
+    #[test]
+    fn blocks() {
+        let matrix = test_utils::square_examples()[3].clone();
+
+        let (top, bottom) = matrix.split_at_row(2).unwrap();
+        assert_eq!(
+            (2, 5, 3, 5),
+            (top.rows(), top.columns(), bottom.rows(), bottom.columns())
+        );
+        assert_eq!(matrix, top.stack(&bottom).unwrap());
+        let (left, right) = matrix.split_at_column(4).unwrap();
+        assert_eq!(matrix, left.augment(&right).unwrap());
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            matrix.split_at_row(0).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            matrix.split_at_column(5).unwrap_err()
+        );
+
+        let grid = matrix.blocks(&[2], &[1, 3]).unwrap();
+        assert_eq!((2, 3), (grid.len(), grid[0].len()));
+        assert_eq!(matrix.submatrix(2..5, 1..3).unwrap(), grid[1][1]);
+        assert_eq!(matrix, Matrix::from_blocks(&grid).unwrap());
+        assert_eq!(vec![vec![matrix.clone()]], matrix.blocks(&[], &[]).unwrap());
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            matrix.blocks(&[3, 3], &[]).unwrap_err()
+        );
+
+        let a = matrix.blocks(&[2], &[3]).unwrap();
+        let b = matrix.blocks(&[3], &[2]).unwrap();
+        let product = Matrix::from_blocks(&Matrix::block_multiply(&a, &b).unwrap()).unwrap();
+        let expected = (&matrix * &matrix).unwrap();
+        for i in 0..5 {
+            for j in 0..5 {
+                let difference =
+                    product.get_element(i, j).unwrap() - expected.get_element(i, j).unwrap();
+                assert!(difference.abs() < 1e-4);
+            }
+        }
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 2),
+                actual: (3, 2)
+            },
+            Matrix::block_multiply(&a, &matrix.blocks(&[1, 3], &[2]).unwrap()).unwrap_err()
+        );
+
+        // Blocks whose inner dimensions don't match, although the grids do
+        let mismatched = matrix.blocks(&[2], &[2]).unwrap();
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (3, 2),
+                actual: (2, 2)
+            },
+            Matrix::block_multiply(&a, &mismatched).unwrap_err()
+        );
+
+        let mut empty_row = a.clone();
+        empty_row[1].clear();
+        assert_eq!(
+            MatrixError::InconsistentColumnSize,
+            Matrix::block_multiply(&empty_row, &b).unwrap_err()
+        );
+        // A short row of a used to be cut off, giving a wrong product instead of an error
+        let mut ragged_a = a.clone();
+        ragged_a[1].pop();
+        assert_eq!(
+            MatrixError::InconsistentColumnSize,
+            Matrix::block_multiply(&ragged_a, &b).unwrap_err()
+        );
+        let mut ragged_b = b.clone();
+        ragged_b[1].pop();
+        assert_eq!(
+            MatrixError::InconsistentColumnSize,
+            Matrix::block_multiply(&a, &ragged_b).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,516 @@
+use crate::{
+    functions,
+    trace::{self, Span},
+    Matrix, MatrixError, Norm,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum LinearSystemSolution {
+    Unique(Matrix),
+    // Every solution is `particular` plus a combination of the columns of `null_space`, one per free variable
+    Infinite {
+        particular: Matrix,
+        null_space: Matrix,
+        free_variables: Vec<usize>,
+    },
+    NoSolution,
+}
+
+// Gauss-Jordan elimination with partial pivoting on unrounded data, so that a matrix of uniformly
+// tiny elements isn't rounded away before its pivots are found. Columns whose candidates are all
+// within `threshold` of zero get no pivot
+fn eliminate(mut data: functions::Data, threshold: f64) -> (functions::Data, Vec<usize>) {
+    let (rows, columns) = (data.len(), data[0].len());
+    let mut pivot_columns = vec![];
+    let mut pivot_row = 0;
+
+    for column in 0..columns {
+        if pivot_row == rows {
+            break;
+        }
+
+        let max_row = (pivot_row..rows)
+            .max_by(|a, b| data[*a][column].abs().total_cmp(&data[*b][column].abs()))
+            .unwrap();
+        if data[max_row][column].abs() <= threshold {
+            continue;
+        }
+
+        data.swap(pivot_row, max_row);
+        let pivot = data[pivot_row][column];
+        data[pivot_row].iter_mut().for_each(|num| *num /= pivot);
+
+        let pivot_values = data[pivot_row].clone();
+        data.iter_mut()
+            .enumerate()
+            .filter(|(row, _)| *row != pivot_row)
+            .for_each(|(_, row)| {
+                let factor = row[column];
+                row.iter_mut()
+                    .zip(pivot_values.iter())
+                    .for_each(|(num, pivot_num)| *num -= factor * pivot_num);
+                row[column] = 0.0;
+            });
+
+        pivot_columns.push(column);
+        pivot_row += 1;
+    }
+
+    (data, pivot_columns)
+}
+
+impl Matrix {
+    pub fn row_reduce(&self) -> (Matrix, Vec<usize>) {
+        self.row_reduce_within(crate::current_settings().singularity_tolerance)
+    }
+
+    // Like `is_singular_within`, `tolerance` is relative to ‖A‖∞, so a column has no pivot when every
+    // candidate is within tolerance·‖A‖∞ of zero. Returns the reduced row echelon form and the pivot
+    // columns
+    pub fn row_reduce_within(&self, tolerance: f64) -> (Matrix, Vec<usize>) {
+        let (reduced, pivot_columns) = eliminate(
+            self.data.to_vec(),
+            tolerance * self.unrounded_norm(Norm::Infinity),
+        );
+        // Adding zero turns the -0.0 left by dividing zeros by a negative pivot into 0.0
+        let reduced = reduced
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|num| crate::round_result(num) + 0.0)
+                    .collect()
+            })
+            .collect();
+
+        (Matrix::new_with_data(reduced).unwrap(), pivot_columns)
+    }
+
+    pub fn reduced_row_echelon_form(&self) -> Matrix {
+        self.row_reduce().0
+    }
+
+    pub fn rank(&self) -> usize {
+        *self.data.cached(
+            |cache| &cache.rank,
+            || self.rank_within(crate::current_settings().singularity_tolerance),
+        )
+    }
+
+    // The number of pivots `row_reduce_within` finds for the same tolerance
+    pub fn rank_within(&self, tolerance: f64) -> usize {
+        self.row_reduce_within(tolerance).1.len()
+    }
+
+    // x with Ax = b for a square, nonsingular A, by substitution through the cached LU decomposition
+    // rather than by forming the inverse, which takes more work and loses more accuracy. Every
+    // column of b is solved for, and `solve_general` handles singular and rectangular systems
+    pub fn solve(&self, b: &Matrix) -> Result<Matrix, MatrixError> {
+        let n = self.rows;
+        let flops = 2 * trace::cube(n) / 3 + 2 * (n * n * b.columns) as u64;
+        let _span = Span::new("solve", &[self.shape(), b.shape()], flops);
+
+        self.nonsingular_lu(crate::current_settings().singularity_tolerance)?
+            .solve(b)
+    }
+
+    pub fn solve_general(&self, b: &Matrix) -> Result<LinearSystemSolution, MatrixError> {
+        // Gauss-Jordan elimination clears a whole column of [A | b] for each pivot
+        let pivots = self.rows.min(self.columns);
+        let flops = 2 * self.rows * (self.columns + b.columns) * pivots;
+        let _span = Span::new("solve", &[self.shape(), b.shape()], flops as u64);
+        if self.rows != b.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.rows, b.columns),
+                actual: b.shape(),
+            });
+        }
+
+        // The tolerance is relative to A alone, so a large b doesn't hide the pivots of a small A
+        let threshold =
+            crate::current_settings().singularity_tolerance * self.unrounded_norm(Norm::Infinity);
+        let (reduced, pivot_columns) = eliminate(self.augment(b).unwrap().data.to_vec(), threshold);
+        let reduced = functions::rounded(reduced);
+        if pivot_columns.iter().any(|column| *column >= self.columns) {
+            return Ok(LinearSystemSolution::NoSolution);
+        }
+
+        let mut particular = Matrix::new_zero_matrix(self.columns, b.columns).unwrap();
+        pivot_columns.iter().enumerate().for_each(|(row, column)| {
+            particular.data[*column] = reduced.data[row][self.columns..].to_vec()
+        });
+
+        let free_variables: Vec<usize> = (0..self.columns)
+            .filter(|column| !pivot_columns.contains(column))
+            .collect();
+
+        if free_variables.is_empty() {
+            return Ok(LinearSystemSolution::Unique(particular));
+        }
+
+        let mut null_space = Matrix::new_zero_matrix(self.columns, free_variables.len()).unwrap();
+        free_variables
+            .iter()
+            .enumerate()
+            .for_each(|(index, free_variable)| {
+                null_space.data[*free_variable][index] = 1.0;
+                pivot_columns.iter().enumerate().for_each(|(row, column)| {
+                    null_space.data[*column][index] = 0.0 - reduced.data[row][*free_variable]
+                });
+            });
+
+        Ok(LinearSystemSolution::Infinite {
+            particular,
+            null_space,
+            free_variables,
+        })
+    }
+
+    // The x minimising ‖Ax - b‖, by Householder QR on unrounded data. A must have at least as many
+    // rows as columns and independent columns, or the minimiser is not unique
+    pub fn least_squares(&self, b: &Matrix) -> Result<Matrix, MatrixError> {
+        if self.rows != b.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.rows, b.columns),
+                actual: b.shape(),
+            });
+        }
+
+        let (rows, columns) = (self.rows, self.columns);
+        if rows < columns {
+            return Err(MatrixError::SingularMatrix);
+        }
+
+        let scale = self
+            .data
+            .iter()
+            .flatten()
+            .fold(0.0, |max: f64, num| max.max(num.abs()));
+        let mut r = self.data.to_vec();
+        let mut c = b.data.to_vec();
+
+        for k in 0..columns {
+            let norm = (k..rows).map(|i| r[i][k] * r[i][k]).sum::<f64>().sqrt();
+            if norm <= f64::EPSILON * rows as f64 * scale {
+                return Err(MatrixError::SingularMatrix);
+            }
+
+            // Reflects the rest of column k onto a multiple of the first unit vector, choosing the
+            // sign that avoids cancellation
+            let alpha = if r[k][k] > 0.0 { -norm } else { norm };
+            let mut v: Vec<f64> = (k..rows).map(|i| r[i][k]).collect();
+            v[0] -= alpha;
+            let v_norm_squared: f64 = v.iter().map(|num| num * num).sum();
+
+            for target in [&mut r, &mut c] {
+                for j in 0..target[0].len() {
+                    let dot: f64 = (k..rows).map(|i| v[i - k] * target[i][j]).sum();
+                    let factor = 2.0 * dot / v_norm_squared;
+                    (k..rows).for_each(|i| target[i][j] -= factor * v[i - k]);
+                }
+            }
+        }
+
+        let mut solution = vec![vec![0.0; b.columns]; columns];
+        for column in 0..b.columns {
+            for i in (0..columns).rev() {
+                let known: f64 = ((i + 1)..columns)
+                    .map(|j| r[i][j] * solution[j][column])
+                    .sum();
+                solution[i][column] = (c[i][column] - known) / r[i][i];
+            }
+        }
+
+        Matrix::new_with_data(
+            solution
+                .into_iter()
+                .map(|row| row.into_iter().map(crate::round_result).collect())
+                .collect(),
+        )
+    }
+
+    // b - Ax, left unrounded so that residuals far below five decimal places still show
+    pub fn residual(&self, x: &Matrix, b: &Matrix) -> Result<Matrix, MatrixError> {
+        if self.columns != x.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.columns, x.columns),
+                actual: x.shape(),
+            });
+        }
+        if b.shape() != (self.rows, x.columns) {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.rows, x.columns),
+                actual: b.shape(),
+            });
+        }
+
+        Matrix::new_with_data(
+            b.data
+                .iter()
+                .zip(functions::product(&self.data, &x.data))
+                .map(|(b_row, row)| b_row.iter().zip(row).map(|(b, ax)| b - ax).collect())
+                .collect(),
+        )
+    }
+
+    // ‖b - Ax‖ / (‖A‖‖x‖ + ‖b‖) in the ∞-norm, the normwise backward error of x: how much A and b
+    // would have to change, relatively, for x to solve the system exactly
+    pub fn relative_residual_norm(&self, x: &Matrix, b: &Matrix) -> Result<f64, MatrixError> {
+        let residual = self.residual(x, b)?.unrounded_norm(Norm::Infinity);
+        let scale = self.unrounded_norm(Norm::Infinity) * x.unrounded_norm(Norm::Infinity)
+            + b.unrounded_norm(Norm::Infinity);
+
+        // Only A, x and b all zero make the scale zero, and then the residual is zero as well
+        Ok(match scale {
+            0.0 => residual,
+            scale => residual / scale,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn row_reduce() {
+        let (reduced, pivot_columns) =
+            Matrix::new_with_data(vec![vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 7.0]])
+                .unwrap()
+                .row_reduce();
+
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1.0, 2.0, 0.0], vec![0.0, 0.0, 1.0]]).unwrap(),
+            reduced
+        );
+        assert_eq!(vec![0, 2], pivot_columns);
+        assert!(reduced
+            .get_row(1)
+            .unwrap()
+            .iter()
+            .all(|num| num.is_sign_positive()));
+    }
+
+    #[test]
+    fn row_reduce_within() {
+        // The second row is twice the first up to a small perturbation in the last column
+        let nearly_dependent =
+            Matrix::new_with_data(vec![vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 6.001]]).unwrap();
+
+        let (reduced, pivot_columns) = nearly_dependent.row_reduce();
+        assert_eq!(vec![0, 2], pivot_columns);
+        assert_eq!(reduced, nearly_dependent.reduced_row_echelon_form());
+
+        let (reduced, pivot_columns) = nearly_dependent.row_reduce_within(1e-2);
+        assert_eq!(vec![0], pivot_columns);
+        assert_eq!(Ok(vec![1.0, 2.0, 3.0005]), reduced.get_row(0));
+
+        assert_eq!(2, nearly_dependent.rank());
+        assert_eq!(2, nearly_dependent.rank_within(1e-5));
+        assert_eq!(1, nearly_dependent.rank_within(1e-2));
+    }
+
+    #[test]
+    fn uniformly_tiny() {
+        // The tolerance scales with ‖A‖∞, so a tiny but perfectly conditioned matrix keeps its pivots
+        let tiny = Matrix::new_with_data(vec![vec![1e-11]]).unwrap();
+        assert_eq!(1, tiny.rank());
+        assert!(tiny.is_invertible());
+
+        let tiny_identity = Matrix::new_diagonal_matrix(&[1e-11, 1e-11]).unwrap();
+        assert!(!tiny_identity.is_singular());
+        assert_eq!(2, tiny_identity.rank());
+        assert_eq!(
+            Matrix::nth_identity(2).unwrap(),
+            tiny_identity.reduced_row_echelon_form()
+        );
+
+        let b = Matrix::new_with_data(vec![vec![1e-11], vec![2e-11]]).unwrap();
+        assert_eq!(
+            LinearSystemSolution::Unique(
+                Matrix::new_with_data(vec![vec![1.0], vec![2.0]]).unwrap()
+            ),
+            tiny_identity.solve_general(&b).unwrap()
+        );
+        // A right-hand side much larger than A doesn't hide its pivots either
+        let large = Matrix::new_with_data(vec![vec![1.0], vec![1.0]]).unwrap();
+        assert!(matches!(
+            tiny_identity.solve_general(&large).unwrap(),
+            LinearSystemSolution::Unique(_)
+        ));
+    }
+
+    #[test]
+    fn rank() {
+        let [matrix_1x1, matrix_2x2, matrix_3x3, matrix_5x5] = test_utils::square_examples();
+        assert_eq!(1, matrix_1x1.rank());
+        assert_eq!(2, matrix_2x2.rank());
+        assert_eq!(3, matrix_3x3.rank());
+        assert_eq!(5, matrix_5x5.rank());
+
+        assert_eq!(2, test_utils::generic_examples()[2].rank());
+        assert_eq!(0, Matrix::new_zero_matrix(2, 3).unwrap().rank());
+        assert_eq!(
+            1,
+            Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]])
+                .unwrap()
+                .rank()
+        );
+    }
+
+    #[test]
+    fn unique_solution() {
+        let a = Matrix::new_with_data(vec![vec![2.0, 1.0], vec![1.0, 3.0]]).unwrap();
+        let b = Matrix::new_with_data(vec![vec![3.0, 5.0], vec![5.0, 10.0]]).unwrap();
+
+        assert_eq!(
+            LinearSystemSolution::Unique(
+                Matrix::new_with_data(vec![vec![0.8, 1.0], vec![1.4, 3.0]]).unwrap()
+            ),
+            a.solve_general(&b).unwrap()
+        );
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 2),
+                actual: (4, 2)
+            },
+            a.solve_general(&test_utils::generic_examples()[2])
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn infinite_solutions() {
+        let a = Matrix::new_with_data(vec![vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 7.0]]).unwrap();
+        let b = Matrix::new_with_data(vec![vec![4.0], vec![9.0]]).unwrap();
+
+        assert_eq!(
+            LinearSystemSolution::Infinite {
+                particular: Matrix::new_with_data(vec![vec![1.0], vec![0.0], vec![1.0]]).unwrap(),
+                null_space: Matrix::new_with_data(vec![vec![-2.0], vec![1.0], vec![0.0]]).unwrap(),
+                free_variables: vec![1],
+            },
+            a.solve_general(&b).unwrap()
+        );
+    }
+
+    #[test]
+    fn solve() {
+        let a = Matrix::new_with_data(vec![vec![2.0, 1.0], vec![1.0, 3.0]]).unwrap();
+        let b = Matrix::new_with_data(vec![vec![3.0, 1.0], vec![5.0, 0.0]]).unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![0.8, 0.6], vec![1.4, -0.2]]).unwrap(),
+            a.solve(&b).unwrap()
+        );
+
+        for matrix in test_utils::square_examples() {
+            let b = Matrix::new_with_data(vec![vec![1.0]; matrix.rows]).unwrap();
+            let x = matrix.solve(&b).unwrap();
+            assert!(matrix.relative_residual_norm(&x, &b).unwrap() < 1e-5);
+            // Elimination pivots in a different order, so it can disagree in the last place
+            let LinearSystemSolution::Unique(reduced) = matrix.solve_general(&b).unwrap() else {
+                panic!("the system should have one solution");
+            };
+            assert!(x.distance(&reduced, Norm::Infinity).unwrap() <= 1e-5);
+        }
+
+        let singular = Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert_eq!(MatrixError::SingularMatrix, singular.solve(&b).unwrap_err());
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[0].solve(&b).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 2),
+                actual: (4, 2)
+            },
+            a.solve(&test_utils::generic_examples()[2]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn no_solution() {
+        let a = Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        let b = Matrix::new_with_data(vec![vec![1.0], vec![3.0]]).unwrap();
+
+        assert_eq!(
+            LinearSystemSolution::NoSolution,
+            a.solve_general(&b).unwrap()
+        );
+    }
+
+    #[test]
+    fn least_squares() {
+        let x = [0.0, 1.0, 2.0, 3.0];
+        let line = Matrix::new_vandermonde_matrix(&x, 2).unwrap();
+
+        let exact =
+            Matrix::new_with_data(vec![vec![1.0], vec![3.0], vec![5.0], vec![7.0]]).unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1.0], vec![2.0]]).unwrap(),
+            line.least_squares(&exact).unwrap()
+        );
+
+        let noisy = Matrix::new_with_data(vec![vec![1.0], vec![2.0], vec![2.0]]).unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1.16667], vec![0.5]]).unwrap(),
+            Matrix::new_vandermonde_matrix(&x[..3], 2)
+                .unwrap()
+                .least_squares(&noisy)
+                .unwrap()
+        );
+
+        assert_eq!(
+            MatrixError::SingularMatrix,
+            Matrix::new_vandermonde_matrix(&[1.0, 1.0, 1.0], 2)
+                .unwrap()
+                .least_squares(&noisy)
+                .unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (4, 1),
+                actual: (3, 1)
+            },
+            line.least_squares(&noisy).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn residual() {
+        let a = Matrix::new_with_data(vec![vec![2.0, 1.0], vec![1.0, 3.0]]).unwrap();
+        let b = Matrix::new_with_data(vec![vec![3.0], vec![4.0]]).unwrap();
+        let exact = Matrix::new_with_data(vec![vec![1.0], vec![1.0]]).unwrap();
+        let close = Matrix::new_with_data(vec![vec![1.0], vec![1.000001]]).unwrap();
+
+        assert_eq!(
+            Matrix::new_zero_matrix(2, 1).unwrap(),
+            a.residual(&exact, &b).unwrap()
+        );
+        assert_eq!(0.0, a.relative_residual_norm(&exact, &b).unwrap());
+
+        let residual = a.residual(&close, &b).unwrap();
+        assert!((residual.get_element(1, 0).unwrap() + 3e-6).abs() < 1e-15);
+        let relative = a.relative_residual_norm(&close, &b).unwrap();
+        assert!((relative - 3e-6 / (4.0 * 1.000001 + 4.0)).abs() < 1e-15);
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 1),
+                actual: (1, 1)
+            },
+            a.residual(&exact, &Matrix::nth_identity(1).unwrap())
+                .unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 2),
+                actual: (4, 2)
+            },
+            a.residual(&test_utils::generic_examples()[2], &b)
+                .unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{check_index, Matrix, MatrixError};
+
+// The direction `sort_rows_by_column` and `sort_columns_by_row` order their keys in
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum SortOrder {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+// The order that sorts `keys`, as the index each position takes its key from. The sort is stable,
+// so equal keys keep their order, and compares with `total_cmp`, which puts NaN after every number
+fn sorted_order(keys: &[f64], order: SortOrder) -> Vec<usize> {
+    let mut permutation: Vec<usize> = (0..keys.len()).collect();
+    permutation.sort_by(|&a, &b| match order {
+        SortOrder::Ascending => keys[a].total_cmp(&keys[b]),
+        SortOrder::Descending => keys[b].total_cmp(&keys[a]),
+    });
+    permutation
+}
+
+impl Matrix {
+    // Reorders the rows by their values in `column` and returns the permutation applied, where row i
+    // now holds what was row permutation[i], the same convention as `new_permutation_matrix`
+    pub fn sort_rows_by_column(
+        &mut self,
+        column: usize,
+        order: SortOrder,
+    ) -> Result<Vec<usize>, MatrixError> {
+        check_index(column, self.columns)?;
+
+        let keys: Vec<f64> = self.data.iter().map(|row| row[column]).collect();
+        let permutation = sorted_order(&keys, order);
+        self.data = permutation
+            .iter()
+            .map(|&row| self.data[row].clone())
+            .collect::<Vec<_>>()
+            .into();
+
+        Ok(permutation)
+    }
+
+    // Reorders the columns by their values in `row`, where column j now holds what was column
+    // permutation[j]
+    pub fn sort_columns_by_row(
+        &mut self,
+        row: usize,
+        order: SortOrder,
+    ) -> Result<Vec<usize>, MatrixError> {
+        check_index(row, self.rows)?;
+
+        let permutation = sorted_order(&self.data[row], order);
+        self.data = self
+            .data
+            .iter()
+            .map(|elements| permutation.iter().map(|&column| elements[column]).collect())
+            .collect::<Vec<_>>()
+            .into();
+
+        Ok(permutation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn sort_rows_by_column() {
+        let mut matrix = Matrix::new_with_data(vec![
+            vec![3.0, 1.0],
+            vec![1.0, 2.0],
+            vec![2.0, 3.0],
+            vec![1.0, 4.0],
+        ])
+        .unwrap();
+        let original = matrix.clone();
+
+        let permutation = matrix.sort_rows_by_column(0, SortOrder::Ascending).unwrap();
+        // Rows with equal keys keep their order
+        assert_eq!(vec![1, 3, 2, 0], permutation);
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                vec![1.0, 2.0],
+                vec![1.0, 4.0],
+                vec![2.0, 3.0],
+                vec![3.0, 1.0],
+            ])
+            .unwrap(),
+            matrix
+        );
+        let reordered = Matrix::new_permutation_matrix(&permutation).unwrap();
+        assert_eq!(matrix, (&reordered * &original).unwrap());
+
+        let permutation = matrix
+            .sort_rows_by_column(1, SortOrder::Descending)
+            .unwrap();
+        assert_eq!(vec![1, 2, 0, 3], permutation);
+        assert_eq!(Ok(vec![1.0, 4.0]), matrix.get_row(0));
+
+        let mut with_nan =
+            Matrix::new_with_data(vec![vec![f64::NAN], vec![2.0], vec![-1.0]]).unwrap();
+        assert_eq!(
+            Ok(vec![2, 1, 0]),
+            with_nan.sort_rows_by_column(0, SortOrder::Ascending)
+        );
+
+        let [mut matrix_2x3, _, _] = test_utils::generic_examples();
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 3, len: 3 },
+            matrix_2x3
+                .sort_rows_by_column(3, SortOrder::Ascending)
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn sort_columns_by_row() {
+        let mut matrix =
+            Matrix::new_with_data(vec![vec![1.0, 2.0, 3.0], vec![5.0, 9.0, 7.0]]).unwrap();
+        let original = matrix.clone();
+
+        let permutation = matrix
+            .sort_columns_by_row(1, SortOrder::Descending)
+            .unwrap();
+        assert_eq!(vec![1, 2, 0], permutation);
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![2.0, 3.0, 1.0], vec![9.0, 7.0, 5.0]]).unwrap(),
+            matrix
+        );
+        // The columns are reordered by multiplying with the transposed permutation matrix on the right
+        let reordered = Matrix::new_permutation_matrix(&permutation)
+            .unwrap()
+            .transpose();
+        assert_eq!(matrix, (&original * &reordered).unwrap());
+
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 2, len: 2 },
+            matrix
+                .sort_columns_by_row(2, SortOrder::Ascending)
+                .unwrap_err()
+        );
+    }
+}
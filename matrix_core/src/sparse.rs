@@ -0,0 +1,293 @@
+use crate::{random::Rng, Matrix, MatrixError};
+use std::{collections::HashSet, ops::Mul};
+
+// Compressed sparse row storage. The nonzero elements of row i are
+// values[row_starts[i]..row_starts[i + 1]], in the columns given by the same range of
+// column_indices, which increase along each row
+#[derive(Debug, PartialEq, Clone)]
+pub struct SparseMatrix {
+    rows: usize,
+    columns: usize,
+    row_starts: Vec<usize>,
+    column_indices: Vec<usize>,
+    values: Vec<f64>,
+}
+
+impl Rng {
+    // Uniform in 0..n, by the high half of a 128-bit product rather than a biased remainder
+    fn below(&mut self, n: usize) -> usize {
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    // Uniform in [-1, 1) without zero, so every stored element really is nonzero
+    fn nonzero(&mut self) -> f64 {
+        loop {
+            let num = crate::round_result(2.0 * self.next_f64() - 1.0);
+            if num != 0.0 {
+                return num;
+            }
+        }
+    }
+}
+
+impl SparseMatrix {
+    // The positions, as row-major indices, have to be in increasing order
+    fn from_positions(
+        rows: usize,
+        columns: usize,
+        positions: impl IntoIterator<Item = usize>,
+        rng: &mut Rng,
+    ) -> Self {
+        let mut row_starts = vec![0; rows + 1];
+        let mut column_indices = vec![];
+        let mut values = vec![];
+        for position in positions {
+            row_starts[position / columns + 1] += 1;
+            column_indices.push(position % columns);
+            values.push(rng.nonzero());
+        }
+        for row in 0..rows {
+            row_starts[row + 1] += row_starts[row];
+        }
+
+        Self {
+            rows,
+            columns,
+            row_starts,
+            column_indices,
+            values,
+        }
+    }
+
+    // A `density` fraction of the elements, rounded to the nearest count, are nonzero and drawn
+    // uniformly from [-1, 1), at positions chosen uniformly without repeats
+    pub fn random(
+        rows: usize,
+        columns: usize,
+        density: f64,
+        seed: u64,
+    ) -> Result<Self, MatrixError> {
+        if rows == 0 || columns == 0 {
+            return Err(MatrixError::ZeroDimension);
+        }
+        let Some(size) = rows.checked_mul(columns) else {
+            return Err(MatrixError::InvalidArgument);
+        };
+        if !(0.0..=1.0).contains(&density) {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        // Floyd's algorithm takes exactly one draw per nonzero, however large the matrix
+        let count = (density * size as f64).round() as usize;
+        let mut rng = Rng::new(seed);
+        let mut chosen = HashSet::with_capacity(count);
+        for j in (size - count)..size {
+            let candidate = rng.below(j + 1);
+            if !chosen.insert(candidate) {
+                chosen.insert(j);
+            }
+        }
+        let mut positions: Vec<usize> = chosen.into_iter().collect();
+        positions.sort_unstable();
+
+        Ok(Self::from_positions(rows, columns, positions, &mut rng))
+    }
+
+    // Nonzero exactly on the diagonal, the `lower_bandwidth` diagonals below it and the
+    // `upper_bandwidth` diagonals above it, with values drawn uniformly from [-1, 1)
+    pub fn random_banded(
+        rows: usize,
+        columns: usize,
+        lower_bandwidth: usize,
+        upper_bandwidth: usize,
+        seed: u64,
+    ) -> Result<Self, MatrixError> {
+        if rows == 0 || columns == 0 {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        let positions = (0..rows).flat_map(|row| {
+            let first = row.saturating_sub(lower_bandwidth);
+            let last = row.saturating_add(upper_bandwidth).min(columns - 1);
+            // Empty once the band has moved past the last column
+            (first..=last).map(move |column| row * columns + column)
+        });
+
+        Ok(Self::from_positions(
+            rows,
+            columns,
+            positions,
+            &mut Rng::new(seed),
+        ))
+    }
+
+    pub fn from_matrix(matrix: &Matrix) -> Self {
+        let mut row_starts = vec![0];
+        let mut column_indices = vec![];
+        let mut values = vec![];
+        for row in matrix.data.iter() {
+            for (column, num) in row.iter().enumerate().filter(|(_, num)| **num != 0.0) {
+                column_indices.push(column);
+                values.push(*num);
+            }
+            row_starts.push(values.len());
+        }
+
+        Self {
+            rows: matrix.rows,
+            columns: matrix.columns,
+            row_starts,
+            column_indices,
+            values,
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix {
+        let mut matrix = Matrix::new_zero_matrix(self.rows, self.columns).unwrap();
+        for (row, elements) in matrix.data.iter_mut().enumerate() {
+            for index in self.row_starts[row]..self.row_starts[row + 1] {
+                elements[self.column_indices[index]] = self.values[index];
+            }
+        }
+
+        matrix
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn nonzeros(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn density(&self) -> f64 {
+        self.values.len() as f64 / (self.rows as f64 * self.columns as f64)
+    }
+
+    pub fn get_element(&self, row: usize, column: usize) -> Result<f64, MatrixError> {
+        crate::check_index(row, self.rows)?;
+        crate::check_index(column, self.columns)?;
+
+        let range = self.row_starts[row]..self.row_starts[row + 1];
+        Ok(
+            match self.column_indices[range.clone()].binary_search(&column) {
+                Ok(offset) => self.values[range.start + offset],
+                Err(_) => 0.0,
+            },
+        )
+    }
+}
+
+// Only the stored elements take part, so the cost grows with the nonzeros rather than the size
+impl Mul<&Matrix> for &SparseMatrix {
+    type Output = Result<Matrix, MatrixError>;
+
+    fn mul(self, rhs: &Matrix) -> Self::Output {
+        if self.columns != rhs.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.columns, rhs.columns),
+                actual: rhs.shape(),
+            });
+        }
+
+        let mut result = Matrix::new_zero_matrix(self.rows, rhs.columns).unwrap();
+        for (row, result_row) in result.data.iter_mut().enumerate() {
+            for (j, num) in result_row.iter_mut().enumerate() {
+                let range = self.row_starts[row]..self.row_starts[row + 1];
+                *num =
+                    crate::round_result(crate::compensated_sum(range.map(|index| {
+                        self.values[index] * rhs.data[self.column_indices[index]][j]
+                    })));
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn random() {
+        let sparse = SparseMatrix::random(40, 50, 0.05, 42).unwrap();
+        assert_eq!(100, sparse.nonzeros());
+        assert_eq!(0.05, sparse.density());
+        assert_eq!(sparse, SparseMatrix::random(40, 50, 0.05, 42).unwrap());
+        assert_ne!(sparse, SparseMatrix::random(40, 50, 0.05, 43).unwrap());
+
+        let dense = sparse.to_matrix();
+        assert_eq!(
+            100,
+            dense
+                .data
+                .iter()
+                .flatten()
+                .filter(|num| **num != 0.0)
+                .count()
+        );
+        assert!(dense
+            .data
+            .iter()
+            .flatten()
+            .all(|num| (-1.0..1.0).contains(num)));
+        assert_eq!(sparse, SparseMatrix::from_matrix(&dense));
+
+        assert_eq!(0, SparseMatrix::random(3, 3, 0.0, 1).unwrap().nonzeros());
+        assert_eq!(9, SparseMatrix::random(3, 3, 1.0, 1).unwrap().nonzeros());
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            SparseMatrix::random(3, 3, 1.5, 1).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            SparseMatrix::random(0, 3, 0.5, 1).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn random_banded() {
+        let banded = SparseMatrix::random_banded(6, 5, 1, 2, 7).unwrap();
+        // Rows 0 to 3 have 3 or 4 elements, and rows 4 and 5 run into the last column
+        assert_eq!(3 + 4 + 4 + 3 + 2 + 1, banded.nonzeros());
+
+        for row in 0..6 {
+            for column in 0..5 {
+                let in_band = column + 1 >= row && column <= row + 2;
+                assert_eq!(in_band, banded.get_element(row, column).unwrap() != 0.0);
+            }
+        }
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 5, len: 5 },
+            banded.get_element(0, 5).unwrap_err()
+        );
+
+        let diagonal = SparseMatrix::random_banded(4, 4, 0, 0, 7).unwrap();
+        assert_eq!(4, diagonal.nonzeros());
+    }
+
+    #[test]
+    fn multiplication() {
+        let [matrix_2x3, _, matrix_4x2] = test_utils::generic_examples();
+        let sparse = SparseMatrix::random(5, 4, 0.3, 3).unwrap();
+
+        assert_eq!(
+            (&sparse.to_matrix() * &matrix_4x2).unwrap(),
+            (&sparse * &matrix_4x2).unwrap()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (4, 3),
+                actual: (2, 3)
+            },
+            (&sparse * &matrix_2x3).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{Matrix, MatrixError};
+
+impl Matrix {
+    pub fn new_hilbert_matrix(size: usize) -> Result<Self, MatrixError> {
+        let mut hilbert_matrix = Matrix::new_zero_matrix(size, size)?;
+        hilbert_matrix
+            .data
+            .iter_mut()
+            .enumerate()
+            .for_each(|(i, row)| {
+                row.iter_mut()
+                    .enumerate()
+                    .for_each(|(j, num)| *num = 1.0 / (i + j + 1) as f64)
+            });
+
+        Ok(hilbert_matrix)
+    }
+
+    pub fn new_vandermonde_matrix(points: &[f64], columns: usize) -> Result<Self, MatrixError> {
+        let mut vandermonde_matrix = Matrix::new_zero_matrix(points.len(), columns)?;
+        vandermonde_matrix
+            .data
+            .iter_mut()
+            .zip(points.iter())
+            .for_each(|(row, point)| {
+                row.iter_mut()
+                    .enumerate()
+                    .for_each(|(power, num)| *num = point.powi(power as i32))
+            });
+
+        Ok(vandermonde_matrix)
+    }
+
+    // Givens rotation by `angle` radians in the plane of axes `i` and `j`; size 2 with axes 0 and 1 gives the usual 2D rotation
+    pub fn new_rotation_matrix(
+        size: usize,
+        i: usize,
+        j: usize,
+        angle: f64,
+    ) -> Result<Self, MatrixError> {
+        crate::check_index(i, size)?;
+        crate::check_index(j, size)?;
+
+        if i == j {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        let mut rotation_matrix = Matrix::nth_identity(size)?;
+        let (sin, cos) = (
+            crate::round_result(angle.sin()),
+            crate::round_result(angle.cos()),
+        );
+        rotation_matrix.data[i][i] = cos;
+        rotation_matrix.data[j][j] = cos;
+        rotation_matrix.data[i][j] = -sin;
+        rotation_matrix.data[j][i] = sin;
+
+        Ok(rotation_matrix)
+    }
+
+    // Row i of the result has its 1 in column permutation[i], so P * A reorders the rows of A the same way
+    pub fn new_permutation_matrix(permutation: &[usize]) -> Result<Self, MatrixError> {
+        let size = permutation.len();
+        let mut seen = vec![false; size];
+        for &index in permutation {
+            if index >= size || seen[index] {
+                return Err(MatrixError::InvalidArgument);
+            }
+            seen[index] = true;
+        }
+
+        let mut permutation_matrix = Matrix::new_zero_matrix(size, size)?;
+        permutation
+            .iter()
+            .enumerate()
+            .for_each(|(row, column)| permutation_matrix.data[row][*column] = 1.0);
+
+        Ok(permutation_matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn hilbert() {
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_hilbert_matrix(0).unwrap_err()
+        );
+
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                vec![1.0, 1.0 / 2.0, 1.0 / 3.0],
+                vec![1.0 / 2.0, 1.0 / 3.0, 1.0 / 4.0],
+                vec![1.0 / 3.0, 1.0 / 4.0, 1.0 / 5.0],
+            ])
+            .unwrap(),
+            Matrix::new_hilbert_matrix(3).unwrap()
+        );
+    }
+
+    #[test]
+    fn vandermonde() {
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_vandermonde_matrix(&[], 3).unwrap_err()
+        );
+
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                vec![1.0, 2.0, 4.0],
+                vec![1.0, -1.0, 1.0],
+                vec![1.0, 0.5, 0.25],
+            ])
+            .unwrap(),
+            Matrix::new_vandermonde_matrix(&[2.0, -1.0, 0.5], 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn rotation() {
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![0.0, -1.0], vec![1.0, 0.0]]).unwrap(),
+            Matrix::new_rotation_matrix(2, 0, 1, PI / 2.0).unwrap()
+        );
+
+        let rotation = Matrix::new_rotation_matrix(3, 0, 2, PI).unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                vec![-1.0, 0.0, 0.0],
+                vec![0.0, 1.0, 0.0],
+                vec![0.0, 0.0, -1.0],
+            ])
+            .unwrap(),
+            rotation
+        );
+
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 2, len: 2 },
+            Matrix::new_rotation_matrix(2, 0, 2, PI).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            Matrix::new_rotation_matrix(2, 1, 1, PI).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn permutation() {
+        let permutation_matrix = Matrix::new_permutation_matrix(&[2, 0, 1]).unwrap();
+        let matrix_3x3 = &test_utils::square_examples()[2];
+
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                matrix_3x3.get_row(2).unwrap(),
+                matrix_3x3.get_row(0).unwrap(),
+                matrix_3x3.get_row(1).unwrap(),
+            ])
+            .unwrap(),
+            (&permutation_matrix * matrix_3x3).unwrap()
+        );
+
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            Matrix::new_permutation_matrix(&[0, 0, 1]).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            Matrix::new_permutation_matrix(&[0, 3, 1]).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_permutation_matrix(&[]).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,982 @@
+use crate::{
+    functions::{self, Data},
+    trace::{self, Span},
+    Matrix, MatrixError,
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Equilibration {
+    matrix: Matrix,
+    row_scales: Vec<f64>,
+    column_scales: Vec<f64>,
+}
+
+impl Equilibration {
+    pub fn matrix(&self) -> &Matrix {
+        &self.matrix
+    }
+
+    pub fn row_scales(&self) -> &[f64] {
+        &self.row_scales
+    }
+
+    pub fn column_scales(&self) -> &[f64] {
+        &self.column_scales
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct PivotedCholesky {
+    lower: Matrix,
+    permutation: Vec<usize>,
+    rank: usize,
+}
+
+impl PivotedCholesky {
+    pub fn lower(&self) -> &Matrix {
+        &self.lower
+    }
+
+    // Row i of PᵀAP is row permutation[i] of A, with columns reordered the same way
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+}
+
+// Scales are rounded to powers of two so that applying them introduces no rounding error
+fn power_of_two_reciprocal(max: f64) -> f64 {
+    if max == 0.0 {
+        1.0
+    } else {
+        (-max.log2().round()).exp2()
+    }
+}
+
+impl Matrix {
+    pub fn transpose(&self) -> Self {
+        let mut transpose = Self::new_zero_matrix(self.columns, self.rows).unwrap();
+
+        transpose
+            .data
+            .iter_mut()
+            .enumerate()
+            .for_each(|(row_index, row)| {
+                row.iter_mut()
+                    .enumerate()
+                    .for_each(|(column_index, num)| *num = self.data[column_index][row_index])
+            });
+
+        transpose
+    }
+
+    // (A + Aᵀ) / 2, the symmetric matrix closest to A in the Frobenius norm
+    pub fn symmetrize(&self) -> Result<Self, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        let mut symmetric = self.clone();
+        for i in 0..self.rows {
+            for j in 0..self.columns {
+                symmetric.data[i][j] =
+                    crate::round_result((self.data[i][j] + self.data[j][i]) / 2.0);
+            }
+        }
+
+        Ok(symmetric)
+    }
+
+    pub fn trace(&self) -> Result<f64, MatrixError> {
+        *self.data.cached(
+            |cache| &cache.trace,
+            || {
+                if !self.is_square() {
+                    return Err(MatrixError::NonSquareMatrix);
+                }
+
+                let trace = crate::compensated_sum((0..self.rows).map(|i| self.data[i][i]));
+
+                Ok(crate::round_result(trace))
+            },
+        )
+    }
+
+    pub fn trace_of_product(&self, other: &Matrix) -> Result<f64, MatrixError> {
+        if other.shape() != (self.columns, self.rows) {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.columns, self.rows),
+                actual: other.shape(),
+            });
+        }
+
+        let trace = crate::compensated_sum(self.data.iter().enumerate().flat_map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(j, num)| num * other.data[j][i])
+        }));
+
+        Ok(crate::round_result(trace))
+    }
+
+    pub fn determinant(&self) -> Result<f64, MatrixError> {
+        let _span = Span::new(
+            "determinant",
+            &[self.shape()],
+            2 * trace::cube(self.rows) / 3,
+        );
+        *self.data.cached(
+            |cache| &cache.determinant,
+            || {
+                if !self.is_square() {
+                    return Err(MatrixError::NonSquareMatrix);
+                }
+
+                Ok(match self.rows {
+                    1 => self.data[0][0],
+                    2 => crate::round_result(
+                        self.data[0][0] * self.data[1][1] - self.data[0][1] * self.data[1][0],
+                    ),
+                    _ => self.determinant_with(crate::current_settings().pivot_strategy)?,
+                })
+            },
+        )
+    }
+
+    pub fn adjoint(&self) -> Result<Self, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        Ok(match self.rows {
+            1 => Matrix::new_with_data(vec![vec![1.0]]).unwrap(),
+            2 => Matrix::new_with_data(vec![
+                vec![self.data[1][1], -self.data[0][1]],
+                vec![-self.data[1][0], self.data[0][0]],
+            ])
+            .unwrap(),
+            _ => {
+                let mut cofactor_matrix = Matrix::new_zero_matrix(self.rows, self.columns).unwrap();
+                self.data.iter().enumerate().for_each(|(row_index, row)| {
+                    row.iter().enumerate().for_each(|(column_index, _num)| {
+                        let mut sub_matrix_data = vec![vec![0.0; self.columns - 1]; self.rows - 1];
+
+                        self.data.iter().enumerate().for_each(|(i, row)| {
+                            row.iter().enumerate().for_each(|(j, num)| {
+                                if i != row_index && j != column_index {
+                                    sub_matrix_data[if i < row_index { i } else { i - 1usize }]
+                                        [if j < column_index { j } else { j - 1usize }] = *num
+                                }
+                            })
+                        });
+
+                        let sub_matrix = Matrix::new_with_data(sub_matrix_data).unwrap();
+                        let minor = sub_matrix.determinant().unwrap();
+                        cofactor_matrix.data[row_index][column_index] =
+                            if (row_index + column_index) % 2 == 0 {
+                                minor
+                            } else {
+                                -minor
+                            };
+                    })
+                });
+
+                cofactor_matrix.transpose()
+            }
+        })
+    }
+
+    pub fn inverse(&self) -> Result<Self, MatrixError> {
+        self.inverse_within(crate::current_settings().singularity_tolerance)
+    }
+
+    // Fails with SingularMatrix when `is_singular_within` holds for the same tolerance
+    pub fn inverse_within(&self, tolerance: f64) -> Result<Self, MatrixError> {
+        let _span = Span::new("inverse", &[self.shape()], 2 * trace::cube(self.rows));
+        self.nonsingular_lu(tolerance)?
+            .solve(&Matrix::nth_identity(self.rows).unwrap())
+    }
+
+    // D − CA⁻¹B for the partition [A B; C D] whose leading block A is block_size × block_size
+    pub fn schur_complement(&self, block_size: usize) -> Result<Self, MatrixError> {
+        let blocks = self.blocks(&[block_size], &[block_size])?;
+        let (a, b) = (&blocks[0][0], &blocks[0][1]);
+        let (c, d) = (&blocks[1][0], &blocks[1][1]);
+
+        let a_inverse_b = a
+            .nonsingular_lu(crate::current_settings().singularity_tolerance)?
+            .solve(b)?;
+        d - &(c * &a_inverse_b)?
+    }
+
+    // Checks that [A B; C D] fits together with square diagonal blocks
+    fn check_block2x2(a: &Matrix, b: &Matrix, c: &Matrix, d: &Matrix) -> Result<(), MatrixError> {
+        if !a.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+        if b.rows != a.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (a.rows, b.columns),
+                actual: b.shape(),
+            });
+        }
+        if c.columns != a.columns {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (c.rows, a.columns),
+                actual: c.shape(),
+            });
+        }
+        if d.shape() != (c.rows, b.columns) {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (c.rows, b.columns),
+                actual: d.shape(),
+            });
+        }
+        if !d.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        Ok(())
+    }
+
+    // Row j of A⁻¹ solves Aᵀx = eⱼ, so the rows come straight from the cached factorization
+    pub(crate) fn unrounded_inverse(&self) -> Result<Data, MatrixError> {
+        let lu = self.nonsingular_lu(crate::current_settings().singularity_tolerance)?;
+        Ok((0..self.rows)
+            .map(|j| {
+                let mut unit = vec![0.0; self.rows];
+                unit[j] = 1.0;
+                lu.solve_transpose_vector(&unit)
+            })
+            .collect())
+    }
+
+    // A⁻¹B and the Schur complement S = D − CA⁻¹B, unrounded
+    fn block2x2_schur(
+        a: &Matrix,
+        b: &Matrix,
+        c: &Matrix,
+        d: &Matrix,
+    ) -> Result<(Data, Data, Matrix), MatrixError> {
+        Matrix::check_block2x2(a, b, c, d)?;
+        let a_inverse = a.unrounded_inverse()?;
+        let a_inverse_b = functions::product(&a_inverse, &b.data);
+        let schur = functions::sum(&d.data, &functions::product(&c.data, &a_inverse_b), -1.0);
+
+        Ok((
+            a_inverse,
+            a_inverse_b,
+            Matrix {
+                rows: d.rows,
+                columns: d.columns,
+                data: schur.into(),
+            },
+        ))
+    }
+
+    // The inverse of [A B; C D] as a grid of blocks in the same partition, from the inverses of A and
+    // of its Schur complement S = D − CA⁻¹B alone:
+    // [A⁻¹ + A⁻¹BS⁻¹CA⁻¹, −A⁻¹BS⁻¹; −S⁻¹CA⁻¹, S⁻¹]. Only blocks the size of A and D are ever
+    // inverted, and SingularMatrix is returned when either A or S is singular, even if the whole
+    // matrix is not
+    pub fn block2x2_inverse(
+        a: &Matrix,
+        b: &Matrix,
+        c: &Matrix,
+        d: &Matrix,
+    ) -> Result<Vec<Vec<Self>>, MatrixError> {
+        let (a_inverse, a_inverse_b, schur) = Matrix::block2x2_schur(a, b, c, d)?;
+        let schur_inverse = schur.unrounded_inverse()?;
+        let c_a_inverse = functions::product(&c.data, &a_inverse);
+
+        let negate = |data: Data| -> Data {
+            data.into_iter()
+                .map(|row| row.into_iter().map(|num| -num).collect())
+                .collect()
+        };
+        let top_right = negate(functions::product(&a_inverse_b, &schur_inverse));
+        let bottom_left = negate(functions::product(&schur_inverse, &c_a_inverse));
+        let top_left = functions::sum(
+            &a_inverse,
+            &functions::product(&top_right, &c_a_inverse),
+            -1.0,
+        );
+
+        Ok(vec![
+            vec![functions::rounded(top_left), functions::rounded(top_right)],
+            vec![
+                functions::rounded(bottom_left),
+                functions::rounded(schur_inverse),
+            ],
+        ])
+    }
+
+    // det([A B; C D]) = det(A)·det(D − CA⁻¹B), which needs A to be nonsingular
+    pub fn block2x2_determinant(
+        a: &Matrix,
+        b: &Matrix,
+        c: &Matrix,
+        d: &Matrix,
+    ) -> Result<f64, MatrixError> {
+        let (_, _, schur) = Matrix::block2x2_schur(a, b, c, d)?;
+
+        Ok(crate::round_result(
+            a.settings_lu()?.unrounded_determinant() * schur.settings_lu()?.unrounded_determinant(),
+        ))
+    }
+
+    pub fn equilibrate(&self) -> Equilibration {
+        let row_scales: Vec<f64> = self
+            .data
+            .iter()
+            .map(|row| power_of_two_reciprocal(row.iter().fold(0.0, |max, num| num.abs().max(max))))
+            .collect();
+
+        let column_scales: Vec<f64> = (0..self.columns)
+            .map(|column| {
+                power_of_two_reciprocal(
+                    self.data
+                        .iter()
+                        .zip(row_scales.iter())
+                        .fold(0.0, |max, (row, scale)| {
+                            (row[column] * scale).abs().max(max)
+                        }),
+                )
+            })
+            .collect();
+
+        let mut matrix = self.clone();
+        matrix
+            .data
+            .iter_mut()
+            .zip(row_scales.iter())
+            .for_each(|(row, row_scale)| {
+                row.iter_mut()
+                    .zip(column_scales.iter())
+                    .for_each(|(num, column_scale)| *num *= row_scale * column_scale)
+            });
+
+        Equilibration {
+            matrix,
+            row_scales,
+            column_scales,
+        }
+    }
+
+    // The lower triangular L with A = LLᵀ, which exists exactly when A is symmetric positive
+    // definite. Solving with L and Lᵀ takes half the work of an LU decomposition
+    pub fn cholesky(&self) -> Result<Matrix, MatrixError> {
+        self.unrounded_cholesky().map(functions::rounded)
+    }
+
+    pub(crate) fn unrounded_cholesky(&self) -> Result<Data, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        if !self.is_symmetric() {
+            return Err(MatrixError::NonSymmetricMatrix);
+        }
+
+        let n = self.rows;
+        let _span = Span::new("cholesky", &[self.shape()], trace::cube(n) / 3);
+        let mut lower = vec![vec![0.0; n]; n];
+        for i in 0..n {
+            for j in 0..=i {
+                let dot: f64 = (0..j).map(|k| lower[i][k] * lower[j][k]).sum();
+                if i == j {
+                    let pivot = self.data[i][i] - dot;
+                    if pivot <= 0.0 || pivot.is_nan() {
+                        return Err(MatrixError::NotPositiveDefinite);
+                    }
+                    lower[i][i] = pivot.sqrt();
+                } else {
+                    lower[i][j] = (self.data[i][j] - dot) / lower[j][j];
+                }
+            }
+        }
+
+        Ok(lower)
+    }
+
+    pub fn pivoted_cholesky(&self, tolerance: f64) -> Result<PivotedCholesky, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        if !self.is_symmetric() {
+            return Err(MatrixError::NotPositiveSemidefinite);
+        }
+
+        let n = self.rows;
+        let mut data = self.data.to_vec();
+        let mut lower = Matrix::new_zero_matrix(n, n).unwrap();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut rank = n;
+
+        for k in 0..n {
+            let residuals: Vec<f64> = (k..n)
+                .map(|i| data[i][i] - (0..k).map(|j| lower.data[i][j].powi(2)).sum::<f64>())
+                .collect();
+
+            let (offset, pivot) = residuals.iter().enumerate().fold(
+                (0, f64::NEG_INFINITY),
+                |(max_index, max), (index, residual)| {
+                    if *residual > max {
+                        (index, *residual)
+                    } else {
+                        (max_index, max)
+                    }
+                },
+            );
+
+            if pivot <= tolerance {
+                if residuals.iter().any(|residual| *residual < -tolerance) {
+                    return Err(MatrixError::NotPositiveSemidefinite);
+                }
+
+                rank = k;
+                break;
+            }
+
+            let pivot_row = k + offset;
+            if pivot_row != k {
+                data.swap(k, pivot_row);
+                data.iter_mut().for_each(|row| row.swap(k, pivot_row));
+                lower.data.swap(k, pivot_row);
+                permutation.swap(k, pivot_row);
+            }
+
+            lower.data[k][k] = pivot.sqrt();
+            let column: Vec<f64> = ((k + 1)..n)
+                .map(|i| {
+                    let dot: f64 = (0..k).map(|j| lower.data[i][j] * lower.data[k][j]).sum();
+                    (data[i][k] - dot) / lower.data[k][k]
+                })
+                .collect();
+            column
+                .into_iter()
+                .enumerate()
+                .for_each(|(offset, num)| lower.data[k + 1 + offset][k] = num);
+        }
+
+        lower.data.iter_mut().for_each(|row| {
+            row.iter_mut()
+                .for_each(|num| *num = crate::round_result(*num))
+        });
+
+        Ok(PivotedCholesky {
+            lower,
+            permutation,
+            rank,
+        })
+    }
+
+    pub fn inverse_rank1_update(
+        inverse: &Matrix,
+        u: &Matrix,
+        v: &Matrix,
+    ) -> Result<Self, MatrixError> {
+        for vector in [u, v] {
+            if vector.columns != 1 {
+                return Err(MatrixError::DimensionMismatch {
+                    expected: (vector.rows, 1),
+                    actual: vector.shape(),
+                });
+            }
+        }
+
+        Self::inverse_low_rank_update(inverse, u, v)
+    }
+
+    // Woodbury identity: (A + UVᵀ)⁻¹ = A⁻¹ - A⁻¹U(I + VᵀA⁻¹U)⁻¹VᵀA⁻¹
+    pub fn inverse_low_rank_update(
+        inverse: &Matrix,
+        u: &Matrix,
+        v: &Matrix,
+    ) -> Result<Self, MatrixError> {
+        if !inverse.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        if u.rows != inverse.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (inverse.rows, u.columns),
+                actual: u.shape(),
+            });
+        }
+
+        // V must match U exactly, so that I + VᵀA⁻¹U is square
+        if v.shape() != u.shape() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: u.shape(),
+                actual: v.shape(),
+            });
+        }
+
+        let v_transpose = v.transpose();
+        let inverse_u = (inverse * u)?;
+        let v_transpose_inverse = (&v_transpose * inverse)?;
+        let capacitance =
+            (&Matrix::nth_identity(u.columns)? + &(&v_transpose * &inverse_u)?)?.inverse()?;
+        let correction = (&(&inverse_u * &capacitance)? * &v_transpose_inverse)?;
+
+        inverse - &correction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn transpose() {
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![7.2, 9.3], vec![13.8, 2.7], vec![5.1, 6.4]]).unwrap(),
+            test_utils::generic_examples()[0].transpose()
+        )
+    }
+
+    #[test]
+    fn symmetrize() {
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[0].symmetrize().unwrap_err()
+        );
+
+        let symmetric = test_utils::square_examples()[2].symmetrize().unwrap();
+        assert!(symmetric.is_symmetric());
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                vec![2.1, 9.05, 4.7],
+                vec![9.05, 1.6, 9.75],
+                vec![4.7, 9.75, 0.8],
+            ])
+            .unwrap(),
+            symmetric
+        );
+    }
+
+    #[test]
+    fn trace() {
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[0].trace().unwrap_err()
+        );
+
+        let [matrix_1x1, matrix_2x2, matrix_3x3, matrix_5x5] = test_utils::square_examples();
+        assert_eq!(2.5, matrix_1x1.trace().unwrap());
+        assert_eq!(11.2, matrix_2x2.trace().unwrap());
+        assert_eq!(4.5, matrix_3x3.trace().unwrap());
+        assert_eq!(6.1, matrix_5x5.trace().unwrap());
+    }
+
+    #[test]
+    fn trace_of_product() {
+        let [matrix_2x3, another_matrix_2x3, matrix_4x2] = test_utils::generic_examples();
+        let matrix_3x2 = another_matrix_2x3.transpose();
+
+        assert_eq!(
+            (&matrix_2x3 * &matrix_3x2).unwrap().trace().unwrap(),
+            matrix_2x3.trace_of_product(&matrix_3x2).unwrap()
+        );
+        assert_eq!(
+            (&matrix_3x2 * &matrix_2x3).unwrap().trace().unwrap(),
+            matrix_3x2.trace_of_product(&matrix_2x3).unwrap()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (3, 2),
+                actual: (4, 2)
+            },
+            matrix_2x3.trace_of_product(&matrix_4x2).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn determinant() {
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[0].determinant().unwrap_err()
+        );
+
+        let [matrix_1x1, matrix_2x2, matrix_3x3, matrix_5x5] = test_utils::square_examples();
+
+        assert_eq!(2.5, matrix_1x1.determinant().unwrap());
+        assert_eq!(26.51, matrix_2x2.determinant().unwrap());
+        assert_eq!(492.164, matrix_3x3.determinant().unwrap());
+        assert_eq!(-2204.89804, matrix_5x5.determinant().unwrap());
+        assert_eq!(
+            0.0,
+            Matrix::new_zero_matrix(3, 3)
+                .unwrap()
+                .determinant()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn adjoint() {
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[0].adjoint().unwrap_err()
+        );
+
+        let [matrix_1x1, matrix_2x2, matrix_3x3, _] = test_utils::square_examples();
+
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1.0]]).unwrap(),
+            matrix_1x1.adjoint().unwrap()
+        );
+
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![6.7, -2.8], vec![-1.3, 4.5]]).unwrap(),
+            matrix_2x2.adjoint().unwrap()
+        );
+
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                vec![-87.28, 35.29, 64.24],
+                vec![35.76, -18.97, 14.28],
+                vec![93.88, 31.4, -78.12],
+            ])
+            .unwrap(),
+            matrix_3x3.adjoint().unwrap()
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[0].inverse().unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::SingularMatrix,
+            Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 4.0]])
+                .unwrap()
+                .inverse()
+                .unwrap_err()
+        );
+
+        let [matrix_1x1, matrix_2x2, matrix_3x3, _] = test_utils::square_examples();
+
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![0.4]]).unwrap(),
+            matrix_1x1.inverse().unwrap()
+        );
+
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![0.25273, -0.10562], vec![-0.04904, 0.16975]]).unwrap(),
+            matrix_2x2.inverse().unwrap()
+        );
+
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                vec![-0.17734, 0.07170, 0.13053],
+                vec![0.07266, -0.03854, 0.02901],
+                vec![0.19075, 0.06380, -0.15873]
+            ])
+            .unwrap(),
+            matrix_3x3.inverse().unwrap()
+        );
+
+        let tiny = Matrix::new_with_data(vec![vec![1e-6, 0.0], vec![0.0, 1e-6]]).unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1e6, 0.0], vec![0.0, 1e6]]).unwrap(),
+            tiny.inverse().unwrap()
+        );
+        assert_eq!(
+            MatrixError::SingularMatrix,
+            tiny.inverse_within(2.0).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn schur_complement() {
+        let matrix = Matrix::new_with_data(vec![
+            vec![4.0, 2.0, 1.0],
+            vec![2.0, 3.0, 0.0],
+            vec![1.0, 0.0, 2.0],
+        ])
+        .unwrap();
+
+        // 2 - [1 0] [4 2; 2 3]⁻¹ [1; 0] = 2 - 3/8
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1.625]]).unwrap(),
+            matrix.schur_complement(2).unwrap()
+        );
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![2.0, -0.5], vec![-0.5, 1.75]]).unwrap(),
+            matrix.schur_complement(1).unwrap()
+        );
+
+        let singular_block = Matrix::new_with_data(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![2.0, 4.0, 5.0],
+            vec![3.0, 5.0, 6.0],
+        ])
+        .unwrap();
+        assert_eq!(
+            MatrixError::SingularMatrix,
+            singular_block.schur_complement(2).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            matrix.schur_complement(3).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn block2x2_inverse() {
+        let matrix = &test_utils::square_examples()[3];
+        let blocks = matrix.blocks(&[2], &[2]).unwrap();
+        let (a, b) = (&blocks[0][0], &blocks[0][1]);
+        let (c, d) = (&blocks[1][0], &blocks[1][1]);
+
+        let inverse = Matrix::from_blocks(&Matrix::block2x2_inverse(a, b, c, d).unwrap()).unwrap();
+        assert!(
+            inverse
+                .distance(&matrix.inverse().unwrap(), crate::Norm::Infinity)
+                .unwrap()
+                < 1e-4
+        );
+        let determinant = matrix.determinant().unwrap();
+        assert!(
+            (Matrix::block2x2_determinant(a, b, c, d).unwrap() - determinant).abs()
+                < 1e-9 * determinant.abs()
+        );
+
+        // [I 0; C I] has the inverse [I 0; -C I] and determinant 1
+        let identity = Matrix::nth_identity(2).unwrap();
+        let zero = Matrix::new_zero_matrix(2, 2).unwrap();
+        let lower = Matrix::new_with_data(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        assert_eq!(
+            vec![
+                vec![identity.clone(), zero.clone()],
+                vec![-1.0 * &lower, identity.clone()]
+            ],
+            Matrix::block2x2_inverse(&identity, &zero, &lower, &identity).unwrap()
+        );
+        assert_eq!(
+            Ok(1.0),
+            Matrix::block2x2_determinant(&identity, &zero, &lower, &identity)
+        );
+
+        // Nonsingular as a whole, but with a singular leading block
+        let one = Matrix::nth_identity(1).unwrap();
+        let nought = Matrix::new_zero_matrix(1, 1).unwrap();
+        assert_eq!(
+            MatrixError::SingularMatrix,
+            Matrix::block2x2_inverse(&nought, &one, &one, &nought).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 2),
+                actual: (1, 1)
+            },
+            Matrix::block2x2_inverse(&identity, &zero, &lower, &one).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            Matrix::block2x2_determinant(
+                &test_utils::generic_examples()[0],
+                &zero,
+                &lower,
+                &identity
+            )
+            .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn inverse_rank1_update() {
+        let inverse = Matrix::nth_identity(2).unwrap();
+        let u = Matrix::new_with_data(vec![vec![1.0], vec![1.0]]).unwrap();
+        let v = Matrix::new_with_data(vec![vec![1.0], vec![0.0]]).unwrap();
+
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![0.5, 0.0], vec![-0.5, 1.0]]).unwrap(),
+            Matrix::inverse_rank1_update(&inverse, &u, &v).unwrap()
+        );
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 1),
+                actual: (2, 2)
+            },
+            Matrix::inverse_rank1_update(&inverse, &u, &Matrix::nth_identity(2).unwrap())
+                .unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            Matrix::inverse_rank1_update(&test_utils::generic_examples()[0], &u, &v).unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::SingularMatrix,
+            Matrix::inverse_rank1_update(&inverse, &v, &-&v).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn inverse_low_rank_update() {
+        let inverse = Matrix::new_diagonal_matrix(&[1.0, 0.5, 0.25]).unwrap();
+        let u =
+            Matrix::new_with_data(vec![vec![1.0, 0.0], vec![0.0, 2.0], vec![0.0, 0.0]]).unwrap();
+        let v =
+            Matrix::new_with_data(vec![vec![1.0, 0.0], vec![0.0, 0.0], vec![0.0, 4.0]]).unwrap();
+
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                vec![2.0, 0.0, 0.0],
+                vec![0.0, 2.0, 8.0],
+                vec![0.0, 0.0, 4.0]
+            ])
+            .unwrap()
+            .inverse()
+            .unwrap(),
+            Matrix::inverse_low_rank_update(&inverse, &u, &v).unwrap()
+        );
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (3, 2),
+                actual: (3, 3)
+            },
+            Matrix::inverse_low_rank_update(&inverse, &u, &Matrix::nth_identity(3).unwrap())
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn equilibrate() {
+        let row_scaled = Matrix::new_with_data(vec![vec![4.0, 8.0], vec![0.5, 0.25]])
+            .unwrap()
+            .equilibrate();
+        assert_eq!(
+            &Matrix::new_with_data(vec![vec![0.5, 1.0], vec![1.0, 0.5]]).unwrap(),
+            row_scaled.matrix()
+        );
+        assert_eq!(&[0.125, 2.0], row_scaled.row_scales());
+        assert_eq!(&[1.0, 1.0], row_scaled.column_scales());
+
+        let column_scaled = Matrix::new_with_data(vec![vec![1.0, 16.0], vec![0.0, 16.0]])
+            .unwrap()
+            .equilibrate();
+        assert_eq!(
+            &Matrix::new_with_data(vec![vec![1.0, 1.0], vec![0.0, 1.0]]).unwrap(),
+            column_scaled.matrix()
+        );
+        assert_eq!(&[0.0625, 0.0625], column_scaled.row_scales());
+        assert_eq!(&[16.0, 1.0], column_scaled.column_scales());
+
+        let zero_row = Matrix::new_with_data(vec![vec![0.0, 0.0], vec![2.0, 4.0]])
+            .unwrap()
+            .equilibrate();
+        assert_eq!(&[1.0, 0.25], zero_row.row_scales());
+    }
+
+    #[test]
+    fn cholesky() {
+        let matrix = Matrix::new_with_data(vec![
+            vec![4.0, 12.0, -16.0],
+            vec![12.0, 37.0, -43.0],
+            vec![-16.0, -43.0, 98.0],
+        ])
+        .unwrap();
+        let lower = matrix.cholesky().unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                vec![2.0, 0.0, 0.0],
+                vec![6.0, 1.0, 0.0],
+                vec![-8.0, 5.0, 3.0],
+            ])
+            .unwrap(),
+            lower
+        );
+        assert_eq!(matrix, (&lower * &lower.transpose()).unwrap());
+
+        let spd = Matrix::new_with_data(vec![vec![2.0, 1.0], vec![1.0, 3.0]]).unwrap();
+        let lower = spd.cholesky().unwrap();
+        let product = (&lower * &lower.transpose()).unwrap();
+        assert!(product.distance(&spd, crate::Norm::Infinity).unwrap() < 1e-4);
+
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[0].cholesky().unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::NonSymmetricMatrix,
+            test_utils::square_examples()[1].cholesky().unwrap_err()
+        );
+        // Semidefinite isn't enough
+        assert_eq!(
+            MatrixError::NotPositiveDefinite,
+            Matrix::new_with_data(vec![vec![4.0, 2.0], vec![2.0, 1.0]])
+                .unwrap()
+                .cholesky()
+                .unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::NotPositiveDefinite,
+            Matrix::new_diagonal_matrix(&[1.0, -1.0])
+                .unwrap()
+                .cholesky()
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn pivoted_cholesky() {
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[0]
+                .pivoted_cholesky(1e-10)
+                .unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::NotPositiveSemidefinite,
+            test_utils::square_examples()[2]
+                .pivoted_cholesky(1e-10)
+                .unwrap_err()
+        );
+
+        assert_eq!(
+            MatrixError::NotPositiveSemidefinite,
+            Matrix::new_diagonal_matrix(&[1.0, -1.0])
+                .unwrap()
+                .pivoted_cholesky(1e-10)
+                .unwrap_err()
+        );
+
+        let semidefinite = Matrix::new_with_data(vec![vec![4.0, 2.0], vec![2.0, 1.0]])
+            .unwrap()
+            .pivoted_cholesky(1e-10)
+            .unwrap();
+        assert_eq!(1, semidefinite.rank());
+        assert_eq!(&[0, 1], semidefinite.permutation());
+        assert_eq!(
+            &Matrix::new_with_data(vec![vec![2.0, 0.0], vec![1.0, 0.0]]).unwrap(),
+            semidefinite.lower()
+        );
+
+        let definite = Matrix::new_with_data(vec![vec![5.0, 4.0], vec![4.0, 16.0]])
+            .unwrap()
+            .pivoted_cholesky(1e-10)
+            .unwrap();
+        assert_eq!(2, definite.rank());
+        assert_eq!(&[1, 0], definite.permutation());
+        assert_eq!(
+            &Matrix::new_with_data(vec![vec![4.0, 0.0], vec![1.0, 2.0]]).unwrap(),
+            definite.lower()
+        );
+    }
+}
@@ -0,0 +1,109 @@
+// Wall time and estimated floating point operations of computations, for comparing algorithms and
+// reporting how much work a result took
+
+use std::{
+    cell::Cell,
+    fmt::{self, Display, Formatter},
+    time::{Duration, Instant},
+};
+
+thread_local! {
+    static FLOPS: Cell<u64> = const { Cell::new(0) };
+}
+
+pub(crate) fn count_flops(flops: u64) {
+    FLOPS.with(|count| count.set(count.get().wrapping_add(flops)));
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct OperationStats {
+    elapsed: Duration,
+    flops: u64,
+}
+
+impl OperationStats {
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    // The estimates of the operations called, from the shapes of their operands. Work that happens
+    // outside them, such as a loop over elements written by the caller, isn't counted
+    pub fn flops(&self) -> u64 {
+        self.flops
+    }
+}
+
+// "computed in 12.0ms, ~2.1 MFLOPs"
+impl Display for OperationStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "computed in {:.1?}, ~", self.elapsed)?;
+        let flops = self.flops as f64;
+        match ["", "k", "M", "G", "T"]
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(power, _)| flops >= 1000f64.powi(*power as i32))
+        {
+            Some((0, _)) | None => write!(f, "{} FLOPs", self.flops),
+            Some((power, prefix)) => {
+                write!(f, "{:.1} {prefix}FLOPs", flops / 1000f64.powi(power as i32))
+            }
+        }
+    }
+}
+
+// Runs `computation` and reports how long it took and the flops of the matrix operations it called
+// on this thread, such as `stats::measure(|| &a * &b)`
+pub fn measure<T>(computation: impl FnOnce() -> T) -> (T, OperationStats) {
+    let flops = FLOPS.with(Cell::get);
+    let started = Instant::now();
+    let result = computation();
+    let elapsed = started.elapsed();
+
+    (
+        result,
+        OperationStats {
+            elapsed,
+            flops: FLOPS.with(Cell::get).wrapping_sub(flops),
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils, Matrix};
+
+    #[test]
+    fn measure() {
+        let [matrix_2x3, _, matrix_4x2] = test_utils::generic_examples();
+        let (product, stats) = super::measure(|| (&matrix_4x2 * &matrix_2x3).unwrap());
+        assert_eq!((4, 3), product.shape());
+        assert_eq!(48, stats.flops());
+
+        // An inverse counts once, not also for the solve it is built on
+        let square = &test_utils::square_examples()[2];
+        let (_, stats) = super::measure(|| {
+            square.inverse().unwrap();
+            square.determinant().unwrap()
+        });
+        assert_eq!(54 + 18, stats.flops());
+
+        let (_, stats) = super::measure(|| Matrix::nth_identity(3).unwrap());
+        assert_eq!(0, stats.flops());
+    }
+
+    #[test]
+    fn display() {
+        let stats = |flops| OperationStats {
+            elapsed: Duration::from_micros(12_340),
+            flops,
+        };
+        assert_eq!("computed in 12.3ms, ~48 FLOPs", stats(48).to_string());
+        assert_eq!(
+            "computed in 12.3ms, ~2.1 MFLOPs",
+            stats(2_140_000).to_string()
+        );
+        assert_eq!("computed in 12.3ms, ~1.0 kFLOPs", stats(1000).to_string());
+    }
+}
@@ -0,0 +1,427 @@
+use crate::{functions, Matrix, MatrixError};
+
+// Sweeps of the one-sided Jacobi method, which like the two-sided one converges quadratically
+const MAX_SWEEPS: usize = 100;
+
+impl Matrix {
+    // Singular values in descending order with the matching left and right singular vectors as the
+    // columns of U (rows × r) and V (columns × r), where r is the smaller dimension. One-sided Jacobi
+    // rotations orthogonalize the columns of A directly, so unlike AᵀA small singular values keep
+    // their accuracy
+    pub(crate) fn singular_value_decomposition(&self) -> (Vec<f64>, Vec<Vec<f64>>, Vec<Vec<f64>>) {
+        if self.is_symmetric() {
+            return self.symmetric_singular_value_decomposition();
+        }
+
+        if self.rows < self.columns {
+            let (singular_values, u, v) = self.transpose().singular_value_decomposition();
+            return (singular_values, v, u);
+        }
+
+        let (m, n) = self.shape();
+        let mut u = self.data.to_vec();
+        let mut v = Matrix::nth_identity(n).unwrap().take_data();
+
+        for _ in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in (p + 1)..n {
+                    let (alpha, beta, gamma) = u.iter().fold((0.0, 0.0, 0.0), |(a, b, g), row| {
+                        (
+                            a + row[p] * row[p],
+                            b + row[q] * row[q],
+                            g + row[p] * row[q],
+                        )
+                    });
+                    if gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (zeta * zeta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+
+                    for row in u.iter_mut().chain(v.iter_mut()) {
+                        let (row_p, row_q) = (row[p], row[q]);
+                        row[p] = c * row_p - s * row_q;
+                        row[q] = s * row_p + c * row_q;
+                    }
+                }
+            }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        let norms: Vec<f64> = (0..n)
+            .map(|j| u.iter().map(|row| row[j] * row[j]).sum::<f64>().sqrt())
+            .collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|i, j| norms[*j].total_cmp(&norms[*i]));
+
+        let left = (0..m)
+            .map(|i| {
+                order
+                    .iter()
+                    .map(|j| match norms[*j] {
+                        0.0 => 0.0,
+                        norm => u[i][*j] / norm,
+                    })
+                    .collect()
+            })
+            .collect();
+        let right = v
+            .iter()
+            .map(|row| order.iter().map(|j| row[*j]).collect())
+            .collect();
+
+        (order.iter().map(|j| norms[*j]).collect(), left, right)
+    }
+
+    // A symmetric A = QΛQᵀ already has an SVD with the absolute eigenvalues as singular values, Q as
+    // V and Q with the columns of negative eigenvalues flipped as U
+    fn symmetric_singular_value_decomposition(&self) -> (Vec<f64>, Vec<Vec<f64>>, Vec<Vec<f64>>) {
+        let (eigenvalues, eigenvectors) = self.symmetric_eigen_decomposition();
+        let mut order: Vec<usize> = (0..eigenvalues.len()).collect();
+        order.sort_by(|i, j| eigenvalues[*j].abs().total_cmp(&eigenvalues[*i].abs()));
+
+        let left = eigenvectors
+            .iter()
+            .map(|row| {
+                order
+                    .iter()
+                    .map(|j| match eigenvalues[*j] < 0.0 {
+                        true => -row[*j],
+                        false => row[*j],
+                    })
+                    .collect()
+            })
+            .collect();
+        let right = eigenvectors
+            .iter()
+            .map(|row| order.iter().map(|j| row[*j]).collect())
+            .collect();
+
+        (
+            order.iter().map(|j| eigenvalues[*j].abs()).collect(),
+            left,
+            right,
+        )
+    }
+
+    // The closest matrix of rank at most k in both the 2-norm and the Frobenius norm, along with the
+    // Frobenius norm of what was left out, which is the root of the sum of the discarded squared
+    // singular values
+    pub fn truncated_svd(&self, k: usize) -> Result<(Matrix, f64), MatrixError> {
+        if k == 0 || k > self.rows.min(self.columns) {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        let (singular_values, u, v) = self.singular_value_decomposition();
+
+        let mut approximation = Matrix::new_zero_matrix(self.rows, self.columns).unwrap();
+        for (i, row) in approximation.data.iter_mut().enumerate() {
+            for (j, num) in row.iter_mut().enumerate() {
+                *num = crate::round_result(crate::compensated_sum(
+                    (0..k).map(|l| singular_values[l] * u[i][l] * v[j][l]),
+                ));
+            }
+        }
+
+        let error = crate::compensated_sum(singular_values[k..].iter().map(|value| value * value));
+
+        Ok((approximation, crate::round_result(error.sqrt())))
+    }
+}
+
+// Wraps unrounded intermediate results, which are already known to be rectangular and nonempty
+fn unrounded(data: Vec<Vec<f64>>) -> Matrix {
+    Matrix {
+        rows: data.len(),
+        columns: data[0].len(),
+        data: data.into(),
+    }
+}
+
+impl Matrix {
+    // Orthonormal bases of the column space and the null space as the columns of the results, from
+    // the singular vectors whose singular values are larger than `tolerance` times the largest one
+    // and the rest, unrounded. None for a space that only holds zero. Zero rows padding a wide
+    // matrix to a square one give the full set of right singular vectors without changing the
+    // singular values
+    fn range_and_null(&self, tolerance: f64) -> (Option<Matrix>, Option<Matrix>) {
+        let padded = if self.rows < self.columns {
+            let mut data = self.data.to_vec();
+            data.resize(self.columns, vec![0.0; self.columns]);
+            unrounded(data)
+        } else {
+            self.clone()
+        };
+        let (singular_values, u, v) = padded.singular_value_decomposition();
+        let threshold = tolerance * singular_values[0];
+        let rank = singular_values
+            .iter()
+            .take_while(|value| **value > threshold)
+            .count();
+
+        let range = (rank > 0).then(|| {
+            unrounded(
+                u[..self.rows]
+                    .iter()
+                    .map(|row| row[..rank].to_vec())
+                    .collect(),
+            )
+        });
+        let null = (rank < self.columns)
+            .then(|| unrounded(v.into_iter().map(|row| row[rank..].to_vec()).collect()));
+
+        (range, null)
+    }
+
+    fn default_rank_tolerance(&self) -> f64 {
+        self.rows.max(self.columns) as f64 * f64::EPSILON
+    }
+
+    // An orthonormal basis of the column space, like MATLAB's orth, with singular values up to
+    // max(rows, columns)·ε times the largest treated as zero. None for a zero matrix
+    pub fn orth(&self) -> Option<Matrix> {
+        self.orth_within(self.default_rank_tolerance())
+    }
+
+    pub fn orth_within(&self, tolerance: f64) -> Option<Matrix> {
+        self.range_and_null(tolerance)
+            .0
+            .map(|basis| functions::rounded(basis.take_data()))
+    }
+
+    // An orthonormal basis of the null space, like MATLAB's null, with the same tolerance as `orth`.
+    // None when the columns are independent
+    pub fn null(&self) -> Option<Matrix> {
+        self.null_within(self.default_rank_tolerance())
+    }
+
+    pub fn null_within(&self, tolerance: f64) -> Option<Matrix> {
+        self.range_and_null(tolerance)
+            .1
+            .map(|basis| functions::rounded(basis.take_data()))
+    }
+
+    // The angles in radians between the spaces spanned by the columns of the two matrices, from the
+    // smallest up, as many as the smaller of their ranks. Cosines lose the small angles to rounding,
+    // so those come from the sines, the singular values of the part of one basis outside the other
+    // space, as suggested by Björck and Golub
+    pub fn principal_angles(&self, other: &Matrix) -> Result<Vec<f64>, MatrixError> {
+        if self.rows != other.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.rows, other.columns),
+                actual: other.shape(),
+            });
+        }
+
+        let (Some(basis), Some(other_basis)) = (
+            self.range_and_null(self.default_rank_tolerance()).0,
+            other.range_and_null(other.default_rank_tolerance()).0,
+        ) else {
+            return Ok(vec![]);
+        };
+
+        let basis_transpose = basis.transpose();
+        let cosines = unrounded(functions::product(&basis_transpose.data, &other_basis.data))
+            .singular_value_decomposition()
+            .0;
+
+        let projection = functions::product(
+            &basis.data,
+            &functions::product(&basis_transpose.data, &other_basis.data),
+        );
+        let outside = other_basis
+            .data
+            .iter()
+            .zip(projection)
+            .map(|(row, projected)| row.iter().zip(projected).map(|(a, b)| a - b).collect())
+            .collect();
+        let mut sines = unrounded(outside).singular_value_decomposition().0;
+        sines.sort_by(f64::total_cmp);
+
+        Ok(cosines
+            .iter()
+            .zip(sines)
+            .map(|(cosine, sine)| {
+                let angle = if cosine * cosine < 0.5 {
+                    cosine.min(1.0).acos()
+                } else {
+                    sine.min(1.0).asin()
+                };
+                crate::round_result(angle)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils, Norm};
+
+    #[test]
+    fn singular_value_decomposition() {
+        // Symmetric and indefinite, with eigenvalues 3 and -1, so U and V differ in the sign of a column
+        let symmetric = Matrix::new_with_data(vec![vec![1.0, 2.0], vec![2.0, 1.0]]).unwrap();
+        let singular_values = symmetric.singular_value_decomposition().0;
+        assert!(
+            (singular_values[0] - 3.0).abs() < 1e-12 && (singular_values[1] - 1.0).abs() < 1e-12
+        );
+
+        for matrix in test_utils::generic_examples()
+            .into_iter()
+            .chain([symmetric])
+        {
+            let (singular_values, u, v) = matrix.singular_value_decomposition();
+            assert!(singular_values.windows(2).all(|pair| pair[0] >= pair[1]));
+
+            for (i, row) in matrix.data.iter().enumerate() {
+                for (j, num) in row.iter().enumerate() {
+                    let product: f64 = (0..singular_values.len())
+                        .map(|l| singular_values[l] * u[i][l] * v[j][l])
+                        .sum();
+                    assert!((product - num).abs() < 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn truncated_svd() {
+        // Singular values 3 and 1
+        let matrix = Matrix::new_with_data(vec![vec![2.0, 1.0], vec![1.0, 2.0]]).unwrap();
+        assert_eq!(
+            (
+                Matrix::new_with_data(vec![vec![1.5, 1.5], vec![1.5, 1.5]]).unwrap(),
+                1.0
+            ),
+            matrix.truncated_svd(1).unwrap()
+        );
+        assert_eq!((matrix.clone(), 0.0), matrix.truncated_svd(2).unwrap());
+
+        let wide = test_utils::generic_examples()[0].clone();
+        let (approximation, error) = wide.truncated_svd(1).unwrap();
+        assert!(approximation.singular_value_decomposition().0[1] < 1e-4);
+        let residual = (&wide - &approximation).unwrap();
+        assert!((error - residual.norm(Norm::Frobenius)).abs() < 1e-4);
+
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            matrix.truncated_svd(0).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            wide.truncated_svd(3).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn principal_angles() {
+        use std::f64::consts::FRAC_PI_4;
+
+        // The xy-plane against the plane through the x-axis tilted by 45 degrees
+        let plane =
+            Matrix::new_with_data(vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![0.0, 0.0]]).unwrap();
+        let tilted =
+            Matrix::new_with_data(vec![vec![2.0, 0.0], vec![0.0, 1.0], vec![0.0, 1.0]]).unwrap();
+        assert_eq!(
+            vec![0.0, crate::round_result(FRAC_PI_4)],
+            plane.principal_angles(&tilted).unwrap()
+        );
+
+        // Dependent columns only span a line, so there is one angle
+        let line =
+            Matrix::new_with_data(vec![vec![0.0, 0.0], vec![1.0, 2.0], vec![1.0, 2.0]]).unwrap();
+        assert_eq!(
+            vec![crate::round_result(FRAC_PI_4)],
+            line.principal_angles(&plane).unwrap()
+        );
+
+        // Small angles keep their accuracy
+        let nearly = Matrix::new_with_data(vec![vec![1.0], vec![1e-4], vec![0.0]]).unwrap();
+        let x_axis = Matrix::new_with_data(vec![vec![1.0], vec![0.0], vec![0.0]]).unwrap();
+        assert_eq!(vec![0.0001], nearly.principal_angles(&x_axis).unwrap());
+
+        assert!(Matrix::new_zero_matrix(3, 1)
+            .unwrap()
+            .principal_angles(&plane)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (3, 3),
+                actual: (2, 3)
+            },
+            plane
+                .principal_angles(&test_utils::generic_examples()[0])
+                .unwrap_err()
+        );
+    }
+
+    #[test]
+    fn orth_and_null() {
+        // Rank 2, with the third column the sum of the first two
+        let matrix = Matrix::new_with_data(vec![
+            vec![1.0, 0.0, 1.0],
+            vec![0.0, 1.0, 1.0],
+            vec![1.0, 1.0, 2.0],
+            vec![2.0, 0.0, 2.0],
+        ])
+        .unwrap();
+
+        let range = matrix.orth().unwrap();
+        assert_eq!((4, 2), range.shape());
+        assert!(
+            crate::verify::verify_orthogonal(&range, 1e-4)
+                .unwrap()
+                .passed
+        );
+        // Only the rounding of the basis to five decimal places keeps it from spanning exactly the
+        // same space
+        assert!(matrix
+            .principal_angles(&range)
+            .unwrap()
+            .iter()
+            .all(|angle| *angle < 1e-4));
+
+        let null = matrix.null().unwrap();
+        assert_eq!((3, 1), null.shape());
+        assert!((&matrix * &null)
+            .unwrap()
+            .get_data()
+            .iter()
+            .flatten()
+            .all(|num| num.abs() < 1e-4));
+        let direction = 1.0 / 3.0_f64.sqrt();
+        assert!((null.get_element(0, 0).unwrap().abs() - direction).abs() < 1e-4);
+
+        // A wide matrix has a null space of at least the difference in dimensions
+        let [matrix_2x3, ..] = test_utils::generic_examples();
+        assert_eq!((3, 1), matrix_2x3.null().unwrap().shape());
+        assert_eq!((2, 2), matrix_2x3.orth().unwrap().shape());
+
+        let identity = Matrix::nth_identity(3).unwrap();
+        assert_eq!(None, identity.null());
+        assert_eq!(Some(identity.clone()), identity.orth());
+
+        let zero = Matrix::new_zero_matrix(2, 3).unwrap();
+        assert_eq!(None, zero.orth());
+        assert_eq!(Some(identity), zero.null());
+
+        // A looser tolerance drops the small singular value
+        let nearly_singular = Matrix::new_with_data(vec![vec![1.0, 0.0], vec![0.0, 1e-8]]).unwrap();
+        assert_eq!(None, nearly_singular.null());
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![0.0], vec![1.0]]).ok(),
+            nearly_singular.null_within(1e-6)
+        );
+        assert_eq!((2, 1), nearly_singular.orth_within(1e-6).unwrap().shape());
+    }
+}
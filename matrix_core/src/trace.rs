@@ -0,0 +1,155 @@
+// The main operations open a span for each call. The outermost span on a thread adds its flop
+// estimate to the count behind `stats::measure`, and with the `trace` feature it is also recorded
+// in a log kept for each thread
+
+use crate::{stats, Matrix};
+use std::cell::Cell;
+#[cfg(feature = "trace")]
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+// The oldest records are dropped beyond this, so a long session doesn't grow the log without bound
+#[cfg(feature = "trace")]
+const MAX_RECORDS: usize = 1000;
+
+#[cfg(feature = "trace")]
+#[derive(Debug, PartialEq, Clone)]
+pub struct OperationRecord {
+    pub operation: &'static str,
+    // The shapes of the matrices the operation was given, in order
+    pub operands: Vec<(usize, usize)>,
+    pub elapsed: Duration,
+    // The floating point operations the algorithm needs for operands of these shapes, counting
+    // additions and multiplications separately
+    pub flops: u64,
+}
+
+thread_local! {
+    // Spans open on this thread. Only the outermost one counts, so an inverse shows up as one
+    // operation rather than also as the solve it is built on
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+#[cfg(feature = "trace")]
+thread_local! {
+    static LOG: RefCell<VecDeque<OperationRecord>> = const { RefCell::new(VecDeque::new()) };
+}
+
+pub(crate) fn elements(matrix: &Matrix) -> u64 {
+    (matrix.rows * matrix.columns) as u64
+}
+
+pub(crate) fn cube(n: usize) -> u64 {
+    (n as u64).pow(3)
+}
+
+// Measures an operation from its creation until it is dropped
+pub(crate) struct Span {
+    #[cfg(feature = "trace")]
+    record: Option<(OperationRecord, Instant)>,
+}
+
+impl Span {
+    #[cfg_attr(not(feature = "trace"), allow(unused_variables))]
+    pub(crate) fn new(operation: &'static str, operands: &[(usize, usize)], flops: u64) -> Self {
+        let outermost = DEPTH.with(|depth| depth.replace(depth.get() + 1)) == 0;
+        if outermost {
+            stats::count_flops(flops);
+        }
+
+        #[cfg(feature = "trace")]
+        {
+            let record = outermost.then(|| {
+                let record = OperationRecord {
+                    operation,
+                    operands: operands.to_vec(),
+                    elapsed: Duration::ZERO,
+                    flops,
+                };
+                (record, Instant::now())
+            });
+            Span { record }
+        }
+
+        #[cfg(not(feature = "trace"))]
+        Span {}
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get() - 1));
+
+        #[cfg(feature = "trace")]
+        if let Some((mut record, started)) = self.record.take() {
+            record.elapsed = started.elapsed();
+            LOG.with(|log| {
+                let mut log = log.borrow_mut();
+                if log.len() == MAX_RECORDS {
+                    log.pop_front();
+                }
+                log.push_back(record);
+            });
+        }
+    }
+}
+
+#[cfg(feature = "trace")]
+impl Matrix {
+    // The operations recorded on this thread, oldest first, up to the last MAX_RECORDS of them
+    pub fn last_operations() -> Vec<OperationRecord> {
+        LOG.with(|log| log.borrow().iter().cloned().collect())
+    }
+
+    pub fn clear_operations() {
+        LOG.with(|log| log.borrow_mut().clear());
+    }
+}
+
+#[cfg(all(test, feature = "trace"))]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn last_operations() {
+        Matrix::clear_operations();
+        let [matrix_2x3, _, matrix_4x2] = test_utils::generic_examples();
+        let _ = &matrix_4x2 * &matrix_2x3;
+        let _ = &matrix_2x3 * &matrix_2x3;
+        let square = &test_utils::square_examples()[2];
+        square.inverse().unwrap();
+
+        let operations = Matrix::last_operations();
+        assert_eq!(
+            vec!["multiply", "multiply", "inverse"],
+            operations
+                .iter()
+                .map(|record| record.operation)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(vec![(4, 2), (2, 3)], operations[0].operands);
+        assert_eq!(48, operations[0].flops);
+        // Recorded even though the shapes don't match
+        assert_eq!(36, operations[1].flops);
+        // The solve that the inverse is built on isn't recorded separately
+        assert_eq!(vec![(3, 3)], operations[2].operands);
+        assert_eq!(54, operations[2].flops);
+
+        Matrix::clear_operations();
+        assert!(Matrix::last_operations().is_empty());
+    }
+
+    #[test]
+    fn bounded() {
+        Matrix::clear_operations();
+        let matrix = &test_utils::square_examples()[0];
+        for _ in 0..MAX_RECORDS + 5 {
+            let _ = matrix + matrix;
+        }
+        assert_eq!(MAX_RECORDS, Matrix::last_operations().len());
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{Matrix, MatrixError};
+
+// Transformations of d-dimensional points use (d + 1)×(d + 1) matrices in homogeneous coordinates,
+// so that translations compose by multiplication like every other transformation. A rotation in
+// homogeneous coordinates is `new_rotation_matrix(d + 1, i, j, angle)` with i and j below d
+impl Matrix {
+    pub fn new_translation_matrix(offsets: &[f64]) -> Result<Self, MatrixError> {
+        if offsets.is_empty() {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        let dimension = offsets.len();
+        let mut translation = Matrix::nth_identity(dimension + 1)?;
+        offsets
+            .iter()
+            .enumerate()
+            .for_each(|(axis, offset)| translation.data[axis][dimension] = *offset);
+
+        Ok(translation)
+    }
+
+    pub fn new_scaling_matrix(factors: &[f64]) -> Result<Self, MatrixError> {
+        if factors.is_empty() {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        Matrix::new_diagonal_matrix(&[factors, &[1.0]].concat())
+    }
+
+    // Applies this homogeneous transformation to each row of `points`, which holds one point per row
+    pub fn transform_points(&self, points: &Matrix) -> Result<Self, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NonSquareMatrix);
+        }
+
+        // A transformation of d-dimensional points is (d + 1)×(d + 1)
+        if self.rows != points.columns + 1 {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (points.rows, self.rows - 1),
+                actual: points.shape(),
+            });
+        }
+
+        let dimension = points.columns;
+        let data = points
+            .data
+            .iter()
+            .map(|point| {
+                let homogeneous: Vec<f64> = (0..self.rows)
+                    .map(|row| {
+                        crate::compensated_sum(
+                            point
+                                .iter()
+                                .chain([1.0].iter())
+                                .zip(self.data[row].iter())
+                                .map(|(coordinate, num)| coordinate * num),
+                        )
+                    })
+                    .collect();
+                (0..dimension)
+                    .map(|axis| homogeneous[axis] / homogeneous[dimension])
+                    .collect::<Vec<f64>>()
+            })
+            .collect::<Vec<_>>();
+
+        if data.iter().flatten().any(|num| !num.is_finite()) {
+            return Err(MatrixError::InvalidArgument);
+        }
+
+        Matrix::new_with_data(
+            data.into_iter()
+                .map(|row| row.into_iter().map(crate::round_result).collect())
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn transformations() {
+        assert_eq!(
+            Matrix::new_with_data(vec![
+                vec![1.0, 0.0, 2.0],
+                vec![0.0, 1.0, -3.0],
+                vec![0.0, 0.0, 1.0]
+            ])
+            .unwrap(),
+            Matrix::new_translation_matrix(&[2.0, -3.0]).unwrap()
+        );
+        assert_eq!(
+            Matrix::new_diagonal_matrix(&[2.0, 0.5, 1.0]).unwrap(),
+            Matrix::new_scaling_matrix(&[2.0, 0.5]).unwrap()
+        );
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_translation_matrix(&[]).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::ZeroDimension,
+            Matrix::new_scaling_matrix(&[]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn transform_points() {
+        let points = Matrix::new_with_data(vec![vec![1.0, 0.0], vec![2.0, 2.0]]).unwrap();
+
+        // Rotating a quarter turn and then moving right by 1
+        let rotation = Matrix::new_rotation_matrix(3, 0, 1, std::f64::consts::FRAC_PI_2).unwrap();
+        let translation = Matrix::new_translation_matrix(&[1.0, 0.0]).unwrap();
+        let transform = (&translation * &rotation).unwrap();
+
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![1.0, 1.0], vec![-1.0, 2.0]]).unwrap(),
+            transform.transform_points(&points).unwrap()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 3),
+                actual: (2, 2)
+            },
+            Matrix::nth_identity(4)
+                .unwrap()
+                .transform_points(&points)
+                .unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::NonSquareMatrix,
+            test_utils::generic_examples()[0]
+                .transform_points(&points)
+                .unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,214 @@
+// Checks of computed results against the identities they should satisfy, such as A·A⁻¹ = I. The
+// residuals are formed without rounding, so they show errors far below the five decimal places
+// that results are rounded to
+
+use crate::{
+    functions::{self, Data},
+    Matrix, MatrixError,
+};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ResidualReport {
+    // Frobenius norm of the difference between the two sides of the identity
+    pub residual_norm: f64,
+    // The residual norm divided by the scale of the problem, which is what the tolerance bounds
+    pub relative_residual: f64,
+    // Row, column and value of the largest element of the residual in absolute value
+    pub largest_entry: (usize, usize, f64),
+    pub tolerance: f64,
+    pub passed: bool,
+}
+
+impl ResidualReport {
+    fn new(left: &Data, right: &Data, scale: f64, tolerance: f64) -> Self {
+        let mut residual_norm = 0.0;
+        let mut largest_entry: (usize, usize, f64) = (0, 0, 0.0);
+        for (i, (left_row, right_row)) in left.iter().zip(right).enumerate() {
+            for (j, (a, b)) in left_row.iter().zip(right_row).enumerate() {
+                let difference = a - b;
+                residual_norm += difference * difference;
+                if difference.abs() > largest_entry.2.abs() {
+                    largest_entry = (i, j, difference);
+                }
+            }
+        }
+        let residual_norm = residual_norm.sqrt();
+
+        // A zero scale means both sides should be exactly zero, so the absolute residual is used
+        let relative_residual = match scale {
+            0.0 => residual_norm,
+            scale => residual_norm / scale,
+        };
+
+        Self {
+            residual_norm,
+            relative_residual,
+            largest_entry,
+            tolerance,
+            passed: relative_residual <= tolerance,
+        }
+    }
+}
+
+fn check_tolerance(tolerance: f64) -> Result<(), MatrixError> {
+    if tolerance.is_nan() || tolerance < 0.0 {
+        return Err(MatrixError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+fn identity(n: usize) -> Data {
+    Matrix::nth_identity(n).unwrap().take_data()
+}
+
+fn frobenius(matrix: &Matrix) -> f64 {
+    matrix
+        .data
+        .iter()
+        .flatten()
+        .map(|num| num * num)
+        .sum::<f64>()
+        .sqrt()
+}
+
+// A·X = I, relative to ‖A‖‖X‖ so that ill-conditioned matrices are judged by what floating point
+// arithmetic can achieve for them
+pub fn verify_inverse(
+    a: &Matrix,
+    a_inverse: &Matrix,
+    tolerance: f64,
+) -> Result<ResidualReport, MatrixError> {
+    check_tolerance(tolerance)?;
+    if !a.is_square() {
+        return Err(MatrixError::NonSquareMatrix);
+    }
+    if a_inverse.shape() != a.shape() {
+        return Err(MatrixError::DimensionMismatch {
+            expected: a.shape(),
+            actual: a_inverse.shape(),
+        });
+    }
+
+    Ok(ResidualReport::new(
+        &functions::product(&a.data, &a_inverse.data),
+        &identity(a.rows),
+        frobenius(a) * frobenius(a_inverse),
+        tolerance,
+    ))
+}
+
+// P·A = L·U, relative to ‖A‖, for a row permutation matrix P such as
+// `Matrix::new_permutation_matrix(lu.row_permutation())`
+pub fn verify_decomposition(
+    a: &Matrix,
+    lower: &Matrix,
+    upper: &Matrix,
+    permutation: &Matrix,
+    tolerance: f64,
+) -> Result<ResidualReport, MatrixError> {
+    check_tolerance(tolerance)?;
+    if permutation.shape() != (a.rows, a.rows) {
+        return Err(MatrixError::DimensionMismatch {
+            expected: (a.rows, a.rows),
+            actual: permutation.shape(),
+        });
+    }
+    if lower.rows != a.rows || lower.columns != upper.rows || upper.columns != a.columns {
+        return Err(MatrixError::DimensionMismatch {
+            expected: (lower.columns, a.columns),
+            actual: upper.shape(),
+        });
+    }
+
+    Ok(ResidualReport::new(
+        &functions::product(&permutation.data, &a.data),
+        &functions::product(&lower.data, &upper.data),
+        frobenius(a),
+        tolerance,
+    ))
+}
+
+// Qᵀ·Q = I, so that the columns of Q are orthonormal, relative to ‖I‖ = √n for n columns
+pub fn verify_orthogonal(q: &Matrix, tolerance: f64) -> Result<ResidualReport, MatrixError> {
+    check_tolerance(tolerance)?;
+
+    Ok(ResidualReport::new(
+        &functions::product(&q.transpose().data, &q.data),
+        &identity(q.columns),
+        (q.columns as f64).sqrt(),
+        tolerance,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_utils, PivotStrategy};
+
+    #[test]
+    fn inverse() {
+        for matrix in test_utils::square_examples() {
+            let Ok(inverse) = matrix.inverse() else {
+                continue;
+            };
+            // Rounding the inverse to five decimal places leaves a residual of about that size
+            let report = verify_inverse(&matrix, &inverse, 1e-4).unwrap();
+            assert!(report.passed);
+        }
+
+        let matrix = Matrix::new_with_data(vec![vec![2.0, 0.0], vec![0.0, 4.0]]).unwrap();
+        let wrong = Matrix::new_with_data(vec![vec![0.5, 0.0], vec![0.0, 0.5]]).unwrap();
+        let report = verify_inverse(&matrix, &wrong, 1e-5).unwrap();
+        assert!(!report.passed);
+        assert_eq!((1, 1, 1.0), report.largest_entry);
+        assert_eq!(1.0, report.residual_norm);
+
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 2),
+                actual: (1, 1)
+            },
+            verify_inverse(&matrix, &Matrix::nth_identity(1).unwrap(), 1e-5).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::InvalidArgument,
+            verify_inverse(&matrix, &wrong, -1.0).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn decomposition() {
+        for matrix in test_utils::square_examples() {
+            let lu = matrix.lu_decomposition(PivotStrategy::Partial).unwrap();
+            let permutation = Matrix::new_permutation_matrix(lu.row_permutation()).unwrap();
+            let report =
+                verify_decomposition(&matrix, lu.lower(), lu.upper(), &permutation, 1e-5).unwrap();
+            assert!(report.passed);
+
+            let identity = Matrix::nth_identity(matrix.rows).unwrap();
+            if permutation != identity {
+                let report =
+                    verify_decomposition(&matrix, lu.lower(), lu.upper(), &identity, 1e-5).unwrap();
+                assert!(!report.passed);
+            }
+        }
+    }
+
+    #[test]
+    fn orthogonal() {
+        let angle: f64 = 0.3;
+        let rotation = Matrix::new_with_data(vec![
+            vec![angle.cos(), -angle.sin()],
+            vec![angle.sin(), angle.cos()],
+        ])
+        .unwrap();
+        let report = verify_orthogonal(&rotation, 1e-12).unwrap();
+        assert!(report.passed);
+        assert!(report.residual_norm < 1e-15);
+
+        let report = verify_orthogonal(&(2.0 * &rotation), 1e-12).unwrap();
+        assert!(!report.passed);
+        assert!((report.largest_entry.2 - 3.0).abs() < 1e-4);
+    }
+}
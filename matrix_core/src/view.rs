@@ -0,0 +1,301 @@
+use crate::{Matrix, MatrixError};
+use std::ops::{Add, Mul, Range, Sub};
+
+// A window onto the elements of a matrix. Element (i, j) of the view sits at
+// origin + i·row_step + j·column_step in the matrix, so transposing, slicing and reversing a view
+// only changes these numbers and never copies elements
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a> {
+    matrix: &'a Matrix,
+    rows: usize,
+    columns: usize,
+    origin: (usize, usize),
+    row_step: (isize, isize),
+    column_step: (isize, isize),
+}
+
+impl<'a> From<&'a Matrix> for MatrixView<'a> {
+    fn from(matrix: &'a Matrix) -> Self {
+        Self {
+            matrix,
+            rows: matrix.rows,
+            columns: matrix.columns,
+            origin: (0, 0),
+            row_step: (1, 0),
+            column_step: (0, 1),
+        }
+    }
+}
+
+impl<'a> MatrixView<'a> {
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.columns)
+    }
+
+    // Where element (row, column) of the view is in the matrix, for indices already checked
+    fn position(&self, row: usize, column: usize) -> (usize, usize) {
+        let (row, column) = (row as isize, column as isize);
+        (
+            (self.origin.0 as isize + row * self.row_step.0 + column * self.column_step.0) as usize,
+            (self.origin.1 as isize + row * self.row_step.1 + column * self.column_step.1) as usize,
+        )
+    }
+
+    fn element(&self, row: usize, column: usize) -> f64 {
+        let (row, column) = self.position(row, column);
+        self.matrix.data[row][column]
+    }
+
+    pub fn get_element(&self, row: usize, column: usize) -> Result<f64, MatrixError> {
+        crate::check_index(row, self.rows)?;
+        crate::check_index(column, self.columns)?;
+
+        Ok(self.element(row, column))
+    }
+
+    pub fn transpose_view(&self) -> Self {
+        Self {
+            rows: self.columns,
+            columns: self.rows,
+            row_step: self.column_step,
+            column_step: self.row_step,
+            ..*self
+        }
+    }
+
+    pub fn submatrix_view(
+        &self,
+        rows: Range<usize>,
+        columns: Range<usize>,
+    ) -> Result<Self, MatrixError> {
+        if rows.is_empty() || columns.is_empty() {
+            return Err(MatrixError::ZeroDimension);
+        }
+
+        crate::check_index(rows.end - 1, self.rows)?;
+        crate::check_index(columns.end - 1, self.columns)?;
+
+        Ok(Self {
+            rows: rows.len(),
+            columns: columns.len(),
+            origin: self.position(rows.start, columns.start),
+            ..*self
+        })
+    }
+
+    // The rows in the opposite order, last one first
+    pub fn reverse_rows(&self) -> Self {
+        Self {
+            origin: self.position(self.rows - 1, 0),
+            row_step: (-self.row_step.0, -self.row_step.1),
+            ..*self
+        }
+    }
+
+    pub fn reverse_columns(&self) -> Self {
+        Self {
+            origin: self.position(0, self.columns - 1),
+            column_step: (-self.column_step.0, -self.column_step.1),
+            ..*self
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix {
+        Matrix::new_with_data(
+            (0..self.rows)
+                .map(|i| (0..self.columns).map(|j| self.element(i, j)).collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    fn element_wise_operation<F>(&self, other: &Self, operation: F) -> Result<Matrix, MatrixError>
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        if self.shape() != other.shape() {
+            return Err(MatrixError::DimensionMismatch {
+                expected: self.shape(),
+                actual: other.shape(),
+            });
+        }
+
+        Ok(Matrix::new_with_data(
+            (0..self.rows)
+                .map(|i| {
+                    (0..self.columns)
+                        .map(|j| {
+                            crate::round_result(operation(self.element(i, j), other.element(i, j)))
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+        .unwrap())
+    }
+}
+
+impl Add for MatrixView<'_> {
+    type Output = Result<Matrix, MatrixError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.element_wise_operation(&rhs, |a, b| a + b)
+    }
+}
+
+impl Sub for MatrixView<'_> {
+    type Output = Result<Matrix, MatrixError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.element_wise_operation(&rhs, |a, b| a - b)
+    }
+}
+
+impl Mul for MatrixView<'_> {
+    type Output = Result<Matrix, MatrixError>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.columns != rhs.rows {
+            return Err(MatrixError::DimensionMismatch {
+                expected: (self.columns, rhs.columns),
+                actual: rhs.shape(),
+            });
+        }
+
+        Ok(Matrix::new_with_data(
+            (0..self.rows)
+                .map(|i| {
+                    (0..rhs.columns)
+                        .map(|j| {
+                            crate::round_result(crate::compensated_sum(
+                                (0..self.columns).map(|k| self.element(i, k) * rhs.element(k, j)),
+                            ))
+                        })
+                        .collect()
+                })
+                .collect(),
+        )
+        .unwrap())
+    }
+}
+
+impl Matrix {
+    pub fn view(&self) -> MatrixView<'_> {
+        MatrixView::from(self)
+    }
+
+    pub fn transpose_view(&self) -> MatrixView<'_> {
+        self.view().transpose_view()
+    }
+
+    pub fn submatrix_view(
+        &self,
+        rows: Range<usize>,
+        columns: Range<usize>,
+    ) -> Result<MatrixView<'_>, MatrixError> {
+        self.view().submatrix_view(rows, columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn views_match_copies() {
+        for matrix in test_utils::generic_examples()
+            .into_iter()
+            .chain(test_utils::square_examples())
+        {
+            let (rows, columns) = matrix.shape();
+            assert_eq!(matrix, matrix.view().to_matrix());
+            assert_eq!(matrix.transpose(), matrix.transpose_view().to_matrix());
+            assert_eq!(
+                matrix.submatrix(rows / 2..rows, 0..columns).unwrap(),
+                matrix
+                    .submatrix_view(rows / 2..rows, 0..columns)
+                    .unwrap()
+                    .to_matrix()
+            );
+            assert_eq!(
+                matrix.transpose(),
+                matrix
+                    .view()
+                    .reverse_rows()
+                    .transpose_view()
+                    .reverse_columns()
+                    .to_matrix()
+            );
+        }
+    }
+
+    #[test]
+    fn nested_views() {
+        let matrix = Matrix::new_with_data(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ])
+        .unwrap();
+
+        let view = matrix
+            .transpose_view()
+            .reverse_columns()
+            .submatrix_view(1..3, 0..2)
+            .unwrap();
+        assert_eq!(
+            Matrix::new_with_data(vec![vec![8.0, 5.0], vec![9.0, 6.0]]).unwrap(),
+            view.to_matrix()
+        );
+        assert_eq!(5.0, view.get_element(0, 1).unwrap());
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 2, len: 2 },
+            view.get_element(2, 0).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::IndexOutOfBounds { index: 3, len: 3 },
+            matrix.submatrix_view(0..4, 0..1).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn operations() {
+        let [matrix_2x3, another_matrix_2x3, matrix_4x2] = test_utils::generic_examples();
+
+        assert_eq!(
+            (&matrix_2x3 * &another_matrix_2x3.transpose()).unwrap(),
+            (matrix_2x3.view() * another_matrix_2x3.transpose_view()).unwrap()
+        );
+        assert_eq!(
+            (&matrix_2x3.transpose() + &another_matrix_2x3.transpose()).unwrap(),
+            (matrix_2x3.transpose_view() + another_matrix_2x3.transpose_view()).unwrap()
+        );
+        assert_eq!(
+            (&matrix_2x3 - &another_matrix_2x3).unwrap(),
+            (matrix_2x3.view() - another_matrix_2x3.view()).unwrap()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (2, 3),
+                actual: (4, 2)
+            },
+            (matrix_2x3.view() + matrix_4x2.view()).unwrap_err()
+        );
+        assert_eq!(
+            MatrixError::DimensionMismatch {
+                expected: (3, 2),
+                actual: (4, 2)
+            },
+            (matrix_2x3.view() * matrix_4x2.view()).unwrap_err()
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{LinearSystemSolution, Matrix, MatrixError};
+use wasm_bindgen::prelude::*;
+
+// Exposed to JavaScript as `Matrix`. Elements cross the boundary as a flat Float64Array in row-major
+// order, and every MatrixError becomes a thrown Error with its message
+#[wasm_bindgen(js_name = Matrix)]
+pub struct WasmMatrix(Matrix);
+
+#[wasm_bindgen(js_class = Matrix)]
+impl WasmMatrix {
+    #[wasm_bindgen(constructor)]
+    pub fn new(rows: usize, columns: usize, elements: &[f64]) -> Result<WasmMatrix, JsError> {
+        if rows == 0 || columns == 0 {
+            return Err(MatrixError::ZeroDimension.into());
+        }
+
+        if elements.len() != rows * columns {
+            return Err(JsError::new(&format!(
+                "expected {} elements, got {}",
+                rows * columns,
+                elements.len()
+            )));
+        }
+
+        Ok(WasmMatrix(Matrix::new_with_data(
+            elements.chunks(columns).map(<[f64]>::to_vec).collect(),
+        )?))
+    }
+
+    pub fn zeros(rows: usize, columns: usize) -> Result<WasmMatrix, JsError> {
+        Ok(WasmMatrix(Matrix::new_zero_matrix(rows, columns)?))
+    }
+
+    pub fn identity(size: usize) -> Result<WasmMatrix, JsError> {
+        Ok(WasmMatrix(Matrix::nth_identity(size)?))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn rows(&self) -> usize {
+        self.0.rows
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn columns(&self) -> usize {
+        self.0.columns
+    }
+
+    pub fn elements(&self) -> Vec<f64> {
+        self.0.data.concat()
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> Result<f64, JsError> {
+        Ok(self.0.get_element(row, column)?)
+    }
+
+    pub fn set(&mut self, row: usize, column: usize, value: f64) -> Result<(), JsError> {
+        Ok(self.0.set_element(row, column, value)?)
+    }
+
+    pub fn add(&self, other: &WasmMatrix) -> Result<WasmMatrix, JsError> {
+        Ok(WasmMatrix((&self.0 + &other.0)?))
+    }
+
+    pub fn subtract(&self, other: &WasmMatrix) -> Result<WasmMatrix, JsError> {
+        Ok(WasmMatrix((&self.0 - &other.0)?))
+    }
+
+    pub fn multiply(&self, other: &WasmMatrix) -> Result<WasmMatrix, JsError> {
+        Ok(WasmMatrix((&self.0 * &other.0)?))
+    }
+
+    pub fn scale(&self, scalar: f64) -> WasmMatrix {
+        WasmMatrix(scalar * &self.0)
+    }
+
+    pub fn transpose(&self) -> WasmMatrix {
+        WasmMatrix(self.0.transpose())
+    }
+
+    pub fn determinant(&self) -> Result<f64, JsError> {
+        Ok(self.0.determinant()?)
+    }
+
+    pub fn inverse(&self) -> Result<WasmMatrix, JsError> {
+        Ok(WasmMatrix(self.0.inverse()?))
+    }
+
+    // Throws unless the system has exactly one solution
+    pub fn solve(&self, b: &WasmMatrix) -> Result<WasmMatrix, JsError> {
+        match self.0.solve_general(&b.0)? {
+            LinearSystemSolution::Unique(solution) => Ok(WasmMatrix(solution)),
+            LinearSystemSolution::NoSolution => Err(JsError::new("the system has no solution")),
+            LinearSystemSolution::Infinite { .. } => {
+                Err(JsError::new("the system has infinitely many solutions"))
+            }
+        }
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_display_string(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+// Only the successful paths can run outside WebAssembly, since creating a JsError calls into JavaScript
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils;
+
+    #[test]
+    fn round_trip() {
+        let matrix = WasmMatrix::new(2, 3, &[7.2, 13.8, 5.1, 9.3, 2.7, 6.4]).unwrap();
+
+        assert_eq!(test_utils::generic_examples()[0], matrix.0);
+        assert_eq!((2, 3), (matrix.rows(), matrix.columns()));
+        assert_eq!(13.8, matrix.get(0, 1).unwrap());
+        assert_eq!(vec![7.2, 13.8, 5.1, 9.3, 2.7, 6.4], matrix.elements());
+    }
+
+    #[test]
+    fn operations() {
+        let a = WasmMatrix::new(2, 2, &[2.0, 1.0, 1.0, 3.0]).unwrap();
+        let b = WasmMatrix::new(2, 1, &[3.0, 5.0]).unwrap();
+
+        assert_eq!(5.0, a.determinant().unwrap());
+        assert_eq!(vec![0.8, 1.4], a.solve(&b).unwrap().elements());
+        assert_eq!(
+            WasmMatrix::identity(2).unwrap().0,
+            a.multiply(&a.inverse().unwrap()).unwrap().0
+        );
+        assert_eq!(vec![4.0, 2.0, 2.0, 6.0], a.add(&a).unwrap().elements());
+        assert_eq!(a.scale(2.0).0, a.add(&a).unwrap().0);
+    }
+}